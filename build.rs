@@ -7,49 +7,59 @@ extern crate serde;
 #[path = "src/parser.rs"]
 mod parser;
 
-use grammar::Lang;
+use grammar::{InputMode, Lang};
 use std::thread::{self, JoinHandle};
 use std::{env, fs, path};
 
-pub fn train_async(lang: Lang) -> JoinHandle<()> {
+fn model_file_name(lang: Lang, mode: InputMode) -> String {
+    match mode {
+        InputMode::Written => format!("{}.rmp", lang.to_string().to_lowercase()),
+        InputMode::Spoken => format!("{}_spoken.rmp", lang.to_string().to_lowercase()),
+    }
+}
+
+pub fn train_async(lang: Lang, mode: InputMode) -> JoinHandle<()> {
     println!(
         "cargo:rerun-if-changed=grammar/{}/src/",
         lang.to_string().to_lowercase()
     );
     thread::spawn(move || {
         let out_dir = path::PathBuf::from(env::var("OUT_DIR").unwrap());
-        let mut file = fs::File::create(out_dir.join(format!(
-            "{}{}",
-            lang.to_string().to_lowercase(),
-            ".rmp"
-        )))
-        .unwrap();
-        let rules = grammar::rules(lang).unwrap();
-        let examples = grammar::examples(lang);
+        let mut file = fs::File::create(out_dir.join(model_file_name(lang, mode))).unwrap();
+        let rules = grammar::rules_with_mode(lang, mode).unwrap();
+        let examples = grammar::examples_with_mode(lang, mode);
         let model = rustling::train::train(&rules, examples, parser::FeatureExtractor()).unwrap();
         rmp_serde::encode::write(&mut file, &model).unwrap();
     })
 }
 
-pub fn train_sync(lang: Lang) {
+pub fn train_sync(lang: Lang, mode: InputMode) {
     println!(
         "cargo:rerun-if-changed=grammar/{}/src/",
         lang.to_string().to_lowercase()
     );
     let out_dir = path::PathBuf::from(env::var("OUT_DIR").unwrap());
-    let mut file =
-        fs::File::create(out_dir.join(format!("{}{}", lang.to_string().to_lowercase(), ".rmp")))
-            .unwrap();
-    let rules = grammar::rules(lang).unwrap();
-    let examples = grammar::examples(lang);
+    let mut file = fs::File::create(out_dir.join(model_file_name(lang, mode))).unwrap();
+    let rules = grammar::rules_with_mode(lang, mode).unwrap();
+    let examples = grammar::examples_with_mode(lang, mode);
     let model = rustling::train::train(&rules, examples, parser::FeatureExtractor()).unwrap();
     rmp_serde::encode::write(&mut file, &model).unwrap();
 }
 
+/// Spoken models are only trained with the `spoken` feature, they are trained when the parser
+/// is built otherwise.
+fn modes() -> Vec<InputMode> {
+    if env::var_os("CARGO_FEATURE_SPOKEN").is_some() {
+        InputMode::all()
+    } else {
+        vec![InputMode::Written]
+    }
+}
+
 pub fn train_all_async() {
     let join_handlers: Vec<_> = Lang::all()
         .into_iter()
-        .map(|lang| train_async(lang))
+        .flat_map(|lang| modes().into_iter().map(move |mode| train_async(lang, mode)))
         .collect();
 
     for join in join_handlers {
@@ -59,7 +69,9 @@ pub fn train_all_async() {
 
 pub fn train_all_sync() {
     for lang in Lang::all() {
-        train_sync(lang);
+        for mode in modes() {
+            train_sync(lang, mode);
+        }
     }
 }
 
@@ -3,6 +3,7 @@ extern crate clap;
 extern crate rustling;
 extern crate rustling_ontology_grammar as grammar;
 extern crate rustling_ontology_values as values;
+extern crate rustling_ontology_json_utils as json_utils;
 extern crate rustling_ontology_moment;
 #[macro_use]
 extern crate prettytable;
@@ -14,17 +15,28 @@ use values::{ParsingContext, ResolverContext};
 fn main() {
     let matches = clap_app!(rustling_cli =>
         (@arg lang: -l --lang default_value[en] "2-letter language code (default to \"en\")")
+        (@arg mode: -m --mode default_value[written] "input mode, \"written\" or \"spoken\" for speech recognition transcripts (default to \"written\")")
+        (@arg reference: -r --reference +takes_value "reference time, e.g. \"2026-10-18T09:00:00\" (default to 2013-02-12T04:30:00)")
+        (@arg tz: --tz +takes_value "timezone of the reference time and of the values, e.g. \"Europe/Paris\" (default to the local one)")
         (@subcommand parse =>
              (@arg kinds: -k --kinds +takes_value +use_delimiter "kinds, last one wins, coma separated")
              (@arg sentence: +required "Sentence to test")
         )
     ).get_matches();
     let lang = value_t!(matches.value_of("lang"), grammar::Lang).unwrap_or_else(|e| e.exit());
+    let mode = value_t!(matches.value_of("mode"), grammar::InputMode).unwrap_or_else(|e| e.exit());
+    if let Some(tz) = matches.value_of("tz") {
+        json_utils::set_timezone(tz).unwrap_or_else(|e| clap::Error::with_description(&e, clap::ErrorKind::InvalidValue).exit());
+    }
+    let reference = matches
+        .value_of("reference")
+        .map(|it| json_utils::parse_reference(it).unwrap_or_else(|e| clap::Error::with_description(&e, clap::ErrorKind::InvalidValue).exit()))
+        .unwrap_or_else(|| Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)));
     match matches.subcommand() {
         ("parse", Some(matches)) => {
             let sentence = matches.value_of("sentence").unwrap().to_lowercase();
-            let decoder = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
-            let rules = grammar::rules(lang).unwrap();
+            let decoder = ResolverContext::new(Interval::starting_at(reference, Grain::Second));
+            let rules = grammar::rules_with_mode(lang, mode).unwrap();
             let matches = rules.apply_all(&*sentence).unwrap();
             let mut table = Table::new();
             table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use prettytable::Table;
+use rustling_ontology::dimension::Payload;
+use rustling_ontology::*;
+use serde_json::json;
+
+/// Number of sentences in which a rule fired.
+#[derive(Debug, Default, Clone)]
+pub struct RuleCoverage {
+    pub rule: String,
+    /// In the parsing tree of an output of the parser.
+    pub winning: usize,
+    /// In the parsing tree of any candidate, including the outputs.
+    pub candidates: usize,
+}
+
+fn collect_syms(node: &Node<Payload>, syms: &mut Vec<Sym>) {
+    if !syms.contains(&node.rule_sym) {
+        syms.push(node.rule_sym);
+    }
+    for child in node.children.iter() {
+        collect_syms(child, syms);
+    }
+}
+
+/// Parses the sentences and counts the ones in which each of the rules, named as in
+/// `grammar::rule_names`, fired. Rules declared several times under the same name are counted
+/// together.
+pub fn coverage(parser: &RawParser, rules: &[String], sentences: &[String], context: &ResolverContext) -> Vec<RuleCoverage> {
+    let kinds = OutputKind::all();
+    let tagger = CandidateTagger {
+        output_kind_filter: &kinds,
+        context,
+        resolve_all_candidates: false,
+    };
+    let mut counts: HashMap<&str, (usize, usize)> = HashMap::new();
+    for sentence in sentences {
+        let candidates = parser.candidates(&*sentence, &tagger).unwrap();
+        let mut winning = vec![];
+        let mut any = vec![];
+        for candidate in &candidates {
+            collect_syms(&candidate.node.root_node, &mut any);
+            if candidate.tagged && candidate.match_.value.is_some() {
+                collect_syms(&candidate.node.root_node, &mut winning);
+            }
+        }
+        for sym in winning {
+            counts.entry(parser.resolve_sym(&sym).unwrap_or("")).or_insert((0, 0)).0 += 1;
+        }
+        for sym in any {
+            counts.entry(parser.resolve_sym(&sym).unwrap_or("")).or_insert((0, 0)).1 += 1;
+        }
+    }
+    rules
+        .iter()
+        .map(|rule| {
+            let (winning, candidates) = counts.get(rule.as_str()).cloned().unwrap_or((0, 0));
+            RuleCoverage {
+                rule: rule.clone(),
+                winning,
+                candidates,
+            }
+        })
+        .collect()
+}
+
+/// Percentage of the rules firing in an output of the parser.
+pub fn winning_ratio(coverage: &[RuleCoverage]) -> f32 {
+    if coverage.is_empty() {
+        return 100.0;
+    }
+    let covered = coverage.iter().filter(|it| it.winning > 0).count();
+    100.0 * covered as f32 / coverage.len() as f32
+}
+
+pub fn print_coverage(coverage: &[RuleCoverage], all: bool) {
+    let mut table = Table::new();
+    table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.set_titles(row!["rule", "winning", "candidates", "status"]);
+    for rule in coverage {
+        let status = if rule.winning > 0 {
+            "covered"
+        } else if rule.candidates > 0 {
+            "never wins"
+        } else {
+            "never fired"
+        };
+        if all || rule.winning == 0 {
+            table.add_row(row![rule.rule, rule.winning, rule.candidates, status]);
+        }
+    }
+    table.printstd();
+    let never_wins = coverage.iter().filter(|it| it.winning == 0 && it.candidates > 0).count();
+    let never_fired = coverage.iter().filter(|it| it.candidates == 0).count();
+    println!("Rules: {:?} | {:.1}% covered | {:?} never win | {:?} never fired",
+             coverage.len(),
+             winning_ratio(coverage),
+             never_wins,
+             never_fired);
+}
+
+pub fn coverage_json(coverage: &[RuleCoverage]) -> serde_json::Value {
+    json!({
+        "rules": coverage
+            .iter()
+            .map(|it| json!({ "rule": it.rule, "winning": it.winning, "candidates": it.candidates }))
+            .collect::<Vec<_>>(),
+        "coverage": winning_ratio(coverage),
+    })
+}
@@ -0,0 +1,177 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use json_utils::{Entity, Snapshot, SnapshotDiff, Utterance};
+use rustling_ontology::{Local, Moment, Parser};
+
+/// Changed utterances, grouped by the kinds of their entities in the baseline and in the current
+/// version, so that an utterance appears under each kind it had before or has after.
+pub type DiffReport = BTreeMap<String, Vec<SnapshotDiff>>;
+
+/// Entities found by the parser in each utterance, at its reference time or the default one.
+pub fn snapshots(parser: &Parser, utterances: &[Utterance], default_context: Moment<Local>) -> Vec<Snapshot> {
+    utterances
+        .iter()
+        .filter(|it| it.keep())
+        .map(|utterance| {
+            let context = crate::resolver_context(json_utils::utterance_reference(utterance.context, default_context));
+            let entities = parser
+                .parse(utterance.phrase.to_lowercase().as_str(), &context)
+                .unwrap();
+            Snapshot {
+                phrase: utterance.phrase.clone(),
+                context: utterance.context,
+                entities: crate::utterance_entities(&entities),
+            }
+        })
+        .collect()
+}
+
+/// Compares the snapshots of the same utterances, i.e. with the same phrase and reference time,
+/// returning the report, the number of current utterances missing from the baseline and the
+/// number of baseline utterances missing from the current version.
+pub fn diff(baseline: &[Snapshot], current: &[Snapshot]) -> (DiffReport, usize, usize) {
+    let current_keys = current.iter().map(key).collect::<HashSet<_>>();
+    let removed = baseline
+        .iter()
+        .filter(|it| !current_keys.contains(&key(it)))
+        .count();
+    let baseline = baseline
+        .iter()
+        .map(|it| (key(it), it))
+        .collect::<HashMap<_, _>>();
+    let mut report = DiffReport::new();
+    let mut added = 0;
+    for after in current {
+        let before = match baseline.get(&key(after)) {
+            Some(before) => before,
+            None => {
+                added += 1;
+                continue;
+            }
+        };
+        let changes = before.changes(after);
+        if changes.is_empty() {
+            continue;
+        }
+        let mut dimensions = before
+            .entities
+            .iter()
+            .chain(after.entities.iter())
+            .map(|it| it.value.kind())
+            .collect::<BTreeSet<_>>();
+        if dimensions.is_empty() {
+            dimensions.insert("None");
+        }
+        let diff = SnapshotDiff {
+            phrase: after.phrase.clone(),
+            context: after.context,
+            changes,
+            before: before.entities.clone(),
+            after: after.entities.clone(),
+        };
+        for dimension in dimensions {
+            report
+                .entry(dimension.to_string())
+                .or_insert_with(Vec::new)
+                .push(diff.clone());
+        }
+    }
+    (report, added, removed)
+}
+
+fn key(snapshot: &Snapshot) -> (&str, Option<i64>) {
+    (snapshot.phrase.as_str(), snapshot.context.as_ref().map(|it| it.0.timestamp()))
+}
+
+pub fn print_report(report: &DiffReport, total: usize, added: usize, removed: usize) {
+    for (dimension, diffs) in report {
+        println!("{} ({} changed)", dimension, diffs.len());
+        for diff in diffs {
+            match diff.context {
+                Some(context) => println!("  {:?} at {} {:?}", diff.phrase, context, diff.changes),
+                None => println!("  {:?} {:?}", diff.phrase, diff.changes),
+            }
+            for entity in &diff.before {
+                println!("    - {}", format_entity(&diff.phrase, entity));
+            }
+            for entity in &diff.after {
+                println!("    + {}", format_entity(&diff.phrase, entity));
+            }
+        }
+    }
+    let changed = report
+        .values()
+        .flat_map(|diffs| diffs.iter().map(|it| (it.phrase.as_str(), it.context.as_ref().map(|context| context.0.timestamp()))))
+        .collect::<HashSet<_>>()
+        .len();
+    println!("Total: {:?} | {:?} utterances changed", total, changed);
+    if added > 0 {
+        println!("{:?} utterances are missing from the baseline", added);
+    }
+    if removed > 0 {
+        println!("{:?} utterances of the baseline were removed", removed);
+    }
+}
+
+fn format_entity(phrase: &str, entity: &Entity) -> String {
+    format!("{:?} {}",
+            phrase.chars().skip(entity.start).take(entity.end - entity.start).collect::<String>(),
+            serde_json::to_string(&entity.value).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use json_utils::{Change, NumberValue, OrdinalValue, SlotValue};
+    use rustling_ontology::{Local, TimeZone};
+
+    fn snapshot(phrase: &str, context: Option<Moment<Local>>, entities: Vec<Entity>) -> Snapshot {
+        Snapshot {
+            phrase: phrase.to_string(),
+            context,
+            entities,
+        }
+    }
+
+    fn number(start: usize, end: usize, value: f64) -> Entity {
+        Entity {
+            start,
+            end,
+            value: SlotValue::Number(NumberValue { value }),
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let morning = Some(Moment(Local.ymd(2017, 6, 1).and_hms(9, 0, 0)));
+        let evening = Some(Moment(Local.ymd(2017, 6, 1).and_hms(21, 0, 0)));
+        let baseline = vec![
+            snapshot("two", None, vec![number(0, 3, 2.0)]),
+            snapshot("the second", morning, vec![number(4, 10, 2.0)]),
+            snapshot("the second", evening, vec![number(4, 10, 2.0)]),
+        ];
+        let current = vec![
+            snapshot("two", None, vec![number(0, 3, 2.0)]),
+            snapshot("the second", morning, vec![number(4, 10, 2.0)]),
+            snapshot("the second", evening, vec![Entity {
+                start: 0,
+                end: 10,
+                value: SlotValue::Ordinal(OrdinalValue { value: 2 }),
+            }]),
+            snapshot("three", None, vec![number(0, 5, 3.0)]),
+        ];
+        let (report, added, removed) = diff(&baseline, &current);
+        assert_eq!(1, added);
+        assert_eq!(0, removed);
+        assert_eq!(vec!["Number", "Ordinal"], report.keys().map(String::as_str).collect::<Vec<_>>());
+        let diffs = &report["Number"];
+        assert_eq!(1, diffs.len());
+        assert_eq!(evening, diffs[0].context);
+        assert_eq!(vec![Change::Span, Change::Kind], diffs[0].changes);
+        assert_eq!(diffs, &report["Ordinal"]);
+
+        let (_, added, removed) = diff(&current, &baseline);
+        assert_eq!(0, added);
+        assert_eq!(1, removed);
+    }
+}
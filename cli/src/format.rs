@@ -0,0 +1,153 @@
+use std::str::FromStr;
+
+use json_utils::SlotValue;
+use prettytable::Table;
+use rustling_ontology::dimension::Dimension;
+use rustling_ontology::{Candidate, Output, RawParser};
+use serde_json::{json, Value as JsonValue};
+
+/// Output format of the `parse` and `play` commands.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Format {
+    Table,
+    Json,
+    Jsonl,
+}
+
+impl FromStr for Format {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "jsonl" => Ok(Format::Jsonl),
+            _ => Err(format!("{} is not a known format", s)),
+        }
+    }
+}
+
+pub type OutputCandidate = Candidate<Dimension, Option<Output>>;
+
+/// Prints the entities found in sentences, one table or JSON line per sentence as they
+/// come, or a single JSON array of all sentences once `finish` is called.
+pub struct Printer {
+    format: Format,
+    documents: Vec<JsonValue>,
+}
+
+impl Printer {
+    pub fn new(format: Format) -> Printer {
+        Printer { format, documents: vec![] }
+    }
+
+    /// Prints the candidates kept by the tagger, i.e. what the parser outputs.
+    pub fn print_parse(&mut self, parser: &RawParser, sentence: &str, candidates: &[OutputCandidate]) {
+        let entities = candidates
+            .iter()
+            .filter(|c| c.tagged && c.match_.value.is_some())
+            .collect::<Vec<_>>();
+        if self.format == Format::Table {
+            let mut table = Table::new();
+            table.set_titles(row!["ix", "log(p)", "p", "text", "Output(OutputValue)"]);
+            for (ix, c) in entities.iter().enumerate().rev() {
+                table.add_row(row![ix,
+                                   c.match_.probalog,
+                                   f32::exp(c.match_.probalog),
+                                   hilite(sentence, c),
+                                   c.match_.value.as_ref().map(|v| format!("{:?}", v)).unwrap_or("".into())]);
+            }
+            table.printstd();
+        } else {
+            let entities = entities.iter().map(|c| entity(parser, sentence, c)).collect();
+            self.push(sentence, entities);
+        }
+    }
+
+    /// Prints all the candidates, with whether the tagger kept them and the rules of their
+    /// parsing tree.
+    pub fn print_play(&mut self, parser: &RawParser, sentence: &str, candidates: &[OutputCandidate]) {
+        if self.format == Format::Table {
+            let mut table = Table::new();
+            table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+            table.set_titles(row!["ix", "best", "log(p)", "p", "text", "value", "latent", "rule", "children"]);
+            for (ix, c) in candidates.iter().enumerate().rev() {
+                table.add_row(row![ix,
+                                   if c.tagged { "*" } else { " " },
+                                   c.match_.probalog,
+                                   f32::exp(c.match_.probalog),
+                                   hilite(sentence, c),
+                                   c.match_.value.as_ref().map(|v| format!("{:?}", v)).unwrap_or("".into()),
+                                   c.node.value.latent(),
+                                   parser.resolve_sym(&c.node.root_node.rule_sym).unwrap_or(""),
+                                   children(parser, c)
+                                       .iter()
+                                       .map(|name| name.chars().take(20).collect::<String>())
+                                       .collect::<Vec<_>>()
+                                       .join(" + ")]);
+            }
+            table.printstd();
+        } else {
+            let entities = candidates
+                .iter()
+                .map(|c| {
+                    let mut entity = entity(parser, sentence, c);
+                    entity["tagged"] = json!(c.tagged);
+                    entity["children"] = json!(children(parser, c));
+                    entity
+                })
+                .collect();
+            self.push(sentence, entities);
+        }
+    }
+
+    /// Prints the JSON array of all sentences for the `json` format.
+    pub fn finish(self) {
+        if self.format == Format::Json {
+            println!("{}", serde_json::to_string_pretty(&self.documents).unwrap());
+        }
+    }
+
+    fn push(&mut self, sentence: &str, entities: Vec<JsonValue>) {
+        let document = json!({ "sentence": sentence, "entities": entities });
+        match self.format {
+            Format::Jsonl => println!("{}", document),
+            _ => self.documents.push(document),
+        }
+    }
+}
+
+fn entity(parser: &RawParser, sentence: &str, c: &OutputCandidate) -> JsonValue {
+    let (start, end) = (c.match_.byte_range.0, c.match_.byte_range.1);
+    json!({
+        "range": { "start": start, "end": end },
+        "char_range": { "start": c.match_.char_range.0, "end": c.match_.char_range.1 },
+        "text": &sentence[start..end],
+        "kind": c.match_.value.as_ref().map(|v| v.kind().to_string()),
+        "value": c.match_.value.clone().map(SlotValue::from),
+        "latent": c.match_.latent,
+        "probalog": c.match_.probalog,
+        "probability": f32::exp(c.match_.probalog),
+        "rule": parser.resolve_sym(&c.node.root_node.rule_sym),
+    })
+}
+
+fn children<'a>(parser: &'a RawParser, c: &OutputCandidate) -> Vec<&'a str> {
+    c.node
+        .root_node
+        .children
+        .iter()
+        .map(|n| parser.resolve_sym(&n.rule_sym).unwrap_or(""))
+        .collect()
+}
+
+fn hilite(sentence: &str, c: &OutputCandidate) -> String {
+    let mut hilite = String::new();
+    for _ in 0..c.match_.byte_range.0 {
+        hilite.push('_');
+    }
+    hilite.push_str(&sentence[c.match_.byte_range.0..c.match_.byte_range.1]);
+    for _ in c.match_.byte_range.1..sentence.len() {
+        hilite.push('_');
+    }
+    hilite
+}
@@ -0,0 +1,202 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use regex::Regex;
+use rustling_ontology::dimension::{Dimension, DimensionKind};
+use rustling_ontology::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LintKind {
+    DuplicateName,
+    InvalidRegex,
+    EmptyMatch,
+    Uppercase,
+    UndeclaredDimension,
+}
+
+impl LintKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LintKind::DuplicateName => "duplicate-name",
+            LintKind::InvalidRegex => "invalid-regex",
+            LintKind::EmptyMatch => "empty-match",
+            LintKind::Uppercase => "uppercase",
+            LintKind::UndeclaredDimension => "undeclared-dimension",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Lint {
+    pub kind: LintKind,
+    pub rule: String,
+    /// File and line of the rule or regex, when found in the sources.
+    pub location: Option<String>,
+    pub message: String,
+}
+
+/// Reads the rule names which are deliberately shared by several rules of a grammar, one per
+/// line, e.g. `intersect`. A missing file lists none.
+pub fn read_shared_names(path: &Path) -> io::Result<HashSet<String>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(content
+            .lines()
+            .map(|it| it.trim())
+            .filter(|it| !it.is_empty())
+            .map(|it| it.to_string())
+            .collect()),
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(HashSet::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Checks the rule names and regexes declared in the sources of a grammar, e.g.
+/// `grammar/en/src`, returning the lints and the names of the rules found. A name declared again
+/// by the next rule, as in the variants of `named-day`, or listed in `shared` isn't a duplicate.
+///
+/// Unlike `lint_rule_set`, this doesn't look at the `RuleSet<Dimension>`: it only keeps the
+/// compiled regexes and one symbol per rule name, so the duplicate names and the uppercase
+/// regexes are found by scraping the `b.rule_*` and `b.reg` calls instead. A name or a regex
+/// which isn't a string literal at the call, like `b.reg(DIGIT_SEQUENCE)`, isn't checked.
+pub fn lint_sources(dir: &Path, shared: &HashSet<String>) -> io::Result<(Vec<Lint>, HashSet<String>)> {
+    let rule = Regex::new(r#"b\.rule_\d+(?:_terminal)?\(\s*"((?:[^"\\]|\\.)*)""#).unwrap();
+    let reg = Regex::new(r#"b\.reg(?:_neg_lh)?\(\s*(?:r(#*)"|")"#).unwrap();
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|it| it.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    paths.sort();
+
+    let mut lints = vec![];
+    let mut names: HashMap<String, String> = HashMap::new();
+    for path in paths {
+        let file_name = path.file_name().and_then(|it| it.to_str()).unwrap_or("").to_string();
+        if !file_name.ends_with(".rs") || file_name == "lib.rs" || file_name == "training.rs" {
+            continue;
+        }
+        // Blank the commented out lines, keeping the line numbers
+        let source = fs::read_to_string(&path)?
+            .lines()
+            .map(|line| if line.trim_start().starts_with("//") { "" } else { line })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let location = |offset: usize| format!("{}:{}", path.display(), source[..offset].matches('\n').count() + 1);
+
+        let rules = rule
+            .captures_iter(&source)
+            .map(|it| (it.get(0).unwrap().start(), unescape(&source[it.get(1).unwrap().start()..])))
+            .collect::<Vec<_>>();
+        let mut previous: Option<&str> = None;
+        for &(offset, ref name) in &rules {
+            let variant = previous == Some(name.as_str()) || shared.contains(name);
+            previous = Some(name.as_str());
+            if let Some(first) = names.get(name) {
+                if variant {
+                    continue;
+                }
+                lints.push(Lint {
+                    kind: LintKind::DuplicateName,
+                    rule: name.clone(),
+                    location: Some(location(offset)),
+                    message: format!("already declared at {}", first),
+                });
+            } else {
+                names.insert(name.clone(), location(offset));
+            }
+        }
+
+        for captures in reg.captures_iter(&source) {
+            let start = captures.get(0).unwrap().end();
+            let pattern = match captures.get(1) {
+                Some(hashes) => {
+                    let end = source[start..].find(&format!("\"{}", hashes.as_str())).map(|it| start + it);
+                    source[start..end.unwrap_or(start)].to_string()
+                }
+                None => unescape(&source[start..]),
+            };
+            let offset = captures.get(0).unwrap().start();
+            let rule = rules
+                .iter()
+                .take_while(|&&(rule_offset, _)| rule_offset < offset)
+                .last()
+                .map(|&(_, ref name)| name.clone())
+                .unwrap_or_default();
+            lints.extend(lint_regex(&pattern).map(|(kind, message)| Lint {
+                kind,
+                rule,
+                location: Some(location(offset)),
+                message,
+            }));
+        }
+    }
+    Ok((lints, names.into_iter().map(|(name, _)| name).collect()))
+}
+
+/// Content of a non-raw string literal, up to its closing quote.
+fn unescape(literal: &str) -> String {
+    let mut content = String::new();
+    let mut chars = literal.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => break,
+            '\\' => content.extend(chars.next()),
+            _ => content.push(c),
+        }
+    }
+    content
+}
+
+fn lint_regex(pattern: &str) -> Option<(LintKind, String)> {
+    let regex = match Regex::new(&format!("^(?:{})$", pattern)) {
+        Ok(regex) => regex,
+        Err(e) => return Some((LintKind::InvalidRegex, format!("{:?}: {}", pattern, e))),
+    };
+    if regex.is_match("") {
+        return Some((LintKind::EmptyMatch, format!("{:?} matches the empty string", pattern)));
+    }
+    // Escapes and classes like \D or \p{Lu} aren't characters to match
+    let escapes = Regex::new(r"\\[pP]\{[^}]*\}|\\.").unwrap();
+    if !pattern.contains("(?i") && escapes.replace_all(pattern, "").chars().any(char::is_uppercase) {
+        return Some((LintKind::Uppercase, format!("{:?} can't match the lowercased input", pattern)));
+    }
+    None
+}
+
+/// Checks the nodes built by a rule set on the sentences: terminal rules matching the empty
+/// string, and rules producing an output kind which the language doesn't declare in its
+/// `dims()`, which never give an output.
+pub fn lint_rule_set(rules: &RuleSet<Dimension>, lang: Lang, sentences: &[String]) -> Vec<Lint> {
+    let declared = dims(lang);
+    let undeclared = OutputKind::all()
+        .iter()
+        .map(|it| it.to_dim())
+        .filter(|it| !declared.contains(it))
+        .collect::<Vec<DimensionKind>>();
+    let mut flagged = HashSet::new();
+    let mut lints = vec![];
+    for sentence in sentences {
+        for node in rules.apply_all(&*sentence).unwrap().iter() {
+            let kind = node.value.kind();
+            let name = rules.resolve_sym(&node.root_node.rule_sym).unwrap_or("").to_string();
+            let range = &node.root_node.byte_range;
+            if range.0 == range.1 && flagged.insert((LintKind::EmptyMatch, name.clone())) {
+                lints.push(Lint {
+                    kind: LintKind::EmptyMatch,
+                    rule: name.clone(),
+                    location: None,
+                    message: format!("matches the empty string at {} in {:?}", range.0, sentence),
+                });
+            }
+            if undeclared.contains(&kind) && flagged.insert((LintKind::UndeclaredDimension, name.clone())) {
+                lints.push(Lint {
+                    kind: LintKind::UndeclaredDimension,
+                    rule: name,
+                    location: None,
+                    message: format!("outputs {:?}, which isn't in the dims() of {}", kind, lang.to_string()),
+                });
+            }
+        }
+    }
+    lints
+}
@@ -1,131 +1,129 @@
 #[macro_use]
 extern crate clap;
+extern crate rustling_ontology_grammar as grammar;
 extern crate rustling_ontology_json_utils as json_utils;
 extern crate rustling_ontology_moment;
+extern crate regex;
 extern crate rustling_ontology;
+extern crate rustyline;
 extern crate serde_json;
 #[macro_use]
 extern crate prettytable;
 
+mod coverage;
+mod diff;
+mod format;
+mod lint;
+mod report;
+mod repl;
+
+use std::io::{BufRead, Write};
 use std::str::FromStr;
 
 use rustling_ontology::*;
-use prettytable::Table;
-use json_utils::{PartialUtterance, Utterance, TestOutput, TestAssertion, SlotValue};
+use json_utils::{Entity, EntityScores, PartialUtterance, Utterance, TestOutput, TestAssertion, SlotValue};
+
+use crate::format::{Format, Printer};
 
 fn main() {
     let matches = clap_app!(rustling_cli =>
         (@arg lang: -l --lang default_value[en] "2-letter language code (default to \"en\")")
+        (@arg mode: -m --mode default_value[written] "input mode, \"written\" or \"spoken\" for speech recognition transcripts (default to \"written\")")
+        (@arg reference: -r --reference +takes_value "reference time, e.g. \"2026-10-18T09:00:00\" (default to now, or 2017-06-01T05:00:00 for utterance files)")
+        (@arg tz: --tz +takes_value "timezone of the reference time and of the values, e.g. \"Europe/Paris\" (default to the local one)")
         (@subcommand parse =>
              (@arg kinds: -k --kinds +takes_value +use_delimiter "kinds, last one wins, coma separated")
-             (@arg sentence: +required "Sentence to test")
+             (@arg format: -F --format +takes_value possible_value[table json jsonl] default_value[table] "output format")
+             (@arg sentence: "Sentence to test, one sentence per line is read from stdin if absent")
         )
         (@subcommand play =>
              (@arg kinds: -k --kinds +takes_value +use_delimiter "kinds, last one wins, coma separated")
-             (@arg sentence: +required "Sentence to test")
+             (@arg format: -F --format +takes_value possible_value[table json jsonl] default_value[table] "output format")
+             (@arg sentence: "Sentence to test, one sentence per line is read from stdin if absent")
+        )
+        (@subcommand repl =>
+             (about: "Parse sentences interactively, type :help for the commands")
         )
         (@subcommand utterance =>
             (@arg force: -f --force "if the value should be recomputed")
             (@arg path: -p --path +takes_value "Path to utterances file")
         )
+        (@subcommand diff =>
+             (about: "Report the utterances whose entities changed from a baseline")
+             (@arg input: -i --input +takes_value +required "Path to utterances file")
+             (@arg snapshot: --snapshot +takes_value "Path to the snapshot of the baseline outputs")
+             (@arg save: --save +takes_value "Path to save the snapshot of the current outputs to")
+             (@arg output: -o --output +takes_value "Path to the JSON report")
+        )
+        (@subcommand coverage =>
+             (about: "Report how often each rule fires when parsing a corpus")
+             (@arg input: -i --input +takes_value +multiple "Paths to utterances files, the training examples are parsed if absent")
+             (@arg examples: -e --examples "parse the training examples along with the utterances files")
+             (@arg all: -a --all "list the covered rules too")
+             (@arg min: --min +takes_value "fail when less than this percentage of the rules fire in an output")
+             (@arg output: -o --output +takes_value "Path to the JSON report")
+        )
+        (@subcommand lint =>
+             (about: "Check the rules of a language for duplicate names and regexes which can't match")
+             (@arg grammar: -g --grammar +takes_value "Path to the grammar folder (default to the one of this repository)")
+        )
         (@subcommand test =>
              (@arg kinds: -k --kinds +takes_value +use_delimiter "kinds, last one wins, coma separated")
              (@arg input: -i --input +takes_value "Path to utterances file")
              (@arg output: -o --output +takes_value "Path to test output file")
+             (@arg format: -F --format +takes_value possible_value[json junit tap] default_value[json] "format of the test output file")
+             (@arg expected_failures: -x --("expected-failures") +takes_value "Path to the phrases expected to fail, one per line")
         )
     ).get_matches();
 
     let lang = value_t!(matches.value_of("lang"), Lang).unwrap_or_else(|e| e.exit());
+    let mode = value_t!(matches.value_of("mode"), InputMode).unwrap_or_else(|e| e.exit());
+    if let Some(tz) = matches.value_of("tz") {
+        json_utils::set_timezone(tz).unwrap_or_else(|e| clap::Error::with_description(&e, clap::ErrorKind::InvalidValue).exit());
+    }
+    let reference = matches.value_of("reference").map(|it| {
+        json_utils::parse_reference(it).unwrap_or_else(|e| clap::Error::with_description(&e, clap::ErrorKind::InvalidValue).exit())
+    });
     match matches.subcommand() {
         ("parse", Some(matches)) => {
-            let kinds = matches
-                  .values_of("kinds")
-                  .map(|values| {
-                           values
-                               .map(|s| OutputKind::from_str(s).unwrap())
-                               .collect::<Vec<_>>()
-                  });
-            let sentence = matches.value_of("sentence").unwrap().to_lowercase();
-            let parser = build_parser(lang).unwrap();
-            
-            let context = ResolverContext::default();
-            let entities = if let Some(kinds) = kinds {
-                parser.parse_with_kind_order(&*sentence, &context, &kinds).unwrap()
-            } else {
-                parser.parse(&*sentence, &context).unwrap()
+            let kinds = kinds_arg(matches).unwrap_or_else(OutputKind::all);
+            let format = value_t!(matches.value_of("format"), Format).unwrap_or_else(|e| e.exit());
+            let parser = build_raw_parser_with_mode(lang, mode).unwrap();
+
+            let context = resolver_context(reference.unwrap_or_else(|| Moment(Local::now())));
+            let tagger = CandidateTagger {
+                output_kind_filter: &kinds,
+                context: &context,
+                resolve_all_candidates: false,
             };
-            let mut table = Table::new();
-            table.set_titles(row!["ix", "log(p)", "p", "text", "Output(OutputValue)"]);
-            for (ix, c) in entities.iter().enumerate().rev() {
-                let mut hilite = String::new();
-                for _ in 0..c.byte_range.0 {
-                    hilite.push('_');
-                }
-                hilite.push_str(&sentence[c.byte_range.0..c.byte_range.1]);
-                for _ in c.byte_range.1..sentence.len() {
-                    hilite.push('_');
-                }
-                table.add_row(row![ix,
-                                   c.probalog,
-                                   f32::exp(c.probalog),
-                                   hilite,
-                                   format!("{:?}", c.value)]);
+            let mut printer = Printer::new(format);
+            for sentence in sentences(matches) {
+                let candidates = parser.candidates(&*sentence, &tagger).unwrap();
+                printer.print_parse(&parser, &sentence, &candidates);
             }
-            table.printstd();
+            printer.finish();
         }
         ("play", Some(matches)) => {
-            let kinds = matches
-                .values_of("kinds")
-                .map(|values| {
-                         values
-                             .map(|s| OutputKind::from_str(s).unwrap())
-                             .collect()
-                     })
-                .unwrap_or(OutputKind::all());
-            let sentence = matches.value_of("sentence").unwrap().to_lowercase();
-            let parser = build_raw_parser(lang).unwrap();
+            let kinds = kinds_arg(matches).unwrap_or_else(OutputKind::all);
+            let format = value_t!(matches.value_of("format"), Format).unwrap_or_else(|e| e.exit());
+            let parser = build_raw_parser_with_mode(lang, mode).unwrap();
 
-            let context = ResolverContext::default();
+            let context = resolver_context(reference.unwrap_or_else(|| Moment(Local::now())));
             
             let tagger = CandidateTagger {
                 output_kind_filter: &kinds,
                 context: &context,
                 resolve_all_candidates: true,
             };
-            let candidates = parser.candidates(&*sentence, &tagger).unwrap();
-            let mut table = Table::new();
-            table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
-            table.set_titles(row!["ix", "best", "log(p)", "p", "text", "value", "latent", "rule", "children"]);
-
-            for (ix, c) in candidates.iter().enumerate().rev() {
-                let mut hilite = String::new();
-                for _ in 0..c.match_.byte_range.0 {
-                    hilite.push('_');
-                }
-                hilite.push_str(&sentence[c.match_.byte_range.0..c.match_.byte_range.1]);
-                for _ in c.match_.byte_range.1..sentence.len() {
-                    hilite.push('_');
-                }
-                table.add_row(row![ix,
-                                   if c.tagged { "*" } else { " " },
-                                   c.match_.probalog,
-                                   f32::exp(c.match_.probalog),
-                                   hilite,
-                                   c.match_.value.as_ref().map(|v| format!("{:?}", v)).unwrap_or("".into()),
-                                   c.node.value.latent(),
-                                   parser.resolve_sym(&c.node.root_node.rule_sym).unwrap_or(""),
-                                   c.node
-                                       .root_node
-                                       .children
-                                       .iter()
-                                       .map(|n| {
-                                           let name = parser.resolve_sym(&n.rule_sym).unwrap_or("");
-                                                name.chars().take(20).collect::<String>()
-                                            })
-                                       .collect::<Vec<_>>()
-                                       .join(" + ")]);
+            let mut printer = Printer::new(format);
+            for sentence in sentences(matches) {
+                let candidates = parser.candidates(&*sentence, &tagger).unwrap();
+                printer.print_play(&parser, &sentence, &candidates);
             }
-            table.printstd();
+            printer.finish();
+        }
+        ("repl", Some(_)) => {
+            repl::Repl::new(lang, mode, reference).run();
         }
         ("utterance", Some(matches)) => {
             let path = matches.value_of("path").unwrap();
@@ -135,12 +133,12 @@ fn main() {
               let file = ::std::fs::File::open(path).unwrap();
               serde_json::from_reader(&file).unwrap()
             };
-            let parser = build_parser(lang).unwrap();
-            let default_context = Moment(Local.ymd(2017, 6, 1).and_hms(5, 00, 0));
+            let parser = build_parser_with_mode(lang, mode).unwrap();
+            let default_context = reference.unwrap_or_else(json_utils::default_reference);
             let utterances: Vec<Utterance> = partial_utterances.into_iter()
                 .map(|it| {
                   if it.keep() && (it.value.is_none() || force_resolution) {
-                      let context = ResolverContext::new(Interval::starting_at(default_context, Grain::Second));
+                      let context = resolver_context(json_utils::utterance_reference(it.context, default_context));
                       let entities = parser.parse(it.phrase.to_lowercase().as_str(), &context).unwrap();
                       let full_match = entities
                         .into_iter()
@@ -151,8 +149,9 @@ fn main() {
                           in_grammar: it.in_grammar,
                           skip_rustling: it.skip_rustling,
                           translation: it.translation,
-                          context: default_context.clone(),
+                          context: it.context,
                           value: full_match.map(|it| it.value.into()),
+                          entities: it.entities,
                       }   
                   } else {
                     Utterance {
@@ -160,8 +159,9 @@ fn main() {
                       in_grammar: it.in_grammar,
                       skip_rustling: it.skip_rustling,
                       translation: it.translation,
-                      context: default_context.clone(),
+                      context: it.context,
                       value: it.value,
+                      entities: it.entities,
                     }
                   }
                 })
@@ -169,39 +169,143 @@ fn main() {
             let file = ::std::fs::File::create(path).unwrap();
             serde_json::to_writer_pretty(&file, &utterances).unwrap();
         }
+        ("diff", Some(matches)) => {
+            let input_path = matches.value_of("input").unwrap();
+            let utterances: Vec<Utterance> = {
+              let file = ::std::fs::File::open(input_path).map_err(|e| format!("Could not open input file at path: {}, with error {}", input_path, e)).unwrap();
+              serde_json::from_reader(&file).unwrap()
+            };
+            let default_context = reference.unwrap_or_else(json_utils::default_reference);
+            let parser = build_parser_with_mode(lang, mode).unwrap();
+            let current = diff::snapshots(&parser, &utterances, default_context);
+            if let Some(path) = matches.value_of("save") {
+                let file = ::std::fs::File::create(path).map_err(|e| format!("Could not create snapshot file at path: {} with error {}", path, e)).unwrap();
+                serde_json::to_writer_pretty(&file, &current).unwrap();
+            }
+            let baseline: Vec<json_utils::Snapshot> = if let Some(path) = matches.value_of("snapshot") {
+                let file = ::std::fs::File::open(path).map_err(|e| format!("Could not open snapshot file at path: {}, with error {}", path, e)).unwrap();
+                serde_json::from_reader(&file).unwrap()
+            } else if matches.is_present("save") {
+                return;
+            } else {
+                clap::Error::with_description("Either a --snapshot to compare with or a path to --save the snapshot to is required", clap::ErrorKind::MissingRequiredArgument).exit()
+            };
+            let (report, added, removed) = diff::diff(&baseline, &current);
+            if let Some(path) = matches.value_of("output") {
+                let file = ::std::fs::File::create(path).map_err(|e| format!("Could not create output file at path: {} with error {}", path, e)).unwrap();
+                serde_json::to_writer_pretty(&file, &report).unwrap();
+            }
+            diff::print_report(&report, current.len(), added, removed);
+        }
+        ("coverage", Some(matches)) => {
+            let min = matches.value_of("min").map(|_| value_t!(matches, "min", f32).unwrap_or_else(|e| e.exit()));
+            let mut sentences = vec![];
+            for input_path in matches.values_of("input").into_iter().flat_map(|it| it) {
+                let utterances: Vec<Utterance> = {
+                  let file = ::std::fs::File::open(input_path).map_err(|e| format!("Could not open input file at path: {}, with error {}", input_path, e)).unwrap();
+                  serde_json::from_reader(&file).unwrap()
+                };
+                sentences.extend(utterances.into_iter().filter(|it| it.keep()).map(|it| it.phrase.to_lowercase()));
+            }
+            if matches.is_present("examples") || !matches.is_present("input") {
+                sentences.extend(grammar::examples_with_mode(lang, mode).iter().map(|it| it.text.to_lowercase()));
+            }
+            let parser = build_raw_parser_with_mode(lang, mode).unwrap();
+            let context = resolver_context(reference.unwrap_or_else(json_utils::default_reference));
+            let rules = grammar::rule_names(&grammar::rules_with_mode(lang, mode).unwrap());
+            let coverage = coverage::coverage(&parser, &rules, &sentences, &context);
+            if let Some(path) = matches.value_of("output") {
+                let file = ::std::fs::File::create(path).map_err(|e| format!("Could not create output file at path: {} with error {}", path, e)).unwrap();
+                serde_json::to_writer_pretty(&file, &coverage::coverage_json(&coverage)).unwrap();
+            }
+            coverage::print_coverage(&coverage, matches.is_present("all"));
+            if let Some(min) = min {
+                let ratio = coverage::winning_ratio(&coverage);
+                if ratio < min {
+                    println!("Coverage {:.1}% is below the minimum of {:.1}%", ratio, min);
+                    ::std::process::exit(1);
+                }
+            }
+        }
+        ("lint", Some(matches)) => {
+            let grammar_path = matches
+                .value_of("grammar")
+                .map(::std::path::PathBuf::from)
+                .unwrap_or_else(|| ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../grammar"));
+            let lang_path = grammar_path.join(lang.to_string().to_lowercase());
+            let shared_path = lang_path.join("shared-rule-names.txt");
+            let shared = lint::read_shared_names(&shared_path)
+                .map_err(|e| format!("Could not read the shared rule names at path: {}, with error {}", shared_path.display(), e))
+                .unwrap();
+            let sources_path = lang_path.join("src");
+            let (mut lints, declared) = lint::lint_sources(&sources_path, &shared)
+                .map_err(|e| format!("Could not read the grammar at path: {}, with error {}", sources_path.display(), e))
+                .unwrap();
+            let rules = grammar::rules_with_mode(lang, mode).unwrap();
+            let examples = grammar::examples_with_mode(lang, mode)
+                .iter()
+                .map(|it| it.text.to_lowercase())
+                .collect::<Vec<_>>();
+            lints.extend(lint::lint_rule_set(&rules, lang, &examples));
+            let unchecked = grammar::rule_names(&rules)
+                .into_iter()
+                .filter(|it| !declared.contains(it))
+                .collect::<Vec<_>>();
+            let mut table = prettytable::Table::new();
+            table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+            table.set_titles(row!["lint", "rule", "location", "message"]);
+            for lint in &lints {
+                table.add_row(row![lint.kind.as_str(), lint.rule, lint.location.as_ref().map(|it| it.as_str()).unwrap_or(""), lint.message]);
+            }
+            table.printstd();
+            println!("Lints: {:?}", lints.len());
+            if !unchecked.is_empty() {
+                println!("Rules whose name isn't a literal of the sources, with unchecked regexes: {:?}", unchecked);
+            }
+            if !lints.is_empty() {
+                ::std::process::exit(1);
+            }
+        }
         ("test", Some(matches)) => {
             let input_path = matches.value_of("input").unwrap();
             let output_path = matches.value_of("output").unwrap();
-            let kinds = matches
-                .values_of("kinds")
-                .map(|values| {
-                    values
-                        .map(|s| OutputKind::from_str(s).unwrap())
-                        .collect::<Vec<_>>()
-                });
+            let format = value_t!(matches, "format", report::ReportFormat).unwrap_or_else(|e| e.exit());
+            let kinds = kinds_arg(matches);
             let utterances: Vec<Utterance> = {
               let file = ::std::fs::File::open(input_path).map_err(|e| format!("Could not open input file at path: {}, with error {}", input_path, e)).unwrap();;
               serde_json::from_reader(&file).unwrap()
             };
-            let parser = build_parser(lang).unwrap();
-            let default_context = Moment(Local.ymd(2017, 6, 1).and_hms(5, 00, 0));
+            let parser = build_parser_with_mode(lang, mode).unwrap();
+            let default_context = reference.unwrap_or_else(json_utils::default_reference);
             
             let output: Vec<TestOutput> = utterances.into_iter()
                 .map(|utterance| {
+                  let utterance_context = json_utils::utterance_reference(utterance.context, default_context);
                   if utterance.keep() {
-                      let context = ResolverContext::new(Interval::starting_at(default_context, Grain::Second));
+                      let context = resolver_context(utterance_context);
                       let entities = if let Some(ref kinds) = kinds {
                           parser.parse_with_kind_order(utterance.phrase.to_lowercase().as_str(), &context, &kinds).unwrap()
                       } else {
                           parser.parse(utterance.phrase.to_lowercase().as_str(), &context).unwrap()
                       };
-                      let assertion = if entities.len() == 1 {
+                      let scores = utterance.entities.as_ref().map(|expected| EntityScores::new(expected, &utterance_entities(&entities)));
+                      let assertion = if let (Some(expected), Some(scores)) = (&utterance.entities, scores) {
+                          if scores.is_perfect() {
+                              TestAssertion::Success(None)
+                          } else {
+                              TestAssertion::Failed {
+                                  expected: expected.iter().map(|it| it.value.clone()).collect(),
+                                  found: entities.iter().map(|it| it.value.clone().into()).collect(),
+                                  reason: format!("{} of the {} entities found are expected, out of {} expected entities", scores.correct, scores.found, scores.expected),
+                              }
+                          }
+                      } else if entities.len() == 1 {
                          let entity = entities.first();
                          match (entity, utterance.value) {
                             (Some(entity), Some(ref expected_value)) => {
                               let entity_value = SlotValue::from(entity.value.clone());
                               if entity.byte_range.len() == utterance.phrase.len() {
-                                  if entity_value == expected_value.clone() {
+                                  if entity_value.matches_expected(expected_value) {
                                       TestAssertion::Success(Some(expected_value.clone()))
                                   } else {
                                       TestAssertion::Failed {
@@ -246,28 +350,91 @@ fn main() {
                           phrase: utterance.phrase,
                           in_grammar: utterance.in_grammar,
                           skip_rustling: utterance.skip_rustling,
-                          context: utterance.context,
+                          context: utterance_context,
                           translation: utterance.translation,
                           output: assertion,
+                          scores,
                       }   
                   } else {
                     TestOutput {
                       phrase: utterance.phrase,
                       in_grammar: utterance.in_grammar,
                       skip_rustling: utterance.skip_rustling,
-                      context: utterance.context,
+                      context: utterance_context,
                       translation: utterance.translation,
                       output: TestAssertion::Success(None),
+                      scores: None,
                     }
                   }
                 })
                 .collect();
+            let expected_failures = matches
+                .value_of("expected_failures")
+                .map(|path| json_utils::read_expected_failures(path).map_err(|e| format!("Could not open expected failures file at path: {}, with error {}", path, e)).unwrap())
+                .unwrap_or_default();
             let total_test = output.len();
             let failed_test = output.iter().filter(|it| it.output.is_failed()).collect::<Vec<_>>().len();
+            let expected_failed_test = output.iter().filter(|it| it.output.is_failed() && expected_failures.contains(&it.phrase)).count();
             let file = ::std::fs::File::create(output_path).map_err(|e| format!("Could not create output file at path: {} with error {}", output_path, e)).unwrap();
-            serde_json::to_writer_pretty(&file, &output).unwrap();
-            println!("Total: {:?} | {:?} tests fail", total_test, failed_test);
+            let lang = lang.to_string().to_lowercase();
+            match format {
+                report::ReportFormat::Json => serde_json::to_writer_pretty(&file, &output).unwrap(),
+                report::ReportFormat::Junit => (&file).write_all(report::junit(&lang, &output, &expected_failures).as_bytes()).unwrap(),
+                report::ReportFormat::Tap => (&file).write_all(report::tap(&lang, &output, &expected_failures).as_bytes()).unwrap(),
+            }
+            println!("Total: {:?} | {:?} tests fail | {:?} of them expected", total_test, failed_test, expected_failed_test);
+            let mut scores = EntityScores::default();
+            for it in output.iter().filter_map(|it| it.scores.as_ref()) {
+                scores.add(it);
+            }
+            if scores.expected > 0 || scores.found > 0 {
+                println!("Entities: {:?} expected | {:?} found | precision {:.3} | recall {:.3}", scores.expected, scores.found, scores.precision(), scores.recall());
+            }
+            if failed_test > expected_failed_test {
+                ::std::process::exit(1);
+            }
         }
         (cmd, _) => panic!("Unknown command {}", cmd),
     }
 }
+
+fn kinds_arg(matches: &clap::ArgMatches) -> Option<Vec<OutputKind>> {
+    matches
+        .values_of("kinds")
+        .map(|values| {
+                 values
+                     .map(|s| OutputKind::from_str(s).unwrap())
+                     .collect()
+             })
+}
+
+/// The sentence given as argument, or the non-empty lines of stdin, lowercased. Lines are read
+/// as they come, so that the output of each one is printed before the next one is read.
+fn sentences(matches: &clap::ArgMatches) -> Box<dyn Iterator<Item = String>> {
+    if let Some(sentence) = matches.value_of("sentence") {
+        return Box::new(::std::iter::once(sentence.to_lowercase()));
+    }
+    let lines = ::std::io::BufReader::new(::std::io::stdin()).lines();
+    Box::new(
+        lines
+            .map(|line| line.unwrap())
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.to_lowercase()),
+    )
+}
+
+/// Entities found by the parser, with their character ranges.
+fn utterance_entities(entities: &[ParserMatch<Output>]) -> Vec<Entity> {
+    entities
+        .iter()
+        .map(|it| Entity {
+            start: it.char_range.0,
+            end: it.char_range.1,
+            value: it.value.clone().into(),
+        })
+        .collect()
+}
+
+fn resolver_context(reference: Moment<Local>) -> ResolverContext {
+    ResolverContext::new(Interval::starting_at(reference, Grain::Second))
+}
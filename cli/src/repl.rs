@@ -0,0 +1,173 @@
+use std::str::FromStr;
+
+use rustling_ontology::dimension::Payload;
+use rustling_ontology::*;
+use rustyline::error::ReadlineError;
+use rustyline::Editor;
+
+use crate::format::{Format, OutputCandidate, Printer};
+
+const HELP: &str = "\
+Type a sentence to parse it, or one of the commands:
+  :lang <lang>            parse another language
+  :kinds [<kind>,...]     filter the output kinds, all of them when empty
+  :reference [<time>]     resolve at another reference time, now when empty
+  :all                    toggle between the output of the parser and all the candidates
+  :tree <ix>              show the rule tree of a candidate of the last sentence
+  :help                   show this help
+  :quit                   exit";
+
+/// Interactive session keeping the parser built between sentences.
+pub struct Repl {
+    lang: Lang,
+    mode: InputMode,
+    parser: RawParser,
+    kinds: Vec<OutputKind>,
+    reference: Option<Moment<Local>>,
+    all_candidates: bool,
+    sentence: String,
+    candidates: Vec<OutputCandidate>,
+}
+
+impl Repl {
+    pub fn new(lang: Lang, mode: InputMode, reference: Option<Moment<Local>>) -> Repl {
+        Repl {
+            lang,
+            mode,
+            parser: build_raw_parser_with_mode(lang, mode).unwrap(),
+            kinds: OutputKind::all(),
+            reference,
+            all_candidates: false,
+            sentence: String::new(),
+            candidates: vec![],
+        }
+    }
+
+    /// Reads lines until end of input or `:quit`, with the history kept in
+    /// `~/.rustling_history`.
+    pub fn run(&mut self) {
+        let history = ::std::env::var("HOME")
+            .map(|home| ::std::path::Path::new(&home).join(".rustling_history"))
+            .ok();
+        let mut editor = Editor::<()>::new();
+        if let Some(ref history) = history {
+            let _ = editor.load_history(history);
+        }
+        println!("{}", HELP);
+        loop {
+            let prompt = format!("{}{}> ", self.lang.to_string().to_lowercase(), if self.all_candidates { "*" } else { "" });
+            match editor.readline(&prompt) {
+                Ok(line) => {
+                    let line = line.trim();
+                    if line.is_empty() {
+                        continue;
+                    }
+                    editor.add_history_entry(line);
+                    if line == ":quit" || line == ":q" {
+                        break;
+                    }
+                    if let Err(e) = self.execute(line) {
+                        println!("{}", e);
+                    }
+                }
+                Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => break,
+                Err(e) => {
+                    println!("{}", e);
+                    break;
+                }
+            }
+        }
+        if let Some(ref history) = history {
+            let _ = editor.save_history(history);
+        }
+    }
+
+    fn execute(&mut self, line: &str) -> Result<(), String> {
+        if !line.starts_with(':') {
+            self.parse(line);
+            return Ok(());
+        }
+        let mut words = line[1..].splitn(2, char::is_whitespace);
+        let command = words.next().unwrap_or("");
+        let argument = words.next().map(|it| it.trim()).unwrap_or("");
+        match command {
+            "lang" => {
+                let lang = Lang::from_str(argument)?;
+                if lang != self.lang {
+                    println!("Building the {} parser...", lang.to_string());
+                    self.parser = build_raw_parser_with_mode(lang, self.mode).map_err(|e| e.to_string())?;
+                    self.lang = lang;
+                    self.candidates.clear();
+                }
+            }
+            "kinds" => {
+                self.kinds = if argument.is_empty() {
+                    OutputKind::all()
+                } else {
+                    argument
+                        .split(',')
+                        .map(|it| OutputKind::from_str(it.trim()))
+                        .collect::<Result<_, _>>()?
+                };
+            }
+            "reference" => {
+                self.reference = if argument.is_empty() {
+                    None
+                } else {
+                    Some(json_utils::parse_reference(argument)?)
+                };
+            }
+            "all" => {
+                self.all_candidates = !self.all_candidates;
+                println!("Showing {}", if self.all_candidates { "all the candidates" } else { "the output of the parser" });
+            }
+            "tree" => {
+                let ix = argument.parse::<usize>().map_err(|_| format!("Invalid candidate index {:?}", argument))?;
+                let candidate = self
+                    .candidates
+                    .get(ix)
+                    .ok_or_else(|| format!("No candidate {} for the last sentence", ix))?;
+                self.print_tree(&candidate.node.root_node, 0);
+            }
+            "help" => println!("{}", HELP),
+            _ => return Err(format!("Unknown command :{}, type :help for the list of commands", command)),
+        }
+        Ok(())
+    }
+
+    fn parse(&mut self, sentence: &str) {
+        self.sentence = sentence.to_lowercase();
+        let context = crate::resolver_context(self.reference.unwrap_or_else(|| Moment(Local::now())));
+        let tagger = CandidateTagger {
+            output_kind_filter: &self.kinds,
+            context: &context,
+            resolve_all_candidates: self.all_candidates,
+        };
+        let mut candidates = match self.parser.candidates(&*self.sentence, &tagger) {
+            Ok(candidates) => candidates,
+            Err(e) => {
+                println!("{}", e);
+                return;
+            }
+        };
+        // Keep the indexes of the printed table for `:tree`
+        let mut printer = Printer::new(Format::Table);
+        if self.all_candidates {
+            printer.print_play(&self.parser, &self.sentence, &candidates);
+        } else {
+            candidates.retain(|c| c.tagged && c.match_.value.is_some());
+            printer.print_parse(&self.parser, &self.sentence, &candidates);
+        }
+        self.candidates = candidates;
+    }
+
+    fn print_tree(&self, node: &Node<Payload>, depth: usize) {
+        println!("{}{} {:?}",
+                 "  ".repeat(depth),
+                 self.parser.resolve_sym(&node.rule_sym).unwrap_or(""),
+                 &self.sentence[node.byte_range.0..node.byte_range.1]);
+        for child in node.children.iter() {
+            self.print_tree(child, depth + 1);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::{BTreeMap, HashSet};
+
+use json_utils::{SlotValue, TestAssertion, TestOutput};
+
+/// Format of the file written by the `test` command.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ReportFormat {
+    Json,
+    Junit,
+    Tap,
+}
+
+impl ::std::str::FromStr for ReportFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<ReportFormat, String> {
+        match s {
+            "json" => Ok(ReportFormat::Json),
+            "junit" => Ok(ReportFormat::Junit),
+            "tap" => Ok(ReportFormat::Tap),
+            _ => Err(format!("{} is not a known report format", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Passed,
+    Skipped,
+    Failed(String),
+    /// Failed, but listed in the expected failures.
+    ExpectedFailure(String),
+}
+
+impl Status {
+    pub fn of(output: &TestOutput, expected_failures: &HashSet<String>) -> Status {
+        match output.output {
+            TestAssertion::Success(None) if output.scores.is_none() => Status::Skipped,
+            TestAssertion::Success(_) => Status::Passed,
+            TestAssertion::Failed { ref expected, ref found, ref reason } => {
+                let message = format!("{}, expected: {}, found: {}",
+                                      reason,
+                                      serde_json::to_string(expected).unwrap(),
+                                      serde_json::to_string(found).unwrap());
+                if expected_failures.contains(&output.phrase) {
+                    Status::ExpectedFailure(message)
+                } else {
+                    Status::Failed(message)
+                }
+            }
+        }
+    }
+
+    pub fn is_failed(&self) -> bool {
+        if let Status::Failed(_) = self {
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Kind of the expected value, or of the found one when none is expected. Utterances expecting
+/// a list of entities are grouped together.
+fn dimension(output: &TestOutput) -> &'static str {
+    if output.scores.is_some() {
+        return "Entities";
+    }
+    match output.output {
+        TestAssertion::Success(ref value) => value.as_ref().map(SlotValue::kind),
+        TestAssertion::Failed { ref expected, ref found, .. } => {
+            expected.first().or(found.first()).map(SlotValue::kind)
+        }
+    }
+    .unwrap_or("None")
+}
+
+/// Tests grouped by dimension, each one in the order of the utterances file.
+fn groups<'a>(outputs: &'a [TestOutput], expected_failures: &HashSet<String>) -> BTreeMap<&'static str, Vec<(&'a TestOutput, Status)>> {
+    let mut groups = BTreeMap::new();
+    for output in outputs {
+        groups
+            .entry(dimension(output))
+            .or_insert_with(Vec::new)
+            .push((output, Status::of(output, expected_failures)));
+    }
+    groups
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// JUnit XML report with one test suite per language and dimension, e.g. "en.Number". Expected
+/// failures are reported as skipped tests.
+pub fn junit(lang: &str, outputs: &[TestOutput], expected_failures: &HashSet<String>) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
+    for (dimension, tests) in groups(outputs, expected_failures) {
+        let failures = tests.iter().filter(|it| it.1.is_failed()).count();
+        let skipped = tests.iter().filter(|it| !it.1.is_failed() && it.1 != Status::Passed).count();
+        xml.push_str(&format!("  <testsuite name=\"{}.{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
+                              lang, dimension, tests.len(), failures, skipped));
+        for (output, status) in tests {
+            let name = escape_xml(&output.phrase);
+            match status {
+                Status::Passed => xml.push_str(&format!("    <testcase classname=\"{}.{}\" name=\"{}\"/>\n", lang, dimension, name)),
+                Status::Skipped => xml.push_str(&format!("    <testcase classname=\"{}.{}\" name=\"{}\"><skipped/></testcase>\n", lang, dimension, name)),
+                Status::ExpectedFailure(message) => xml.push_str(&format!("    <testcase classname=\"{}.{}\" name=\"{}\"><skipped message=\"expected failure: {}\"/></testcase>\n",
+                                                                          lang, dimension, name, escape_xml(&message))),
+                Status::Failed(message) => xml.push_str(&format!("    <testcase classname=\"{}.{}\" name=\"{}\"><failure message=\"{}\"/></testcase>\n",
+                                                                 lang, dimension, name, escape_xml(&message))),
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// TAP report, with a comment line before the tests of each language and dimension. Expected
+/// failures are "TODO" tests, which don't fail the run.
+pub fn tap(lang: &str, outputs: &[TestOutput], expected_failures: &HashSet<String>) -> String {
+    let mut tap = format!("TAP version 13\n1..{}\n", outputs.len());
+    let mut ix = 0;
+    for (dimension, tests) in groups(outputs, expected_failures) {
+        tap.push_str(&format!("# {}.{}\n", lang, dimension));
+        for (output, status) in tests {
+            ix += 1;
+            // `#` starts a directive in TAP descriptions
+            let name = output.phrase.replace('#', "\\#");
+            match status {
+                Status::Passed => tap.push_str(&format!("ok {} - {}\n", ix, name)),
+                Status::Skipped => tap.push_str(&format!("ok {} - {} # SKIP\n", ix, name)),
+                Status::ExpectedFailure(message) => tap.push_str(&format!("not ok {} - {} # TODO expected failure\n  ---\n  message: {:?}\n  ...\n", ix, name, message)),
+                Status::Failed(message) => tap.push_str(&format!("not ok {} - {}\n  ---\n  message: {:?}\n  ...\n", ix, name, message)),
+            }
+        }
+    }
+    tap
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use json_utils::{NumberValue, default_reference};
+
+    fn output(phrase: &str, assertion: TestAssertion<Vec<SlotValue>, Vec<SlotValue>>) -> TestOutput {
+        TestOutput {
+            phrase: phrase.to_string(),
+            in_grammar: None,
+            skip_rustling: None,
+            context: default_reference(),
+            translation: None,
+            output: assertion,
+            scores: None,
+        }
+    }
+
+    fn outputs() -> Vec<TestOutput> {
+        let two = SlotValue::Number(NumberValue { value: 2.0 });
+        vec![
+            output("two", TestAssertion::Success(Some(two.clone()))),
+            output("a <pair>", TestAssertion::Failed { expected: vec![two.clone()], found: vec![], reason: "No entity".to_string() }),
+            output("deux", TestAssertion::Failed { expected: vec![two], found: vec![], reason: "No entity".to_string() }),
+        ]
+    }
+
+    #[test]
+    fn test_junit() {
+        let expected_failures = vec!["deux".to_string()].into_iter().collect();
+        let xml = junit("en", &outputs(), &expected_failures);
+        assert!(xml.contains("<testsuite name=\"en.Number\" tests=\"3\" failures=\"1\" skipped=\"1\">"));
+        assert!(xml.contains("name=\"a &lt;pair&gt;\"><failure message="));
+        assert!(xml.contains("name=\"deux\"><skipped message=\"expected failure: "));
+    }
+
+    #[test]
+    fn test_tap() {
+        let expected_failures = vec!["deux".to_string()].into_iter().collect();
+        let tap = tap("en", &outputs(), &expected_failures);
+        assert!(tap.starts_with("TAP version 13\n1..3\n# en.Number\nok 1 - two\nnot ok 2 - a <pair>\n"));
+        assert!(tap.contains("not ok 3 - deux # TODO expected failure\n"));
+    }
+}
@@ -0,0 +1,25 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use rustling_ontology::*;
+
+thread_local! {
+    // Building the parsers is much slower than parsing, do it once for all the runs
+    static PARSERS: Vec<Parser> = Lang::all()
+        .into_iter()
+        .map(|lang| build_parser(lang).unwrap())
+        .collect();
+}
+
+// The first byte picks the language, the rest is the sentence.
+fuzz_target!(|data: &[u8]| {
+    if let Some((&lang, text)) = data.split_first() {
+        if let Ok(text) = ::std::str::from_utf8(text) {
+            // A fixed reference time keeps the crashes reproducible
+            let context = ResolverContext::from_secs(1_500_000_000);
+            PARSERS.with(|parsers| {
+                let parser = &parsers[lang as usize % parsers.len()];
+                let _ = parser.parse(&text.to_lowercase(), &context);
+            });
+        }
+    }
+});
@@ -8,22 +8,35 @@ mod training;
 
 use rustling_ontology_values::DimensionKind::*;
 
-pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+fn rule_set_builder() -> ::rustling::RustlingResult<::rustling::RuleSetBuilder<rustling_ontology_values::Dimension>> {
     let mut b = ::rustling::RuleSetBuilder::new(
                     ::rustling::BoundariesChecker::composed_word_or_detailed(),
                     ::rustling::BoundariesChecker::separated_alphanumeric_word());
     rules::rules_numbers(&mut b)?;
+    rules::rules_digit_sequence(&mut b)?;
     rules::rules_datetime(&mut b)?;
     rules::rules_cycle(&mut b)?;
     rules::rules_duration(&mut b)?;
+    rules::rules_age(&mut b)?;
     rules::rules_temperature(&mut b)?;
     rules::rules_finance(&mut b)?;
     rules::rules_percentage(&mut b)?;
+    Ok(b)
+}
+
+pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    Ok(rule_set_builder()?.build())
+}
+
+/// Rules for raw speech recognition transcripts, on top of the regular ones.
+pub fn spoken_rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    let mut b = rule_set_builder()?;
+    rules::rules_spoken(&mut b)?;
     Ok(b.build())
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Datetime, Duration, Temperature, AmountOfMoney, Percentage];
+    return vec![Number, Ordinal, Datetime, Duration, Temperature, AmountOfMoney, Percentage, DigitSequence, Age];
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
@@ -33,6 +46,14 @@ pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Di
     training::examples_temperature(&mut v);
     training::examples_finance(&mut v);
     training::examples_percentage(&mut v);
+    training::examples_digit_sequence(&mut v);
+    training::examples_age(&mut v);
+    v
+}
+
+pub fn spoken_examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
+    let mut v = examples();
+    training::examples_spoken(&mut v);
     v
 }
 
@@ -57,4 +78,10 @@ mod test {
         let examples = examples();
         assert_examples(&rules, examples);
     }
+    #[test]
+    fn test_spoken_examples() {
+        let rules = spoken_rule_set().unwrap();
+        let examples = spoken_examples();
+        assert_examples(&rules, examples);
+    }
 }
@@ -2,8 +2,30 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::Decimal;
 use rustling_ontology_moment::{Grain, PeriodComp, Weekday, Period};
 
+// Words and abbreviations which are valid roman numerals, e.g. "Di" and "Mi" for Dienstag and
+// Mittwoch, or units like "ml"
+const ROMAN_NUMERAL_STOP_WORDS: [&str; 15] = [
+    "cc", "cd", "cl", "cm", "cv", "di", "dl", "div", "mdl", "mi", "mix", "ml", "mm", "mv", "xl",
+];
+
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    match word {
+        "halb" | "halbe" | "halben" | "halber" => Ok(2),
+        "drittel" => Ok(3),
+        "viertel" => Ok(4),
+        "funftel" | "fünftel" => Ok(5),
+        "sechstel" => Ok(6),
+        "siebtel" => Ok(7),
+        "achtel" => Ok(8),
+        "neuntel" => Ok(9),
+        "zehntel" => Ok(10),
+        _ => Err(RuleError::Invalid.into()),
+    }
+}
+
 fn german_article_regex() -> &'static str {
     r#"(?:i[nm]s?|zu[rm]?|beim?|um|w[äa]h?rend|f[uü]r) ?(?:de(?:r|m|s|n)|die|das)?"#
 }
@@ -16,7 +38,7 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
     b.rule_2("<number> per cent",
         number_check!(),
         b.reg(r"(?:%|prozente?s?|vom hundert)")?,
-        |number, _| Ok(PercentageValue(number.value().value()))
+        |number, _| Ok(PercentageValue::from(number.value()))
     );
     Ok(())
 }
@@ -104,19 +126,42 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       |_| Ok(MoneyUnitValue { unit: Some("KRW") })
     );
     b.rule_1_terminal("BTC",
-                      b.reg(r#"btc|฿|bitcoins?"#)?,
+                      b.reg(r#"btc|xbt|฿|₿|bitcoins?"#)?,
                       |_| Ok(MoneyUnitValue { unit: Some("฿") })
     );
     b.rule_1_terminal("cent",
                       b.reg(r#"cents?|penn(?:y|ies)|pence|cts?|c|¢"#)?,
                       |_| Ok(MoneyUnitValue { unit: Some("cent") })
     );
+    b.rule_1_terminal("ETH",
+                      b.reg(r#"ethereums?|ethers?|eth|ξ"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("ETH") })
+    );
+    b.rule_1_terminal("sat",
+                      b.reg(r#"satoshis?|sats?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("sat") })
+    );
+    b.rule_1_terminal("unnamed currency",
+                      b.reg(r#"m[äa]use|kr[öo]ten|tacken|[öo]cken|piepen|moneten"#)?,
+                      |_| Ok(MoneyUnitValue { unit: None })
+    );
+    b.rule_2("<number> riesen",
+             integer_check_by_range!(1),
+             b.reg(r#"riesen|mille"#)?,
+             |a, _| Ok(AmountOfMoneyValue {
+                 value: a.value().value as f64 * 1000.0,
+                 decimal: Decimal::from(a.value().value).checked_mul(Decimal::new(1000, 0)),
+                 unit: None,
+                 ..AmountOfMoneyValue::default()
+             })
+    );
     b.rule_2("<unit> <amount>",
              money_unit!(),
              number_check!(),
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
+                     decimal: b.value().decimal(),
                      unit: a.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -126,10 +171,28 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              money_unit!(),
              |a, b| Ok(AmountOfMoneyValue {
                  value: a.value().value(),
+                 decimal: a.value().decimal(),
                  unit: b.value().unit,
                  ..AmountOfMoneyValue::default()
              })
     );
+    b.rule_2("<amount-of-money> <magnitude>",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             b.reg_neg_lh(r#"(k|tsd|mio|mrd|m)\.?"#, r#"^\w"#)?,
+             |a, text_match| helpers::money_magnitude(a.value(), text_match.group(1).as_ref()));
+    b.rule_3("<number> <magnitude> <unit>",
+             number_check!(),
+             b.reg(r#"(k|tsd|mio|mrd|m)\.?"#)?,
+             money_unit!(),
+             |a, text_match, b| {
+                 let amount = AmountOfMoneyValue {
+                     value: a.value().value(),
+                     decimal: a.value().decimal(),
+                     unit: b.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 };
+                 helpers::money_magnitude(&amount, text_match.group(1).as_ref())
+             });
     b.rule_2("about <amount-of-money>",
              b.reg(r#"zirka|circa|nahezu|beinahe|ungef[äa]hr|fast|ca\.?"#)?,
              amount_of_money_check!(),
@@ -221,6 +284,15 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
     );
+    b.rule_2("<number> <unit-of-duration>",
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             unit_of_duration_check!(),
+             |number, uod| match number.value() {
+                 // Integers are handled above
+                 &NumberValue::Float(ref float) => Ok(DurationValue::new(helpers::fractional_period(float.value, uod.value().grain)?)),
+                 _ => Err(RuleError::Invalid.into()),
+             }
+    );
     b.rule_2("number.number hours",
              b.reg(r#"(\d+)\.(\d+)"#)?,
              unit_of_duration_check!(|uod: &UnitOfDurationValue| uod.grain == Grain::Hour),
@@ -255,7 +327,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(DurationValue::new(PeriodComp::minutes(value * 60 + 30).into()))
              }
     );
-    b.rule_2("half an hour",
+    b.rule_2("one and a half hour",
              b.reg(r#"anderthalb"#)?,
              unit_of_duration_check!(|uod: &UnitOfDurationValue| uod.grain == Grain::Hour),
              |_, _| Ok(DurationValue::new(PeriodComp::minutes(90).into()))
@@ -377,6 +449,60 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     Ok(())
 }
 
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<duration> old",
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             b.reg(r#"alt"#)?,
+             |duration, _| helpers::age(duration.value())
+    );
+    b.rule_2("aged <duration>",
+             b.reg(r#"im alter von"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| helpers::age(duration.value())
+    );
+    b.rule_4("aged <integer> to <duration>",
+             b.reg(r#"im alter von"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"bis|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, integer, _, duration| helpers::age_range(integer.value().value, &helpers::age(duration.value())?)
+    );
+    b.rule_4("between <integer> and <age>",
+             b.reg(r#"zwischen"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"und"#)?,
+             age_check!(|age: &AgeValue| age.is_single()),
+             |_, integer, _, age| helpers::age_range(integer.value().value, age.value())
+    );
+    b.rule_3("<integer> to <age>",
+             integer_check_by_range!(0),
+             b.reg(r#"bis|-"#)?,
+             age_check!(|age: &AgeValue| age.is_single()),
+             |integer, _, age| helpers::age_range(integer.value().value, age.value())
+    );
+    b.rule_2("<integer>-jährig",
+             integer_check_by_range!(0),
+             b.reg(r#"-?j[äa]hrige?[nrsm]?"#)?,
+             |integer, _| helpers::age_in_years(integer.value().value)
+    );
+    b.rule_2("I am <integer>",
+             b.reg(r#"(?:ich )?bin"#)?,
+             integer_check_by_range!(1, 120),
+             |_, integer| helpers::age_in_years(integer.value().value)
+    );
+    b.rule_2("older than <duration>",
+             b.reg(r#"ab"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| Ok(helpers::age_in_years_duration(duration.value())?.at_least())
+    );
+    b.rule_2("younger than <duration>",
+             b.reg(r#"unter"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| Ok(helpers::age_in_years_duration(duration.value())?.at_most())
+    );
+    Ok(())
+}
+
 pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("second (cycle)",
                       b.reg(r#"sekund(en|e)"#)?,
@@ -829,7 +955,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       |_| Ok(helpers::month_day(11, 1)?.form(Form::Celebration))
     );
     b.rule_1_terminal("Holy Joseph",
-                      b.reg(r#"sankt josef"#)?,
+                      b.reg(r#"sankt josef|josefstag"#)?,
                       |_| Ok(helpers::month_day(3, 19)?.form(Form::Celebration))
     );
     b.rule_1_terminal("Holy Florian",
@@ -864,10 +990,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"sankt leopold"#)?,
                       |_| Ok(helpers::month_day(11, 15)?.form(Form::Celebration))
     );
-    b.rule_1_terminal("Holy Joseph",
-                      b.reg(r#"josefstag"#)?,
-                      |_| Ok(helpers::month_day(3, 19)?.form(Form::Celebration))
-    );
     b.rule_1_terminal("Switzerland national celebration",
                       b.reg(r#"an der bundesfeier"#)?,
                       |_| Ok(helpers::month_day(8, 1)?.form(Form::Celebration))
@@ -2116,7 +2238,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"grade?s?|°"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
-                 unit: Some("degree"),
+                 unit: Some(TemperatureUnit::Degree),
                  latent: false,
              })
     );
@@ -2125,7 +2247,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"c(?:elsius)?\.?"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
-                 unit: Some("celsius"),
+                 unit: Some(TemperatureUnit::Celsius),
                  latent: false,
              })
     );
@@ -2134,7 +2256,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"k(?:elvin)?"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
-                 unit: Some("kelvin"),
+                 unit: Some(TemperatureUnit::Kelvin),
                  latent: false,
              })
     );
@@ -2143,7 +2265,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"f(?:ah?rh?enh?eit)?"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
-                 unit: Some("fahrenheit"),
+                 unit: Some(TemperatureUnit::Fahrenheit),
                  latent: false,
              })
     );
@@ -2152,7 +2274,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              b.reg(r#"f"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
-                 unit: Some("fahrenheit"),
+                 unit: Some(TemperatureUnit::Fahrenheit),
                  latent: false,
              })
     );
@@ -2235,7 +2357,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("billion",
                       b.reg(r#"milliarden?"#)?,
-                      |_| IntegerValue::new_with_grain(1_000_000, 6)
+                      |_| IntegerValue::new_with_grain(1_000_000_000, 9)
     );
     b.rule_1_terminal("couple",
                       b.reg(r#"(?:ein )?paar"#)?,
@@ -2325,18 +2447,72 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("decimal number",
                       b.reg(r#"(\d*,\d+)"#)?,
-                      |text_match| FloatValue::new(text_match.group(1).replace(",", ".").parse()?)
+                      |text_match| FloatValue::from_decimal(text_match.group(1).replace(",", ".").parse()?)
+    );
+    b.rule_2("<number> <multiplier>",
+             number_check!(|number: &NumberValue| !number.prefixed() && !number.suffixed()),
+             integer_check!(|integer: &IntegerValue| integer.is_multiplier()),
+             |number, multiplier| {
+                 // Integers followed by hundert, tausend... are already composed above
+                 if let &NumberValue::Integer(_) = number.value() {
+                     if !multiplier.value().group {
+                         return Err(RuleError::Invalid.into());
+                     }
+                 }
+                 helpers::multiply_number(number.value(), multiplier.value())
+             }
+    );
+    b.rule_1_terminal("integer (roman numerals)",
+                      b.reg(r#"([mdclxvi]{2,})"#)?,
+                      |text_match| IntegerValue::new(helpers::roman_numeral(text_match.group(1), &ROMAN_NUMERAL_STOP_WORDS)?)
+    );
+    b.rule_1_terminal("fraction (unicode)",
+                      b.reg(r#"(\d*)([½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅐⅛⅜⅝⅞⅑⅒])"#)?,
+                      |text_match| {
+                          let fraction = helpers::vulgar_fraction(text_match.group(2))?;
+                          if text_match.group(1).is_empty() {
+                              return Ok(fraction);
+                          }
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
+    );
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d+)/(\d+)"#)?,
+                      |text_match| helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("mixed number (numeric)",
+                      b.reg(r#"(\d+) (\d+)/(\d+)"#)?,
+                      |text_match| {
+                          let fraction = helpers::fraction(text_match.group(2).parse()?, text_match.group(3).parse()?)?;
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
+    );
+    b.rule_2("<integer> <fraction denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(halb(?:e[nr]?)?|drittel|viertel|f[üu]nftel|sechstel|siebtel|achtel|neuntel|zehntel)"#)?,
+             |integer, text_match| helpers::spelled_fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    b.rule_1_terminal("anderthalb",
+                      b.reg(r#"anderthalb"#)?,
+                      |_| FloatValue::from_decimal(Decimal::new(15, 1))
+    );
+    b.rule_2("<integer> and a half",
+             integer_check_by_range!(0),
+             b.reg(r#"ein ?halb"#)?,
+             |integer, _| helpers::mixed_number(integer.value(), &helpers::fraction(1, 2)?.into())
+    );
+    b.rule_3("<integer> und <fraction>",
+             integer_check_by_range!(0),
+             b.reg(r#"und"#)?,
+             number_check!(|number: &NumberValue| number.is_proper_fraction()),
+             |integer, _, fraction| helpers::mixed_number(integer.value(), fraction.value())
     );
     b.rule_3("number dot number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
              b.reg(r#"komma"#)?,
              integer_check!(|integer: &IntegerValue| !integer.suffixed),
              |a, _, b| {
-                 let value: f64 = format!("{}.{}", a.value().value, b.value().value).parse()?;
-                 Ok(FloatValue {
-                     value,
-                     ..FloatValue::default()
-                 })
+                 FloatValue::from_decimal(format!("{}.{}", a.value().value, b.value().value).parse()?)
              });
     b.rule_4("number dot zero ... number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
@@ -2345,15 +2521,11 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check!(|integer: &IntegerValue| !integer.suffixed),
              |a, _, zeros, b| {
                  let zeros_string =  std::iter::repeat("0").take(zeros.group(0).split_whitespace().count()).collect::<String>();
-                 let value: f64 = format!("{}.{}{}", a.value().value, zeros_string, b.value().value).parse()?;
-                 Ok(FloatValue {
-                     value,
-                     ..FloatValue::default()
-                 })
+                 FloatValue::from_decimal(format!("{}.{}{}", a.value().value, zeros_string, b.value().value).parse()?)
              });
     b.rule_1_terminal("decimal with thousands separator",
                       b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
-                      |text_match| FloatValue::new(text_match.group(1).replace(".", "").replace(",", ".").parse()?)
+                      |text_match| FloatValue::from_decimal(text_match.group(1).replace(".", "").replace(",", ".").parse()?)
     );
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|minus|negativ"#)?,
@@ -2371,6 +2543,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      NumberValue::Float(float) => {
                          FloatValue {
                              value: float.value * -1.0,
+                             decimal: float.decimal.map(|it| -it),
                              prefixed: true,
                              ..float
                          }
@@ -2433,6 +2606,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                          } else {
                              FloatValue {
                                  value: product,
+                                 decimal: float.decimal.and_then(|it| it.checked_mul(multiplier.into())),
                                  suffixed: true,
                                  ..float
                              }
@@ -2478,6 +2652,10 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"0*(\d+)(?:\.| ?(?:te(?:n|r|s)?)|(?:ste(?:n|r|s)?))"#)?,
                       |text_match| Ok(OrdinalValue::new(text_match.group(1).parse()?))
     );
+    b.rule_1_terminal("ordinal (roman numerals)",
+                      b.reg(r#"([mdclxvi]{2,})\."#)?,
+                      |text_match| Ok(OrdinalValue::new(helpers::roman_numeral(text_match.group(1), &ROMAN_NUMERAL_STOP_WORDS)?))
+    );
     b.rule_2("der <ordinal>",
              b.reg(r#"de[rsnm]|das|die"#)?,
              ordinal_check!(),
@@ -2573,3 +2751,77 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("digit sequence",
+                      b.reg(r#"\d+(?:[ .\-]\d+)*"#)?,
+                      |text_match| helpers::digit_sequence_value(text_match.group(0))
+    );
+    b.rule_1_terminal("phone number (international with country code)",
+                      b.reg(r#"(?:\+|00)(\d{1,3})(?: ?\(0\) ?|[ .\-])(\d+(?:[ .\-]\d+)*)"#)?,
+                      |text_match| helpers::international_phone_number(Some(text_match.group(1)), text_match.group(2))
+    );
+    b.rule_1_terminal("phone number (international)",
+                      b.reg(r#"\+(\d+)"#)?,
+                      |text_match| helpers::international_phone_number(None, text_match.group(1))
+    );
+    b.rule_2("flight <code>",
+             b.reg(r#"(?:der )?flug(?:nummer)?"#)?,
+             b.reg(r#"([a-z][a-z0-9]|[0-9][a-z]) ?(\d{1,4})"#)?,
+             |_, code| Ok(helpers::digit_code(code.group(2))?.prefix(code.group(1)))
+    );
+    Ok(())
+}
+
+pub fn rules_spoken(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("integer (zwo)",
+                      b.reg(r#"zwo"#)?,
+                      |_| IntegerValue::new(2)
+    );
+    b.rule_1_terminal("integer (digit sequence)",
+                      b.reg(r#"(?:null|eins|zwei|zwo|drei|vier|f[üu]nf|sechs|sieben|acht|neun)(?:,?[ -]+(?:(?:äh+m?|öh+m?|ehm+|hm+),?[ -]+)?(?:null|eins|zwei|zwo|drei|vier|f[üu]nf|sechs|sieben|acht|neun)){2,}"#)?,
+                      |text_match| {
+                          let digits = text_match.group(0)
+                              .split(|c: char| c == ' ' || c == '-' || c == ',')
+                              .filter_map(spoken_digit)
+                              .collect::<Vec<_>>();
+                          helpers::digit_sequence(&digits)
+                      }
+    );
+    b.rule_2("<number> <filler>",
+             number_check!(),
+             b.reg(r#"(?:äh+m?|öh+m?|ehm+|hm+)"#)?,
+             |number, _| Ok(number.value().clone())
+    );
+    // Years are read by hundreds ("neunzehn hundert neunundneunzig"), which the written rules compose
+    b.rule_3("military time (null <hour> uhr)",
+             b.reg(r#"null"#)?,
+             integer_check_by_range!(0, 9),
+             b.reg(r#"uhr"#)?,
+             |_, hour, _| helpers::hour(hour.value().value as u32, false)
+    );
+    b.rule_3("military time (null <hour> <minutes>)",
+             b.reg(r#"null"#)?,
+             integer_check_by_range!(0, 9),
+             integer_check_by_range!(10, 59),
+             |_, hour, minute| helpers::hour_minute(hour.value().value as u32, minute.value().value as u32, false)
+    );
+    Ok(())
+}
+
+fn spoken_digit(word: &str) -> Option<i64> {
+    match word {
+        "null" => Some(0),
+        "eins" => Some(1),
+        "zwei" | "zwo" => Some(2),
+        "drei" => Some(3),
+        "vier" => Some(4),
+        "fünf" | "funf" => Some(5),
+        "sechs" => Some(6),
+        "sieben" => Some(7),
+        "acht" => Some(8),
+        "neun" => Some(9),
+        // Filler words
+        _ => None,
+    }
+}
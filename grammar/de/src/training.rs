@@ -9,16 +9,17 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(3.0, Some("degree")), "3°", "3 °", "plus 3°", "3 ° über null","+3°");
-    example!(v, check_temperature(71.0, Some("degree")), "71 grad", "71 Grad", "plus 71 Grad", "71 Grad über null", "ein und siebzig Grad");
-    example!(v, check_temperature(-7.0, Some("degree")), "-7°", "-7 Grad", "minus sieben grad", "7 Grad unter null", "sieben grad unter dem gefrierpunkt", "7 Grad unterm gefrierpunkt");
+    example!(v, check_temperature(3.0, Some(TemperatureUnit::Degree)), "3°", "3 °", "plus 3°", "3 ° über null","+3°");
+    example!(v, check_temperature(71.0, Some(TemperatureUnit::Degree)), "71 grad", "71 Grad", "plus 71 Grad", "71 Grad über null", "ein und siebzig Grad");
+    example!(v, check_temperature(-7.0, Some(TemperatureUnit::Degree)), "-7°", "-7 Grad", "minus sieben grad", "7 Grad unter null", "sieben grad unter dem gefrierpunkt", "7 Grad unterm gefrierpunkt");
     example!(v, check_temperature(-92.0, None), "zwei und neunzig unter dem gefrierpunkt", "92 unterm gefrierpunkt");
-    example!(v, check_temperature(-18.0, Some("degree")), "-18°", "-18 Grad");
-    example!(v, check_temperature(15.0, Some("celsius")), "15 celsius", "15 Grad Celsius", "fünfzehn Grad CELSIUS", "15 C", "15°C");
-    example!(v, check_temperature(-115.0, Some("celsius")), "minus 115 celsius", "-115 Grad Celsius", "minus hundertfünfzehn Grad CELSIUS", "- 115 C", "-115°C");
-    example!(v, check_temperature(78.0, Some("fahrenheit")), "78 fahrenheit", "78 Grad Fahrenheit", "achtundsiebzig Grad FAHRENHEIT", "78 f", "78°F");
-    example!(v, check_temperature(-78.0, Some("fahrenheit")), "minus 78 fahrenheit", "-78 Grad Fahrenheit", "minus acht und siebzig Grad FAHRENHEIT", "- 78 f", "-78°F");
-    example!(v, check_temperature(130.0, Some("kelvin")), "130 kelvin", "130 Grad Kelvin", "hundert dreißig Grad KELVIN", "130 k", "130°K");
+    example!(v, check_temperature(-18.0, Some(TemperatureUnit::Degree)), "-18°", "-18 Grad");
+    example!(v, check_temperature(15.0, Some(TemperatureUnit::Celsius)), "15 celsius", "15 Grad Celsius", "fünfzehn Grad CELSIUS", "15 C", "15°C");
+    example!(v, check_temperature(-115.0, Some(TemperatureUnit::Celsius)), "minus 115 celsius", "-115 Grad Celsius", "minus hundertfünfzehn Grad CELSIUS", "- 115 C", "-115°C");
+    example!(v, check_temperature(78.0, Some(TemperatureUnit::Fahrenheit)), "78 fahrenheit", "78 Grad Fahrenheit", "achtundsiebzig Grad FAHRENHEIT", "78 f", "78°F");
+    example!(v, check_temperature(-78.0, Some(TemperatureUnit::Fahrenheit)), "minus 78 fahrenheit", "-78 Grad Fahrenheit", "minus acht und siebzig Grad FAHRENHEIT", "- 78 f", "-78°F");
+    example!(v, check_temperature(130.0, Some(TemperatureUnit::Kelvin)), "130 kelvin", "130 Grad Kelvin", "hundert dreißig Grad KELVIN", "130 k", "130°K");
+    example!(v, check_temperature(37.5, Some(TemperatureUnit::Degree)), "siebenunddreißigeinhalb Grad", "37½°");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -28,6 +29,7 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(478.0, Some("USD"), Precision::Exact), "478 US-Dollar");
     example!(v, check_finance(5.0, Some("USD"), Precision::Exact), "5 us$");
     example!(v, check_finance(674.0, Some("AUD"), Precision::Exact), "674 australische dollar");
+    example!(v, check_finance(2.5, Some("$"), Precision::Exact), "zweieinhalb dollar", "2½ dollar");
     example!(v, check_finance(7438.0, Some("AUD"), Precision::Exact), "7438 AUD");
     example!(v, check_finance(8.0, Some("CAD"), Precision::Exact), "8Can$");
     example!(v, check_finance(5.0, Some("CAD"), Precision::Approximate), "ungefähr fünf Kanadische Dollars");
@@ -73,6 +75,13 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(1047.0, Some("EUR"), Precision::Approximate), "ungefähr eintausend siebenundvierzig Euro");
     example!(v, check_finance(1001.0, Some("CHF"), Precision::Exact), "tausendeines Schweizer Frankens");
     example!(v, check_finance(500736.0, Some("EUR"), Precision::Exact), "fünfhunderttausendsiebenhundertsechsunddreißig euro");
+    example!(v, check_finance(0.5, Some("฿"), Precision::Exact), "0,5 BTC", "0,5 Bitcoin");
+    example!(v, check_finance(2.0, Some("ETH"), Precision::Exact), "2 eth", "zwei Ether");
+    example!(v, check_finance(300.0, Some("sat"), Precision::Exact), "300 sats", "dreihundert Satoshis");
+    example!(v, check_finance(1200000.0, Some("EUR"), Precision::Exact), "1,2 Mio. €", "1,2 Mio. Euro", "1,2 M€");
+    example!(v, check_finance(5000.0, Some("EUR"), Precision::Exact), "5k€", "5 Tsd. Euro");
+    example!(v, check_finance(50.0, None, Precision::Exact), "50 Mäuse", "fünfzig Kröten", "50 Tacken");
+    example!(v, check_finance(2000.0, None, Precision::Exact), "zwei Riesen", "2 Mille");
 }
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -249,6 +258,8 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     // Additional examples
     example!(v, check_moment!(c, [2013, 2, 12, 6, 0, 0]), "in anderthalb stunde");
     example!(v, check_moment!(c, [2013, 2, 12, 6, 0, 0]), "in eineinhalb std", "in eineinhalb Std.");
+    example!(v, check_moment!(c, [2013, 2, 12, 6, 15, 0]), "in ein und drei viertel stunden", "in 1¾ stunden");
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 50, 0]), "in 1/3 stunde", "in einer drittel stunde");
     example!(v, check_moment!(c, [2013, 2, 12, 5]), "in der nächsten Stunde");
     example!(v, check_moment_with_precision!(c, [2013, 3, 5], Precision::Approximate), "in fast drei Wochen");
     example!(v, check_moment!(c, [2013, 2, 12, 5, 45, 0]), "in einer Stunde und eine viertelstunde");
@@ -321,6 +332,13 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(721012), "sieben hundert einundzwanzig tausend zwölf");
     example!(v, check_integer(31256721), "ein und dreissig millionen zwei hundert sechs und fünfzig tausend sieben hundert ein und zwanzig");
     example!(v, check_ordinal(4), "vierter", "4ter");
+    example!(v, check_integer(14), "xiv");
+    example!(v, check_integer(58), "lviii");
+    example!(v, check_integer(2500000), "2,5 millionen", "zwei komma fünf millionen");
+    example!(v, check_integer(1500000000), "1,5 milliarden");
+    example!(v, check_integer(36), "3 dutzend", "drei dutzend");
+    example!(v, check_ordinal(14), "xiv.");
+    example!(v, check_ordinal(20), "das xx.");
     example!(v, check_float(1416.15), "1416,15");
     example!(v, check_float(1416.15), "1.416,15");
     example!(v, check_float(1.1), "1,1", "1,10", "01,10", "eins komma eins", "eins komma zehn");
@@ -328,6 +346,35 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     //example!(v, check_float(32.75), "32,75", "zweiunddreißig komma fünfundsiebzig");
     example!(v, check_float(10.08), "10,08", "zehn komma null acht");
     example!(v, check_float(1000000.0), "1.000.000,00");
+    example!(v, check_float(0.75), "dreiviertel", "drei viertel", "3/4", "¾");
+    example!(v, check_float(2.0 / 3.0), "zwei drittel", "zweidrittel", "⅔");
+    example!(v, check_float(0.5), "ein halb", "einhalb", "½");
+    example!(v, check_float(1.5), "anderthalb", "eineinhalb", "1½", "1 1/2");
+    example!(v, check_float(2.5), "zweieinhalb", "zwei ein halb");
     example!(v, check_ordinal(44), "der vierundvierzigste");
     example!(v, check_integer(455628), "vierhundertfünfundfünfzigtausendsechshundertachtundzwanzig");
-}
\ No newline at end of file
+}
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age!([34]), "34 jahre alt", "ich bin 34", "im alter von 34 jahren");
+    example!(v, check_age!([5]), "5-jährige", "fünf jahre alt");
+    example!(v, check_age!([6], [12]), "im alter von 6 bis 12 jahren");
+    example!(v, check_age(Some(period!(12)), None), "ab 12 jahren");
+    example!(v, check_age(None, Some(period!(18))), "unter 18 jahren");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("03012345678"), "030 12345678");
+    example!(v, check_phone_number("4930123456"), "+49 30 123456");
+    example!(v, check_digit_sequence("4815"), "4 8 1 5");
+    example!(v, check_digit_sequence("400"), "Flug LH 400");
+}
+
+pub fn examples_spoken(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_integer(3012345), "drei null eins zwei drei vier fünf", "drei null eins, äh, zwo drei vier fünf");
+    example!(v, check_integer(102), "hundert äh zwei");
+    example!(v, check_integer(2), "zwo");
+    example!(v, check_moment!(c, [2013, 2, 12, 7]), "null sieben uhr", "um null sieben uhr");
+    example!(v, check_moment!(c, [2013, 2, 12, 9, 15]), "null neun fünfzehn");
+}
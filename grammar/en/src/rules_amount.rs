@@ -2,12 +2,13 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::Decimal;
 
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> per cent",
         number_check!(),
         b.reg(r"per ?cents?")?,
-        |number, _| Ok(PercentageValue(number.value().value()))
+        |number, _| Ok(PercentageValue::from(number.value()))
     );
     Ok(())
 }
@@ -106,19 +107,54 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       |_| Ok(MoneyUnitValue { unit: Some("KRW") })
     );
     b.rule_1_terminal("฿",
-                      b.reg(r#"bitcoins?"#)?,
+                      b.reg(r#"bitcoins?|btc|xbt|₿"#)?,
                       |_| Ok(MoneyUnitValue { unit: Some("฿") })
     );
     b.rule_1_terminal("cent",
-                      b.reg(r#"centimes?|cents?|penn(?:y|ies)"#)?,
+                      b.reg(r#"centimes?|cents?|penn(?:y|ies)|pence"#)?,
                       |_| Ok(MoneyUnitValue { unit: Some("cent") })
     );
+    b.rule_1_terminal("ETH",
+                      b.reg(r#"ethereums?|ethers?|eth|ξ"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("ETH") })
+    );
+    b.rule_1_terminal("sat",
+                      b.reg(r#"satoshis?|sats?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("sat") })
+    );
+    b.rule_2("<number> grand",
+             integer_check_by_range!(1),
+             b.reg(r#"grand"#)?,
+             |a, _| {
+                 Ok(AmountOfMoneyValue {
+                     value: a.value().value as f64 * 1000.0,
+                     decimal: Decimal::from(a.value().value).checked_mul(Decimal::new(1000, 0)),
+                     unit: None,
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_1_terminal("a fiver|a tenner",
+                      b.reg(r#"an? (fiver|tenner)"#)?,
+                      |text_match| {
+                          let value: i64 = match text_match.group(1).as_ref() {
+                              "fiver" => 5,
+                              "tenner" => 10,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          Ok(AmountOfMoneyValue {
+                              value: value as f64,
+                              decimal: Some(value.into()),
+                              unit: None,
+                              ..AmountOfMoneyValue::default()
+                          })
+                      });
     b.rule_2("<unit> <amount>",
              money_unit!(),
              number_check!(),
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
+                     decimal: b.value().decimal(),
                      unit: a.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -129,10 +165,28 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     decimal: a.value().decimal(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
              });
+    b.rule_2("<amount-of-money> <magnitude>",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             b.reg_neg_lh(r#"(k|m|bn)\.?"#, r#"^\w"#)?,
+             |a, text_match| helpers::money_magnitude(a.value(), text_match.group(1).as_ref()));
+    b.rule_3("<number> <magnitude> <unit>",
+             number_check!(),
+             b.reg(r#"(k|m|bn)\.?"#)?,
+             money_unit!(),
+             |a, text_match, b| {
+                 let amount = AmountOfMoneyValue {
+                     value: a.value().value(),
+                     decimal: a.value().decimal(),
+                     unit: b.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 };
+                 helpers::money_magnitude(&amount, text_match.group(1).as_ref())
+             });
     b.rule_2("about <amount-of-money>",
              b.reg(r#"(?:about|approx(?:\.|imately)?|close to|near(?: to)?|around|almost)"#)?,
              amount_of_money_check!(),
@@ -166,11 +220,11 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              });
     b.rule_2("<latent temp> degrees",
              temperature_check!(|temp: &TemperatureValue| temp.latent),
-             b.reg(r#"(?:deg(?:ree?)?s?\.?)"#)?,
+             b.reg(r#"(?:deg(?:ree?)?s?\.?)|°"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("degree"),
+                     unit: Some(TemperatureUnit::Degree),
                      latent: true,
                  })
              });
@@ -180,7 +234,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
                  })
              });
@@ -190,7 +244,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
                  })
              });
@@ -200,8 +254,38 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("kelvin"),
+                     unit: Some(TemperatureUnit::Kelvin),
+                     latent: false,
+                 })
+             });
+    b.rule_2("<temp> °C",
+             temperature_check!(|temp: &TemperatureValue| temp.latent),
+             b.reg(r#"° ?c\.?|℃"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some(TemperatureUnit::Celsius),
+                     latent: false,
+                 })
+             });
+    b.rule_2("<temp> °F",
+             temperature_check!(|temp: &TemperatureValue| temp.latent),
+             b.reg(r#"° ?f\.?|℉"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some(TemperatureUnit::Fahrenheit),
+                     latent: false,
+                 })
+             });
+    b.rule_2("<temp> below zero",
+             temperature_check!(|temp: &TemperatureValue| temp.value >= 0.0),
+             b.reg(r#"below (?:zero|0)"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: -1.0 * a.value().value,
                      latent: false,
+                     ..*a.value()
                  })
              });
     Ok(())
@@ -144,6 +144,32 @@ pub fn rules_celebration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                               .form(Form::Celebration))
                       }
     );
+    b.rule_1_terminal("ramadan",
+                      b.reg(r#"(?:the )?(?:month of )?rama[dz]h?an"#)?,
+                      |_| Ok(helpers::hijri_month_day(9, 1)?
+                          .span_to(&helpers::hijri_month_day(10, 1)?, false)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("eid al-fitr",
+                      b.reg(r#"eid(?: al| ul| el)?[- ]?fitr"#)?,
+                      |_| Ok(helpers::hijri_month_day(10, 1)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("eid al-adha",
+                      b.reg(r#"eid(?: al| ul| el)?[- ]?adha"#)?,
+                      |_| Ok(helpers::hijri_month_day(12, 10)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("rosh hashanah",
+                      b.reg(r#"rosh hash?ann?ah?"#)?,
+                      |_| Ok(helpers::hebrew_month_day(7, 1)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("yom kippur",
+                      b.reg(r#"yom kipp?ur"#)?,
+                      |_| Ok(helpers::hebrew_month_day(7, 10)?
+                          .form(Form::Celebration))
+    );
 
     Ok(())
 }
\ No newline at end of file
@@ -857,7 +857,7 @@ pub fn rules_datetime_with_duration(b: &mut RuleSetBuilder<Dimension>) -> Rustli
              b.reg(r#"from now"#)?,
              |_, duration, _| duration.value().in_present()
     );
-    b.rule_3("in <duration>",
+    b.rule_3("in <duration> time",
              b.reg(r#"in"#)?,
              duration_check!(),
              b.reg(r#"(?:' )? times?"#)?,
@@ -54,6 +54,21 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
     );
+    b.rule_2("<number> <unit-of-duration>",
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             unit_of_duration_check!(),
+             |number, uod| match number.value() {
+                 // Integers are handled above
+                 &NumberValue::Float(ref float) => Ok(DurationValue::new(helpers::fractional_period(float.value, uod.value().grain)?)),
+                 _ => Err(RuleError::Invalid.into()),
+             }
+    );
+    b.rule_3("<fraction> of a <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.is_proper_fraction()),
+             b.reg(r#"of an?"#)?,
+             unit_of_duration_check!(),
+             |fraction, _, uod| Ok(DurationValue::new(helpers::fractional_period(fraction.value().value(), uod.value().grain)?))
+    );
     b.rule_3("<integer> more <unit-of-duration>",
              integer_check_by_range!(0),
              b.reg(r#"more"#)?,
@@ -171,4 +186,70 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |duration, _| Ok(duration.value().clone().precision(Precision::Exact))
     );
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<duration> old",
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             b.reg(r#"old|of age"#)?,
+             |duration, _| helpers::age(duration.value())
+    );
+    b.rule_2("aged <duration>",
+             b.reg(r#"aged"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| helpers::age(duration.value())
+    );
+    b.rule_4("aged <integer> to <duration>",
+             b.reg(r#"aged"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"to|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, integer, _, duration| helpers::age_range(integer.value().value, &helpers::age(duration.value())?)
+    );
+    b.rule_4("between <integer> and <age>",
+             b.reg(r#"between"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"and"#)?,
+             age_check!(|age: &AgeValue| age.is_single()),
+             |_, integer, _, age| helpers::age_range(integer.value().value, age.value())
+    );
+    b.rule_3("<integer> to <age>",
+             integer_check_by_range!(0),
+             b.reg(r#"to|-"#)?,
+             age_check!(|age: &AgeValue| age.is_single()),
+             |integer, _, age| helpers::age_range(integer.value().value, age.value())
+    );
+    b.rule_4("<integer>-<unit-of-duration>-old",
+             integer_check_by_range!(0),
+             b.reg(r#"-"#)?,
+             unit_of_duration_check!(),
+             b.reg(r#"-olds?"#)?,
+             |integer, _, uod, _| helpers::age(&DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+    );
+    b.rule_2("I am <integer>",
+             b.reg(r#"i'?m|i am"#)?,
+             integer_check_by_range!(1, 120),
+             |_, integer| helpers::age_in_years(integer.value().value)
+    );
+    b.rule_2("over <age>",
+             b.reg(r#"over|above|older than|at least"#)?,
+             age_check!(|age: &AgeValue| age.is_single()),
+             |_, age| Ok(age.value().clone().at_least())
+    );
+    b.rule_2("under <age>",
+             b.reg(r#"under|below|younger than"#)?,
+             age_check!(|age: &AgeValue| age.is_single()),
+             |_, age| Ok(age.value().clone().at_most())
+    );
+    b.rule_2("<age> and over",
+             age_check!(|age: &AgeValue| age.is_single()),
+             b.reg(r#"(?:and|or) (?:over|older|above)"#)?,
+             |age, _| Ok(age.value().clone().at_least())
+    );
+    b.rule_2("<age> and under",
+             age_check!(|age: &AgeValue| age.is_single()),
+             b.reg(r#"(?:and|or) (?:under|younger|below)"#)?,
+             |age, _| Ok(age.value().clone().at_most())
+    );
+    Ok(())
+}
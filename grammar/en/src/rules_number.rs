@@ -5,6 +5,39 @@ use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
 
+// Words and abbreviations which are valid roman numerals, e.g. "mix", "cd" or "mi" for miles
+const ROMAN_NUMERAL_STOP_WORDS: [&str; 21] = [
+    "cc", "cd", "cl", "cm", "cv", "dc", "di", "dl", "dx", "li", "mc", "md", "mi", "ml", "mm", "mv",
+    "xl", "civ", "div", "mix", "mdl",
+];
+
+// Denominators are singular after "one" and plural otherwise, which keeps "twenty third" an
+// ordinal
+fn fraction_denominator(word: &str, numerator: i64) -> RuleResult<i64> {
+    let (stem, plural) = match word {
+        "halves" => ("half", true),
+        word if word.ends_with('s') => (&word[..word.len() - 1], true),
+        word => (word, false),
+    };
+    if plural == (numerator == 1) {
+        return Err(RuleError::Invalid.into());
+    }
+    match stem {
+        "half" => Ok(2),
+        "third" => Ok(3),
+        // "4 quarters" are rather a year than 1
+        "quarter" if numerator >= 4 => Err(RuleError::Invalid.into()),
+        "quarter" | "fourth" => Ok(4),
+        "fifth" => Ok(5),
+        "sixth" => Ok(6),
+        "seventh" => Ok(7),
+        "eighth" => Ok(8),
+        "ninth" => Ok(9),
+        "tenth" => Ok(10),
+        _ => Err(RuleError::Invalid.into()),
+    }
+}
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_3("intersect (with and)",
              number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
@@ -82,54 +115,125 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  IntegerValue::new_with_grain(value, 1)
              });
 
-    b.rule_1_terminal("100, 1_000, 1_000_000, 1_000_000_000",
-                      b.reg(r#"(hundred|thousand|million|billion)s?"#)?,
+    b.rule_1_terminal("100, 1_000, 1_000_000, 1_000_000_000, 1_000_000_000_000...",
+                      b.reg(r#"(hundred|thousand|million|billion|trillion|quadrillion|quintillion)s?"#)?,
                       |text_match| {
                           let (value, grain) = match text_match.group(1).as_ref() {
                               "hundred" => (100, 2),
                               "thousand" => (1_000, 3),
                               "million" => (1_000_000, 6),
                               "billion" => (1_000_000_000, 9),
+                              "trillion" => (1_000_000_000_000, 12),
+                              "quadrillion" => (1_000_000_000_000_000, 15),
+                              "quintillion" => (1_000_000_000_000_000_000, 18),
                               _ => return Err(RuleError::Invalid.into()),
                           };
                           IntegerValue::new_with_grain(value, grain)
                       }
     );
 
-    b.rule_2("200..900, 2_000..9_000, 2_000_000..9_000_000_000",
+    b.rule_2("200..900, 2_000..9_000, 2_000_000..9_000_000_000, ...",
              integer_check_by_range!(1, 999),
-             b.reg(r#"(hundred|thousand|million|billion)s?"#)?,
+             b.reg(r#"(hundred|thousand|million|billion|trillion|quadrillion|quintillion)s?"#)?,
              |integer, text_match| {
                  let (value, grain) = match text_match.group(1).as_ref() {
                      "hundred" => (100, 2),
                      "thousand" => (1_000, 3),
                      "million" => (1_000_000, 6),
                      "billion" => (1_000_000_000, 9),
+                     "trillion" => (1_000_000_000_000, 12),
+                     "quadrillion" => (1_000_000_000_000_000, 15),
+                     "quintillion" => (1_000_000_000_000_000_000, 18),
                      _ => return Err(RuleError::Invalid.into()),
                  };
-                 IntegerValue::new_with_grain(integer.value().value * value, grain)
+                 helpers::multiply_number(&integer.value().clone().into(), &IntegerValue::new_with_grain(value, grain)?)
+             }
+    );
+    b.rule_1_terminal("dozen",
+                      b.reg(r#"dozen"#)?,
+                      |_| Ok(IntegerValue {
+                          value: 12,
+                          grain: Some(1),
+                          group: true,
+                          ..IntegerValue::default()
+                      })
+    );
+    b.rule_2("<number> <multiplier>",
+             number_check!(|number: &NumberValue| !number.prefixed() && !number.suffixed()),
+             integer_check!(|integer: &IntegerValue| integer.is_multiplier()),
+             |number, multiplier| {
+                 // Integers followed by hundred, thousand... are already composed above
+                 if let &NumberValue::Integer(_) = number.value() {
+                     if !multiplier.value().group {
+                         return Err(RuleError::Invalid.into());
+                     }
+                 }
+                 helpers::multiply_number(number.value(), multiplier.value())
+             }
+    );
+    b.rule_2("<number (numeric)> <multiplier>",
+             b.reg(r#"(\d{1,3}(?:,\d{3})+(?:\.\d+)?|\d+(?:\.\d+)?)"#)?,
+             integer_check!(|integer: &IntegerValue| integer.is_multiplier()),
+             |text_match, multiplier| {
+                 let number = text_match.group(1).replace(",", "");
+                 let number: NumberValue = if number.contains('.') {
+                     FloatValue::from_decimal(number.parse()?)?.into()
+                 } else {
+                     IntegerValue::new(number.parse()?)?.into()
+                 };
+                 helpers::multiply_number(&number, multiplier.value())
+             }
+    );
+    b.rule_1_terminal("integer (roman numerals)",
+                      b.reg(r#"([mdclxvi]{2,})"#)?,
+                      |text_match| IntegerValue::new(helpers::roman_numeral(&text_match.group(1), &ROMAN_NUMERAL_STOP_WORDS)?)
+    );
+    b.rule_1_terminal("fraction (unicode)",
+                      b.reg(r#"(\d*)([½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅐⅛⅜⅝⅞⅑⅒])"#)?,
+                      |text_match| {
+                          let fraction = helpers::vulgar_fraction(text_match.group(2))?;
+                          if text_match.group(1).is_empty() {
+                              return Ok(fraction);
+                          }
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
+    );
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d+)/(\d+)"#)?,
+                      |text_match| helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("mixed number (numeric)",
+                      b.reg(r#"(\d+) (\d+)/(\d+)"#)?,
+                      |text_match| {
+                          let fraction = helpers::fraction(text_match.group(2).parse()?, text_match.group(3).parse()?)?;
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
+    );
+    b.rule_2("<integer> <fraction denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(hal(?:f|ves)|thirds?|quarters?|fourths?|fifths?|sixths?|sevenths?|eighths?|ninths?|tenths?)"#)?,
+             |integer, text_match| {
+                 let denominator = fraction_denominator(text_match.group(1), integer.value().value)?;
+                 helpers::spelled_fraction(integer.value().value, denominator)
              }
     );
-    b.rule_2("<integer> and a half",
-             integer_check!(),
-             b.reg(r#"and a half"#)?,
-             |integer, _| FloatValue::new(integer.value().value as f64 + 0.5)
+    b.rule_2("a <fraction denominator>",
+             b.reg(r#"an?"#)?,
+             b.reg(r#"(half|third|quarter|fourth|fifth|sixth|seventh|eighth|ninth|tenth)"#)?,
+             |_, text_match| helpers::spelled_fraction(1, fraction_denominator(text_match.group(1), 1)?)
     );
-    b.rule_2("<integer> and a quarter",
-             integer_check!(),
-             b.reg(r#"and a quarter"#)?,
-             |integer, _| FloatValue::new(integer.value().value as f64 + 0.25)
+    b.rule_3("<integer> and <fraction>",
+             integer_check_by_range!(0),
+             b.reg(r#"and"#)?,
+             number_check!(|number: &NumberValue| number.is_proper_fraction()),
+             |integer, _, fraction| helpers::mixed_number(integer.value(), fraction.value())
     );
     b.rule_3("number dot number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
              b.reg(r#"dot|point"#)?,
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
              |a, _, b| {
-                 let value: f64 = format!("{}.{}", a.value().value, b.value().value).parse()?;
-                 Ok(FloatValue {
-                     value,
-                     ..FloatValue::default()
-                 })
+                 FloatValue::from_decimal(format!("{}.{}", a.value().value, b.value().value).parse()?)
              });
     b.rule_4("number dot zero... number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
@@ -138,11 +242,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
              |a, _, zeros, b| {
                  let zeros_string =  std::iter::repeat("0").take(zeros.group(0).split_whitespace().count()).collect::<String>();
-                 let value: f64 = format!("{}.{}{}", a.value().value, zeros_string, b.value().value).parse()?;
-                 Ok(FloatValue {
-                     value,
-                     ..FloatValue::default()
-                 })
+                 FloatValue::from_decimal(format!("{}.{}{}", a.value().value, zeros_string, b.value().value).parse()?)
 
              });
     b.rule_2("numbers prefix with -, negative or minus",
@@ -162,6 +262,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      NumberValue::Float(float) => {
                          FloatValue {
                              value: float.value * -1.0,
+                             decimal: float.decimal.map(|it| -it),
                              prefixed: true,
                              ..float
                          }
@@ -286,9 +387,47 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(OrdinalValue::new(integer.value().value + ordinal.value().value))
              }
     );
+    b.rule_1_terminal("ordinal (roman numerals)",
+                      b.reg(r#"([mdclxvi]{2,})(st|nd|rd|th)"#)?,
+                      |text_match| {
+                          let value = helpers::roman_numeral(&text_match.group(1), &ROMAN_NUMERAL_STOP_WORDS)?;
+                          let suffix = match (value % 10, value % 100) {
+                              (_, 11..=13) => "th",
+                              (1, _) => "st",
+                              (2, _) => "nd",
+                              (3, _) => "rd",
+                              _ => "th",
+                          };
+                          if text_match.group(2) != suffix {
+                              return Err(RuleError::Invalid.into());
+                          }
+                          Ok(OrdinalValue::new(value))
+                      }
+    );
     b.rule_2("the <ordinal>",
              b.reg(r#"the"#)?,
              ordinal_check!(),
              |_, ordinal| Ok((*ordinal.value()).prefixed()));
     Ok(())
 }
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("digit sequence",
+                      b.reg(r#"\d+(?:[ .\-]\d+)*"#)?,
+                      |text_match| helpers::digit_sequence_value(text_match.group(0))
+    );
+    b.rule_1_terminal("phone number (international with country code)",
+                      b.reg(r#"(?:\+|00)(\d{1,3})(?: ?\(0\) ?|[ .\-])(\d+(?:[ .\-]\d+)*)"#)?,
+                      |text_match| helpers::international_phone_number(Some(text_match.group(1)), text_match.group(2))
+    );
+    b.rule_1_terminal("phone number (international)",
+                      b.reg(r#"\+(\d+)"#)?,
+                      |text_match| helpers::international_phone_number(None, text_match.group(1))
+    );
+    b.rule_2("flight <code>",
+             b.reg(r#"flight(?: number)?"#)?,
+             b.reg(r#"([a-z][a-z0-9]|[0-9][a-z]) ?(\d{1,4})"#)?,
+             |_, code| Ok(helpers::digit_code(code.group(2))?.prefix(code.group(1)))
+    );
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use rustling::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
+
+/// Digits read one by one, possibly interrupted by filler words
+const DIGIT_SEQUENCE: &str = r#"(?:zero|oh|one|two|three|four|five|six|seven|eight|nine)(?:,?[ -]+(?:(?:um+|uh+|erm?|hmm+),?[ -]+)?(?:zero|oh|one|two|three|four|five|six|seven|eight|nine)){2,}"#;
+
+pub fn rules_spoken(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("integer (digit sequence)",
+                      b.reg(DIGIT_SEQUENCE)?,
+                      |text_match| helpers::digit_sequence(&spoken_digits(text_match.group(0)))
+    );
+    b.rule_1_terminal("digit sequence (spoken)",
+                      b.reg(DIGIT_SEQUENCE)?,
+                      |text_match| helpers::spoken_digit_sequence_value(&spoken_digits(text_match.group(0)))
+    );
+    b.rule_2("<number> <filler>",
+             number_check!(),
+             b.reg(r#"(?:um+|uh+|erm?|hmm+)"#)?,
+             |number, _| Ok(number.value().clone())
+    );
+    b.rule_2("oh as 0 + number [1-9] (as relative minutes)",
+             b.reg(r#"oh"#)?,
+             integer_check_by_range!(1, 9),
+             |_, a| helpers::relative_minute_value_prefixed(a.value().value as i32)
+    );
+    // 1900-2199 are composed by the written rules, see "year as integer composed 1900-2199"
+    b.rule_2("year (paired digits) 1100-1899",
+             integer_check_by_range!(11, 18),
+             integer_check_by_range!(10, 99),
+             |a, b| {
+                 let y = a.value().value * 100 + b.value().value;
+                 Ok(helpers::year(y as i32)?.latent())
+             }
+    );
+    b.rule_3("year (paired digits with oh) 1101-2109",
+             integer_check_by_range!(11, 21),
+             b.reg(r#"oh|zero"#)?,
+             integer_check_by_range!(1, 9),
+             |a, _, b| {
+                 let y = a.value().value * 100 + b.value().value;
+                 Ok(helpers::year(y as i32)?.latent())
+             }
+    );
+    b.rule_3("military time (oh <hour> hundred)",
+             b.reg(r#"oh|zero"#)?,
+             integer_check_by_range!(0, 9),
+             b.reg(r#"hundred(?: hours)?"#)?,
+             |_, hour, _| helpers::hour(hour.value().value as u32, false)
+    );
+    b.rule_2("military time (<hour> hundred hours)",
+             integer_check_by_range!(10, 23),
+             b.reg(r#"hundred hours"#)?,
+             |hour, _| helpers::hour(hour.value().value as u32, false)
+    );
+    b.rule_3("military time (oh <hour> <minutes>)",
+             b.reg(r#"oh|zero"#)?,
+             integer_check_by_range!(0, 9),
+             integer_check_by_range!(10, 59),
+             |_, hour, minute| helpers::hour_minute(hour.value().value as u32, minute.value().value as u32, false)
+    );
+    b.rule_3("military time (<hour> <minutes> hours)",
+             integer_check_by_range!(10, 23),
+             integer_check_by_range!(10, 59),
+             b.reg(r#"hours"#)?,
+             |hour, minute, _| helpers::hour_minute(hour.value().value as u32, minute.value().value as u32, false)
+    );
+    Ok(())
+}
+
+fn spoken_digits(text: &str) -> Vec<i64> {
+    text.split(|c: char| c == ' ' || c == '-' || c == ',')
+        .filter_map(spoken_digit)
+        .collect()
+}
+
+fn spoken_digit(word: &str) -> Option<i64> {
+    match word {
+        "zero" | "oh" => Some(0),
+        "one" => Some(1),
+        "two" => Some(2),
+        "three" => Some(3),
+        "four" => Some(4),
+        "five" => Some(5),
+        "six" => Some(6),
+        "seven" => Some(7),
+        "eight" => Some(8),
+        "nine" => Some(9),
+        // Filler words
+        _ => None,
+    }
+}
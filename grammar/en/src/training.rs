@@ -10,13 +10,19 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(3.0, Some("degree")), "three degrees");
-    example!(v, check_temperature(32.0, Some("celsius")), "thirty two degrees celsius", "thirty two degrees centigrade");
-    example!(v, check_temperature(-27.0, Some("celsius")), "minus twenty seven celsius");
-    example!(v, check_temperature(-5.0, Some("fahrenheit")), "minus five degrees fahrenheit");
-    example!(v, check_temperature(168.0, Some("fahrenheit")), "one hundred and sixty-eight fahrenheit");
-    example!(v, check_temperature(10.0, Some("kelvin")), "ten degrees kelvin");
-    example!(v, check_temperature(21.0, Some("kelvin")), "twenty one kelvin");
+    example!(v, check_temperature(3.0, Some(TemperatureUnit::Degree)), "three degrees", "3°");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Degree)), "five degrees below zero", "minus 5 degrees", "-5°");
+    example!(v, check_temperature(-5.0, None), "five below zero");
+    example!(v, check_temperature(21.0, Some(TemperatureUnit::Celsius)), "21°C", "21 °C", "21℃");
+    example!(v, check_temperature(70.0, Some(TemperatureUnit::Fahrenheit)), "70°F", "70 °f", "70℉");
+    example!(v, check_temperature(-10.0, Some(TemperatureUnit::Celsius)), "-10°C", "ten degrees celsius below zero");
+    example!(v, check_temperature(32.0, Some(TemperatureUnit::Celsius)), "thirty two degrees celsius", "thirty two degrees centigrade");
+    example!(v, check_temperature(-27.0, Some(TemperatureUnit::Celsius)), "minus twenty seven celsius");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Fahrenheit)), "minus five degrees fahrenheit");
+    example!(v, check_temperature(168.0, Some(TemperatureUnit::Fahrenheit)), "one hundred and sixty-eight fahrenheit");
+    example!(v, check_temperature(10.0, Some(TemperatureUnit::Kelvin)), "ten degrees kelvin");
+    example!(v, check_temperature(21.0, Some(TemperatureUnit::Kelvin)), "twenty one kelvin");
+    example!(v, check_temperature(98.5, Some(TemperatureUnit::Fahrenheit)), "ninety eight and a half degrees fahrenheit", "98½ degrees fahrenheit");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -40,9 +46,18 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(200.0, Some("CNY"), Precision::Exact), "two hundred yuan");
     example!(v, check_finance(7.0, Some("KRW"), Precision::Exact), "seven wons");
     example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "three bitcoins");
+    example!(v, check_finance(0.5, Some("฿"), Precision::Exact), "0.5 BTC", "0.5 bitcoins");
+    example!(v, check_finance(2.0, Some("ETH"), Precision::Exact), "2 eth", "two ether");
+    example!(v, check_finance(300.0, Some("sat"), Precision::Exact), "300 sats", "three hundred satoshis");
+    example!(v, check_finance(5000.0, Some("$"), Precision::Exact), "5k dollars", "5 k dollars", "five thousand dollars");
+    example!(v, check_finance(2000000000.0, Some("EUR"), Precision::Exact), "2bn euros", "2 bn euros");
+    example!(v, check_finance(2000.0, None, Precision::Exact), "2 grand", "two grand");
+    example!(v, check_finance(10.0, None, Precision::Exact), "a tenner");
+    example!(v, check_finance(5.0, None, Precision::Exact), "a fiver");
     example!(v, check_finance(2.05, Some("EUR"), Precision::Exact), "two euros and five cents", "two euros five centimes", "two point zero five euros");
-    example!(v, check_finance(5.0, Some("cent"), Precision::Exact), "five cents", "five centimes");
+    example!(v, check_finance(5.0, Some("cent"), Precision::Exact), "five cents", "five centimes", "five pence", "five pennies");
     example!(v, check_finance(1.0, Some("cent"), Precision::Exact), "one cent", "one centime");
+    example!(v, check_finance(2.5, Some("$"), Precision::Exact), "two and a half dollars", "two dollars and a half", "2 1/2 dollars");
 }
 
 
@@ -154,6 +169,11 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 8, 30, 18], [2013, 9, 3, 0]), "labor day weekend");
     example!(v, check_moment!(c, [2013, 10, 31]), "halloween");
     example!(v, check_moment!(c, [2013, 11, 28]), "thanksgiving day", "thanksgiving");
+    example!(v, check_moment_span!(c, [2013, 7, 9], [2013, 8, 8]), "ramadan", "the month of ramadan");
+    example!(v, check_moment!(c, [2013, 8, 8]), "eid al-fitr", "eid ul fitr");
+    example!(v, check_moment!(c, [2013, 10, 15]), "eid al-adha");
+    example!(v, check_moment!(c, [2013, 9, 5]), "rosh hashanah", "rosh hashana");
+    example!(v, check_moment!(c, [2013, 9, 14]), "yom kippur");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 29, 58], [2013, 2, 12, 4, 30, 00]), "last two seconds");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 01], [2013, 2, 12, 4, 30, 04]), "next three seconds");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 28], [2013, 2, 12, 4, 30]), "last two minutes");
@@ -207,6 +227,9 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 1, 30], Precision::Approximate), "about one hour thirty", "for around one hour and thirty minutes");
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "during about a quarter of an hour"); // , "around a quarter hour");
     example!(v, check_duration!([0, 0, 0, 0, 0, 45]), "for three quarters of an hour");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 20]), "for a third of an hour", "during one third of an hour");
+    example!(v, check_duration!([0, 0, 0, 0, 2, 45]), "for two and three quarters hours", "for 2¾ hours");
+    example!(v, check_duration!([0, 0, 1, 3, 12]), "during 1½ weeks");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "during one hour");
     example!(v, check_duration!([0, 0, 2]), "for two weeks");
     example!(v, check_duration!([0, 0, 0, 2], Precision::Approximate), "around two days");
@@ -251,8 +274,63 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v,
              check_integer(31256721),
              "thirty-one million two hundred fifty six thousand seven hundred twenty one");
+    example!(v, check_integer(14), "xiv");
+    example!(v, check_integer(58), "lviii");
+    example!(v, check_integer(1994), "mcmxciv");
+    example!(v, check_integer(5000000), "5 million", "five million");
+    example!(v, check_integer(2500000), "2.5 million", "two point five million");
+    example!(v, check_integer(36), "3 dozen", "three dozen");
+    example!(v, check_integer(1500000000), "1.5 billion");
+    example!(v, check_integer(31400000000000), "thirty one point four trillion", "31.4 trillion");
+    example!(v,
+             check_integer(9_000_000_000_000_000_123),
+             "nine quintillion one hundred twenty three");
+    example!(v, check_float(12e18), "twelve quintillion");
+    example!(v, check_float(0.75), "three quarters", "3/4", "¾");
+    example!(v, check_float(2.0 / 3.0), "two thirds", "⅔");
+    example!(v, check_float(0.5), "a half", "one half", "½");
+    example!(v, check_float(0.125), "an eighth", "one eighth", "1/8");
+    example!(v, check_float(1.5), "one and a half", "1½", "1 1/2");
+    example!(v, check_float(2.75), "two and three quarters");
+    example!(v, check_ordinal(20), "the xxth", "xxth");
+    example!(v, check_ordinal(21), "xxist");
     example!(v, check_ordinal(4), "the fourth", "fourth");
     example!(v, check_ordinal(3), "the third", "third");
     example!(v, check_ordinal(2), "the second", "second");
     example!(v, check_ordinal(21), "the twenty first");
 }
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age!([34]), "34 years old", "i'm 34", "thirty four years old", "aged 34 years", "34 years of age");
+    example!(v, check_age!([5]), "5-year-old", "five-year-old");
+    example!(v, check_age!([0, 6]), "6 months old");
+    example!(v, check_age!([18], [25]), "between 18 and 25 years old", "18 to 25 years old");
+    example!(v, check_age(Some(period!(18)), None), "over 18 years old", "18 years old and over");
+    example!(v, check_age(None, Some(period!(12))), "under 12 years old");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_digit_sequence("4815"), "4 8 1 5");
+    example!(v, check_phone_number("5551234567"), "555-123-4567");
+    example!(v, check_phone_number("33612345678"), "+33 6 12 34 56 78", "+33 (0)6 12 34 56 78", "0033 6 12 34 56 78");
+    example!(v, check_digit_sequence("1234"), "flight AF 1234", "flight number af1234");
+}
+
+pub fn examples_spoken(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_integer(5551234), "five five five one two three four", "five five five, um, one two three four");
+    example!(v, check_integer(205), "two oh five");
+    example!(v, check_integer(25), "twenty um five");
+    example!(v, check_integer(520), "five hundred uh twenty");
+    example!(v, check_moment!(c, [1999]), "nineteen ninety nine");
+    example!(v, check_moment!(c, [1905]), "nineteen oh five");
+    example!(v, check_moment!(c, [1812]), "eighteen twelve");
+    example!(v, check_moment!(c, [2019]), "twenty nineteen");
+    example!(v, check_moment!(c, [2013, 2, 12, 14, 5]), "two oh five pm", "at two oh five pm");
+    example!(v, check_moment!(c, [2013, 2, 12, 5, 15]), "a quarter past five");
+    example!(v, check_moment!(c, [2013, 2, 12, 7]), "oh seven hundred hours", "zero seven hundred", "at oh seven hundred");
+    example!(v, check_moment!(c, [2013, 2, 12, 14]), "fourteen hundred hours");
+    example!(v, check_moment!(c, [2013, 2, 12, 14, 30]), "fourteen thirty hours");
+    example!(v, check_moment!(c, [2013, 2, 12, 9, 15]), "oh nine fifteen");
+    example!(v, check_phone_number("0612345678"), "oh six one two three four five six seven eight");
+}
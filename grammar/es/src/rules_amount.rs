@@ -9,7 +9,7 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
         number_check!(),
         // FIXME
         b.reg(r#"por ?cien(?:tos?)?"#)?,
-        |number, _| Ok(PercentageValue(number.value().value()))
+        |number, _| Ok(PercentageValue::from(number.value()))
     );
     Ok(())
 }
@@ -34,7 +34,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         |_| Ok(MoneyUnitValue { unit: Some("$") })
     );
     b.rule_1_terminal("EUR",
-        b.reg(r#"euro?s?"#)?,
+        b.reg(r#"euro?s?|€"#)?,
         |_| Ok(MoneyUnitValue { unit: Some("EUR") })
     );
     b.rule_1_terminal("£",
@@ -54,7 +54,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       |_| Ok(MoneyUnitValue { unit: Some("AUD") })
     );
     b.rule_1_terminal("Bitcoin",
-        b.reg(r#"bitc[oóò]in(?:e?s)?"#)?,
+        b.reg(r#"bitc[oóò]in(?:e?s)?|btc|xbt|₿"#)?,
         |_| Ok(MoneyUnitValue { unit: Some("฿") })
     );
     b.rule_1_terminal("GBP",
@@ -101,6 +101,18 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"coronas? suecas?"#)?,
                       |_| Ok(MoneyUnitValue { unit: Some("SEK") })
     );
+    b.rule_1_terminal("unnamed currency",
+                      b.reg(r#"pavos|pelas|napos"#)?,
+                      |_| Ok(MoneyUnitValue { unit: None })
+    );
+    b.rule_1_terminal("ETH",
+                      b.reg(r#"ethereums?|ethers?|eth|ξ"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("ETH") })
+    );
+    b.rule_1_terminal("sat",
+                      b.reg(r#"satoshis?|sats?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("sat") })
+    );
     b.rule_1_terminal("cent",
                       b.reg(r#"c[eéè]nt(?:avo|imo)s?"#)?,
                       |_| Ok(MoneyUnitValue { unit: Some("cent") })
@@ -111,6 +123,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     decimal: a.value().decimal(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -122,6 +135,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, _, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     decimal: a.value().decimal(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -132,10 +146,29 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
+                     decimal: b.value().decimal(),
                      unit: a.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
              });
+    // A bare "m" may mean "miles" (thousands) as well as millions, only "mm" is unambiguous
+    b.rule_2("<amount-of-money> <magnitude>",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             b.reg_neg_lh(r#"(k|mm)\.?"#, r#"^\w"#)?,
+             |a, text_match| helpers::money_magnitude(a.value(), text_match.group(1).as_ref()));
+    b.rule_3("<number> <magnitude> <unit>",
+             number_check!(),
+             b.reg(r#"(k|mm)\.?"#)?,
+             money_unit!(),
+             |a, text_match, b| {
+                 let amount = AmountOfMoneyValue {
+                     value: a.value().value(),
+                     decimal: a.value().decimal(),
+                     unit: b.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 };
+                 helpers::money_magnitude(&amount, text_match.group(1).as_ref())
+             });
     b.rule_2("about <amount-of-money>",
              b.reg(r#"aproximadamente|sobre|cerca de|casi|un[oa]s"#)?,
              amount_of_money_check!(),
@@ -187,11 +220,11 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     });
     b.rule_2("<latent temp> temp",
              temperature_check!(),
-             b.reg(r#"grados?"#)?,
+             b.reg(r#"grados?|°"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("degree"),
+                     unit: Some(TemperatureUnit::Degree),
                      latent: false,
                  })
              });
@@ -201,7 +234,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
                  })
              });
@@ -212,7 +245,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("kelvin"),
+                     unit: Some(TemperatureUnit::Kelvin),
                      latent: false,
                  })
              });
@@ -222,7 +255,27 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
+                     latent: false,
+                 })
+             });
+    b.rule_2("<temp> °C",
+             temperature_check!(),
+             b.reg(r#"° ?c\.?|℃"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some(TemperatureUnit::Celsius),
+                     latent: false,
+                 })
+             });
+    b.rule_2("<temp> °F",
+             temperature_check!(),
+             b.reg(r#"° ?f\.?|℉"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
                  })
              });
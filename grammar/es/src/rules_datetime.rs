@@ -185,15 +185,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  start.span_to(&end, true)
              }
     );
-    b.rule_2("beginning <named-month>(interval)",
-             b.reg(r#"a(?: principios|l comienzo) de(?:l mes de)?"#)?,
-             datetime_check!(form!(Form::Month(_))),
-             |_, month| {
-                 let start = month.value().intersect(&helpers::day_of_month(1)?)?;
-                 let end = month.value().intersect(&helpers::day_of_month(5)?)?;
-                 start.span_to(&end, true)
-             }
-    );
     b.rule_2("next <datetime>",
              b.reg(r#"(?:el|la )?pr[oóò]xim[oa]"#)?,
              datetime_check!(),
@@ -82,6 +82,21 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
     );
+    b.rule_2("<number> <unit-of-duration>",
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             unit_of_duration_check!(),
+             |number, uod| match number.value() {
+                 // Integers are handled above
+                 &NumberValue::Float(ref float) => Ok(DurationValue::new(helpers::fractional_period(float.value, uod.value().grain)?)),
+                 _ => Err(RuleError::Invalid.into()),
+             }
+    );
+    b.rule_3("<fraction> de <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.is_proper_fraction()),
+             b.reg(r#"de"#)?,
+             unit_of_duration_check!(),
+             |fraction, _, uod| Ok(DurationValue::new(helpers::fractional_period(fraction.value().value(), uod.value().grain)?))
+    );
     b.rule_3("<integer> <unit-of-duration> and a half",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
@@ -96,8 +111,8 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              b.reg(r#"y cuarto"#)?,
              |integer, uod, _| {
-                 let half_period: Period = uod.value().grain.half_period().map(|a| a.into()).ok_or_else(|| RuleError::Invalid)?;
-                 Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, integer.value().value)))
+                 let quarter_period: Period = uod.value().grain.quarter_period().map(|a| a.into()).ok_or_else(|| RuleError::Invalid)?;
+                 Ok(DurationValue::new(quarter_period + PeriodComp::new(uod.value().grain, integer.value().value)))
              }
     );
     b.rule_3("<duration> y <duration>",
@@ -118,3 +133,52 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<duration> old",
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             b.reg(r#"de edad"#)?,
+             |duration, _| helpers::age(duration.value())
+    );
+    b.rule_2("aged <duration>",
+             b.reg(r#"(?:con )?(?:una )?edad de"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| helpers::age(duration.value())
+    );
+    b.rule_4("aged <integer> to <duration>",
+             b.reg(r#"(?:con )?(?:una )?edad de"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"a|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, integer, _, duration| helpers::age_range(integer.value().value, &helpers::age(duration.value())?)
+    );
+    b.rule_4("between <integer> and <age>",
+             b.reg(r#"entre"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"y"#)?,
+             age_check!(|age: &AgeValue| age.is_single()),
+             |_, integer, _, age| helpers::age_range(integer.value().value, age.value())
+    );
+    b.rule_3("<integer> to <age>",
+             integer_check_by_range!(0),
+             b.reg(r#"a|-"#)?,
+             age_check!(|age: &AgeValue| age.is_single()),
+             |integer, _, age| helpers::age_range(integer.value().value, age.value())
+    );
+    b.rule_2("I have <duration>",
+             b.reg(r#"tengo|tienes|tienen|tiene|tenemos|ten[ée]is"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| helpers::age_in_years_duration(duration.value())
+    );
+    b.rule_2("older than <duration>",
+             b.reg(r#"mayor(?:es)? de"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| Ok(helpers::age(duration.value())?.at_least())
+    );
+    b.rule_2("younger than <duration>",
+             b.reg(r#"menor(?:es)? de"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| Ok(helpers::age(duration.value())?.at_most())
+    );
+    Ok(())
+}
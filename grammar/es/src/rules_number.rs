@@ -4,6 +4,35 @@ use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
 use rustling_ontology_values::dimension::Precision::*;
 
+// Words and abbreviations which are valid roman numerals, e.g. "mi", "di" and "vi", or units
+// like "cm"
+const ROMAN_NUMERAL_STOP_WORDS: [&str; 12] = [
+    "cc", "cd", "cl", "cm", "cv", "di", "dl", "mi", "ml", "mm", "vi", "xl",
+];
+
+// Denominators are singular after "un" and plural otherwise
+fn fraction_denominator(word: &str, numerator: i64) -> RuleResult<i64> {
+    let (stem, plural) = match word {
+        word if word.ends_with('s') => (&word[..word.len() - 1], true),
+        word => (word, false),
+    };
+    if plural == (numerator == 1) {
+        return Err(RuleError::Invalid.into());
+    }
+    match stem {
+        "medio" | "media" => Ok(2),
+        "tercio" => Ok(3),
+        "cuarto" => Ok(4),
+        "quinto" => Ok(5),
+        "sexto" => Ok(6),
+        "septimo" | "séptimo" => Ok(7),
+        "octavo" => Ok(8),
+        "noveno" => Ok(9),
+        "decimo" | "décimo" => Ok(10),
+        _ => Err(RuleError::Invalid.into()),
+    }
+}
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
              number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
@@ -193,11 +222,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"punto|coma"#)?,
              integer_check!(|integer: &IntegerValue| !integer.suffixed),
              |a, _, b| {
-                 let value: f64 = format!("{}.{}", a.value().value, b.value().value).parse()?;
-                 Ok(FloatValue {
-                     value,
-                     ..FloatValue::default()
-                 })
+                 FloatValue::from_decimal(format!("{}.{}", a.value().value, b.value().value).parse()?)
              });
     b.rule_4("number dot zero ... number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
@@ -206,12 +231,87 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check!(|integer: &IntegerValue| !integer.suffixed),
              |a, _, zeros, b| {
                  let zeros_string =  std::iter::repeat("0").take(zeros.group(0).split_whitespace().count()).collect::<String>();
-                 let value: f64 = format!("{}.{}{}", a.value().value, zeros_string, b.value().value).parse()?;
-                 Ok(FloatValue {
-                     value,
-                     ..FloatValue::default()
-                 })
+                 FloatValue::from_decimal(format!("{}.{}{}", a.value().value, zeros_string, b.value().value).parse()?)
              });
+    b.rule_1_terminal("dozen",
+                      b.reg(r#"docenas?"#)?,
+                      |_| Ok(IntegerValue {
+                          value: 12,
+                          grain: Some(1),
+                          group: true,
+                          ..IntegerValue::default()
+                      })
+    );
+    b.rule_2("<number> <multiplier>",
+             number_check!(|number: &NumberValue| !number.prefixed() && !number.suffixed()),
+             integer_check!(|integer: &IntegerValue| integer.is_multiplier()),
+             |number, multiplier| {
+                 // Integers followed by cien, mil... are already composed above
+                 if let &NumberValue::Integer(_) = number.value() {
+                     if !multiplier.value().group {
+                         return Err(RuleError::Invalid.into());
+                     }
+                 }
+                 helpers::multiply_number(number.value(), multiplier.value())
+             }
+    );
+    b.rule_2("<number (numeric)> <multiplier>",
+             b.reg(r#"(\d{1,3}(?:\.\d{3})+(?:,\d+)?|\d+(?:,\d+)?)"#)?,
+             integer_check!(|integer: &IntegerValue| integer.is_multiplier()),
+             |text_match, multiplier| {
+                 let number = text_match.group(1).replace(".", "").replace(",", ".");
+                 let number: NumberValue = if number.contains('.') {
+                     FloatValue::from_decimal(number.parse()?)?.into()
+                 } else {
+                     IntegerValue::new(number.parse()?)?.into()
+                 };
+                 helpers::multiply_number(&number, multiplier.value())
+             }
+    );
+    b.rule_1_terminal("integer (roman numerals)",
+                      b.reg(r#"([mdclxvi]{2,})"#)?,
+                      |text_match| IntegerValue::new(helpers::roman_numeral(text_match.group(1), &ROMAN_NUMERAL_STOP_WORDS)?)
+    );
+    b.rule_1_terminal("fraction (unicode)",
+                      b.reg(r#"(\d*)([½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅐⅛⅜⅝⅞⅑⅒])"#)?,
+                      |text_match| {
+                          let fraction = helpers::vulgar_fraction(text_match.group(2))?;
+                          if text_match.group(1).is_empty() {
+                              return Ok(fraction);
+                          }
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
+    );
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d+)/(\d+)"#)?,
+                      |text_match| helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("mixed number (numeric)",
+                      b.reg(r#"(\d+) (\d+)/(\d+)"#)?,
+                      |text_match| {
+                          let fraction = helpers::fraction(text_match.group(2).parse()?, text_match.group(3).parse()?)?;
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
+    );
+    b.rule_2("<integer> <fraction denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(medi[oa]s?|tercios?|cuartos?|quintos?|sextos?|s[eé]ptimos?|octavos?|novenos?|d[eé]cimos?)"#)?,
+             |integer, text_match| {
+                 let denominator = fraction_denominator(text_match.group(1), integer.value().value)?;
+                 helpers::spelled_fraction(integer.value().value, denominator)
+             }
+    );
+    b.rule_2("<integer> y medio",
+             integer_check_by_range!(0),
+             b.reg(r#"y medi[oa]"#)?,
+             |integer, _| helpers::mixed_number(integer.value(), &helpers::fraction(1, 2)?.into())
+    );
+    b.rule_3("<integer> y <fraction>",
+             integer_check_by_range!(0),
+             b.reg(r#"y"#)?,
+             number_check!(|number: &NumberValue| number.is_proper_fraction()),
+             |integer, _, fraction| helpers::mixed_number(integer.value(), fraction.value())
+    );
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"menos"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -229,6 +329,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      NumberValue::Float(float) => {
                          FloatValue {
                              value: float.value * -1.0,
+                             decimal: float.decimal.map(|it| -it),
                              prefixed: true,
                              ..float
                          }
@@ -315,3 +416,24 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("digit sequence",
+                      b.reg(r#"\d+(?:[ .\-]\d+)*"#)?,
+                      |text_match| helpers::digit_sequence_value(text_match.group(0))
+    );
+    b.rule_1_terminal("phone number (international with country code)",
+                      b.reg(r#"(?:\+|00)(\d{1,3})(?: ?\(0\) ?|[ .\-])(\d+(?:[ .\-]\d+)*)"#)?,
+                      |text_match| helpers::international_phone_number(Some(text_match.group(1)), text_match.group(2))
+    );
+    b.rule_1_terminal("phone number (international)",
+                      b.reg(r#"\+(\d+)"#)?,
+                      |text_match| helpers::international_phone_number(None, text_match.group(1))
+    );
+    b.rule_2("flight <code>",
+             b.reg(r#"(?:el )?vuelo(?: n[uú]mero)?"#)?,
+             b.reg(r#"([a-z][a-z0-9]|[0-9][a-z]) ?(\d{1,4})"#)?,
+             |_, code| Ok(helpers::digit_code(code.group(2))?.prefix(code.group(1)))
+    );
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use rustling::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
+
+pub fn rules_spoken(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("integer (digit sequence)",
+                      b.reg(r#"(?:cero|uno|dos|tres|cuatro|cinco|seis|siete|ocho|nueve)(?:,?[ -]+(?:(?:eh+|em+|ehm+|mmm+),?[ -]+)?(?:cero|uno|dos|tres|cuatro|cinco|seis|siete|ocho|nueve)){2,}"#)?,
+                      |text_match| {
+                          let digits = text_match.group(0)
+                              .split(|c: char| c == ' ' || c == '-' || c == ',')
+                              .filter_map(spoken_digit)
+                              .collect::<Vec<_>>();
+                          helpers::digit_sequence(&digits)
+                      }
+    );
+    b.rule_2("<number> <filler>",
+             number_check!(),
+             b.reg(r#"(?:eh+|em+|ehm+|mmm+)"#)?,
+             |number, _| Ok(number.value().clone())
+    );
+    // Years are never read in pairs ("mil novecientos noventa y nueve")
+    b.rule_3("military time (cero <hour> horas)",
+             b.reg(r#"cero"#)?,
+             integer_check_by_range!(0, 9),
+             b.reg(r#"horas?"#)?,
+             |_, hour, _| helpers::hour(hour.value().value as u32, false)
+    );
+    b.rule_3("military time (cero <hour> <minutes>)",
+             b.reg(r#"cero"#)?,
+             integer_check_by_range!(0, 9),
+             integer_check_by_range!(10, 59),
+             |_, hour, minute| helpers::hour_minute(hour.value().value as u32, minute.value().value as u32, false)
+    );
+    Ok(())
+}
+
+fn spoken_digit(word: &str) -> Option<i64> {
+    match word {
+        "cero" => Some(0),
+        "uno" => Some(1),
+        "dos" => Some(2),
+        "tres" => Some(3),
+        "cuatro" => Some(4),
+        "cinco" => Some(5),
+        "seis" => Some(6),
+        "siete" => Some(7),
+        "ocho" => Some(8),
+        "nueve" => Some(9),
+        // Filler words
+        _ => None,
+    }
+}
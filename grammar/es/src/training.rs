@@ -248,6 +248,8 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "durante un cuarto de hora más o menos", "durante un cuarto de hora aproximadamente");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "durante una hora", "por una hora");
     example!(v, check_duration!([0, 0, 2]), "durante dos semanas", "por dos semanas");
+    example!(v, check_duration!([0, 0, 0, 0, 2, 15]), "durante dos horas y cuarto", "durante dos y un cuarto horas", "durante 2¼ horas");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 40]), "durante dos tercios de hora");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -281,6 +283,17 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(32.75), "treinta y dos punto setenta y cinco", "treinta y dos coma setenta y cinco");
     example!(v, check_float(10.08), "diez punto cero ocho", "diez coma cero ocho");
     // TODO: Check if want/need support for ordinal special character/overscript
+    example!(v, check_integer(14), "xiv");
+    example!(v, check_integer(21), "xxi");
+    example!(v, check_integer(5000000), "5 millones", "cinco millones");
+    example!(v, check_integer(2500000), "2,5 millones", "dos coma cinco millones");
+    example!(v, check_integer(36), "3 docenas", "tres docenas");
+    example!(v, check_float(0.75), "tres cuartos", "3/4", "¾");
+    example!(v, check_float(2.0 / 3.0), "dos tercios", "⅔");
+    example!(v, check_float(0.5), "un medio", "½");
+    example!(v, check_float(0.1), "un décimo", "1/10");
+    example!(v, check_float(2.5), "dos y medio", "2½", "2 1/2");
+    example!(v, check_float(1.75), "uno y tres cuartos");
     example!(v, check_ordinal(1), "primer", "primero", "primera");
     example!(v, check_ordinal(3), "tercero", "tercera");
     example!(v, check_ordinal(2), "segundo");
@@ -294,13 +307,17 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(3.0, Some("degree")), "tres grados");
-    example!(v, check_temperature(32.0, Some("celsius")), "treinta y dos grados celsius", "treinta y dos grados centígrados");
-    example!(v, check_temperature(-27.0, Some("degree")), "veintisiete grados bajo cero","menos veintisiete grados");
-    example!(v, check_temperature(-27.0, Some("celsius")), "menos veintisiete grados celsius", "menos veintisiete grados centigrados");
-    example!(v, check_temperature(-5.0, Some("fahrenheit")), "menos cinco grados fahrenheit", "cinco grados fahrenheit bajo cero");
-    example!(v, check_temperature(168.0, Some("fahrenheit")), "ciento sesenta y ocho fahrenheit", "ciento sesenta y ocho grados fahrenheit");
-    example!(v, check_temperature(10.0, Some("kelvin")), "diez kelvin", "diez grados kelvin");
+    example!(v, check_temperature(3.0, Some(TemperatureUnit::Degree)), "tres grados", "3°");
+    example!(v, check_temperature(21.0, Some(TemperatureUnit::Celsius)), "21°C", "21 °c", "21℃");
+    example!(v, check_temperature(70.0, Some(TemperatureUnit::Fahrenheit)), "70°F", "70℉");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Celsius)), "-5°C", "cinco grados celsius bajo cero");
+    example!(v, check_temperature(32.0, Some(TemperatureUnit::Celsius)), "treinta y dos grados celsius", "treinta y dos grados centígrados");
+    example!(v, check_temperature(-27.0, Some(TemperatureUnit::Degree)), "veintisiete grados bajo cero","menos veintisiete grados");
+    example!(v, check_temperature(-27.0, Some(TemperatureUnit::Celsius)), "menos veintisiete grados celsius", "menos veintisiete grados centigrados");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Fahrenheit)), "menos cinco grados fahrenheit", "cinco grados fahrenheit bajo cero");
+    example!(v, check_temperature(168.0, Some(TemperatureUnit::Fahrenheit)), "ciento sesenta y ocho fahrenheit", "ciento sesenta y ocho grados fahrenheit");
+    example!(v, check_temperature(10.0, Some(TemperatureUnit::Kelvin)), "diez kelvin", "diez grados kelvin");
+    example!(v, check_temperature(36.5, Some(TemperatureUnit::Degree)), "treinta y seis y medio grados", "36½ grados");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -313,6 +330,7 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(125.0, Some("CAD"), Precision::Exact), "ciento veinticinco dólares canadienses");
     example!(v, check_finance(45.0, Some("EUR"), Precision::Exact),  "cuarenta y cinco euros");
     example!(v, check_finance(2.05, Some("EUR"), Precision::Exact),  "dos euros y cinco céntimos");
+    example!(v, check_finance(2.5, Some("EUR"), Precision::Exact),  "dos y medio euros", "2½ euros");
     example!(v, check_finance(2.0, Some("£"), Precision::Exact),  "dos libras");
     example!(v, check_finance(20.0, Some("GBP"), Precision::Exact), "veinte libras esterlinas");
     example!(v, check_finance(38.0, Some("CHF"), Precision::Exact), "treinta y ocho francos suizos");
@@ -327,6 +345,32 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(100.0, Some("CNY"), Precision::Exact), "cien yuanes exactos", "exactamente cien yuanes chinos");
     example!(v, check_finance(7.0, Some("KRW"), Precision::Exact), "siete wones surcoreanos");
     example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "tres bitcoins");
+    example!(v, check_finance(0.5, Some("฿"), Precision::Exact), "0,5 BTC", "0,5 bitcoins");
+    example!(v, check_finance(2.0, Some("ETH"), Precision::Exact), "2 eth", "dos ether");
+    example!(v, check_finance(300.0, Some("sat"), Precision::Exact), "300 sats", "trescientos satoshis");
+    example!(v, check_finance(1200000.0, Some("EUR"), Precision::Exact), "1,2 M€", "1,2 MM €");
+    example!(v, check_finance(5000.0, Some("EUR"), Precision::Exact), "5k€", "5 k euros");
+    example!(v, check_finance(50.0, None, Precision::Exact), "50 pavos", "cincuenta pelas");
     example!(v, check_finance(15.0, Some("$"), Precision::Approximate), "unos quince dólares", "unos quince dolares");
     example!(v, check_finance(3000000.0, Some("EUR"), Precision::Exact), "tres millones de euros");
 }
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age!([34]), "34 años de edad", "tengo 34 años", "tiene treinta y cuatro años");
+    example!(v, check_age!([18], [25]), "entre 18 y 25 años de edad");
+    example!(v, check_age(Some(period!(18)), None), "mayores de 18 años");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("34612345678"), "+34 612 34 56 78");
+    example!(v, check_digit_sequence("4815"), "4 8 1 5");
+    example!(v, check_digit_sequence("6043"), "el vuelo IB 6043");
+}
+
+pub fn examples_spoken(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_integer(5551234), "cinco cinco cinco uno dos tres cuatro", "cinco cinco cinco, eh, uno dos tres cuatro");
+    example!(v, check_integer(1200000), "un millón eh doscientos mil");
+    example!(v, check_moment!(c, [2013, 2, 12, 7]), "cero siete horas", "a las cero siete horas");
+    example!(v, check_moment!(c, [2013, 2, 12, 9, 15]), "cero nueve quince");
+}
@@ -7,16 +7,18 @@ mod rules_datetime;
 mod rules_celebrations;
 mod rules_duration;
 mod rules_number;
+mod rules_spoken;
 mod rules_amount;
 pub mod training;
 
 use rustling_ontology_values::DimensionKind::*;
 
-pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+fn rule_set_builder() -> ::rustling::RustlingResult<::rustling::RuleSetBuilder<rustling_ontology_values::Dimension>> {
     let mut b = ::rustling::RuleSetBuilder::new(
                     ::rustling::BoundariesChecker::detailed(),
                     ::rustling::BoundariesChecker::separated_alphanumeric_word());
     rules_number::rules_numbers(&mut b)?;
+    rules_number::rules_digit_sequence(&mut b)?;
     rules_amount::rules_temperature(&mut b)?;
     rules_amount::rules_finance(&mut b)?;
     rules_amount::rules_percentage(&mut b)?;
@@ -26,11 +28,23 @@ pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ont
     rules_datetime::rules_datetime_with_cycle(&mut b)?;
     rules_celebrations::rules_celebration(&mut b)?;
     rules_duration::rules_duration(&mut b)?;
+    rules_duration::rules_age(&mut b)?;
+    Ok(b)
+}
+
+pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    Ok(rule_set_builder()?.build())
+}
+
+/// Rules for raw speech recognition transcripts, on top of the regular ones.
+pub fn spoken_rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    let mut b = rule_set_builder()?;
+    rules_spoken::rules_spoken(&mut b)?;
     Ok(b.build())
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Duration, Datetime, Temperature, AmountOfMoney, Percentage];
+    return vec![Number, Ordinal, Duration, Datetime, Temperature, AmountOfMoney, Percentage, DigitSequence, Age];
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
@@ -40,6 +54,14 @@ pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Di
     training::examples_temperature(&mut v);
     training::examples_finance(&mut v);
     training::examples_percentage(&mut v);
+    training::examples_digit_sequence(&mut v);
+    training::examples_age(&mut v);
+    v
+}
+
+pub fn spoken_examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
+    let mut v = examples();
+    training::examples_spoken(&mut v);
     v
 }
 
@@ -65,4 +87,10 @@ mod test {
         let examples = examples();
         assert_examples(&rules, examples);
     }
+    #[test]
+    fn test_spoken_examples() {
+        let rules = spoken_rule_set().unwrap();
+        let examples = spoken_examples();
+        assert_examples(&rules, examples);
+    }
 }
@@ -7,7 +7,7 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
     b.rule_2("<number> per cent",
         number_check!(),
         b.reg(r"(?:%|p\.c\.|p. cents?|pour[ -]?cents?)")?,
-        |number, _| Ok(PercentageValue(number.value().value()))
+        |number, _| Ok(PercentageValue::from(number.value()))
     );
     Ok(())
 }
@@ -99,7 +99,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         |_| Ok(MoneyUnitValue { unit: Some("KRW") })
     );
     b.rule_1_terminal("Bitcoin",
-        b.reg(r#"฿|bitcoins?"#)?,
+        b.reg(r#"฿|₿|btc|xbt|bitcoins?"#)?,
         |_| Ok(MoneyUnitValue { unit: Some("฿") })
     );
     b.rule_1_terminal("GBP",
@@ -114,12 +114,21 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"(?:balle)s?"#)?,
                       |_| Ok(MoneyUnitValue { unit: None })
     );
+    b.rule_1_terminal("ETH",
+                      b.reg(r#"ethereums?|ethers?|eth|ξ"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("ETH") })
+    );
+    b.rule_1_terminal("sat",
+                      b.reg(r#"satoshis?|sats?"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("sat") })
+    );
     b.rule_2("<amount> <unit>",
              number_check!(),
              money_unit!(),
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     decimal: a.value().decimal(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -131,6 +140,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         |a, _, b| {
             Ok(AmountOfMoneyValue {
                 value: a.value().value as f64,
+                decimal: Some(a.value().value.into()),
                 precision: Exact,
                 unit: b.value().unit,
                 ..AmountOfMoneyValue::default()
@@ -143,11 +153,29 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         |a, _, b| {
             Ok(AmountOfMoneyValue {
                 value: a.value().value as f64,
+                decimal: Some(a.value().value.into()),
                 precision: Approximate,
                 unit: b.value().unit,
                 ..AmountOfMoneyValue::default()
             })
     });
+    b.rule_2("<amount-of-money> <magnitude>",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             b.reg_neg_lh(r#"(mrds?|mds?|k|m)\.?"#, r#"^\w"#)?,
+             |a, text_match| helpers::money_magnitude(a.value(), text_match.group(1).as_ref()));
+    b.rule_3("<number> <magnitude> <unit>",
+             number_check!(),
+             b.reg(r#"(mrds?|mds?|k|m)\.?"#)?,
+             money_unit!(),
+             |a, text_match, b| {
+                 let amount = AmountOfMoneyValue {
+                     value: a.value().value(),
+                     decimal: a.value().decimal(),
+                     unit: b.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 };
+                 helpers::money_magnitude(&amount, text_match.group(1).as_ref())
+             });
     b.rule_2("about <amount-of-money>",
              b.reg(r#"(?:autour|pas loin|pr[eè]s|aux alentours) d[e']|environ|presque|(?:approximative|quasi)ment"#)?,
              amount_of_money_check!(),
@@ -195,7 +223,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("degree"),
+                     unit: Some(TemperatureUnit::Degree),
                      latent: false,
                  })
              });
@@ -205,7 +233,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
                  })
              });
@@ -215,7 +243,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
                  })
              });
@@ -225,13 +253,13 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("kelvin"),
+                     unit: Some(TemperatureUnit::Kelvin),
                      latent: false,
                  })
              });
     b.rule_2("<temp> en dessous de zero",
              temperature_check!(|temp: &TemperatureValue| !temp.latent),
-             b.reg(r#"en dessous de (?:0|z[ée]ro)"#)?,
+             b.reg(r#"(?:en dessous de|sous) (?:0|z[ée]ro)"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
@@ -104,6 +104,32 @@ pub fn rules_celebration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                       |_| Ok(helpers::month_day(8, 15)?
                           .form(Form::Celebration))
     );
+    b.rule_1_terminal("ramadan",
+                      b.reg(r#"(?:le )?(?:mois (?:de |du ))?rama[dz]an"#)?,
+                      |_| Ok(helpers::hijri_month_day(9, 1)?
+                          .span_to(&helpers::hijri_month_day(10, 1)?, false)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("aïd el-fitr",
+                      b.reg(r#"(?:l')?a[iï]d(?: el| al)?[- ]?fitr|(?:la )?f[eê]te de la rupture(?: du je[uû]ne)?"#)?,
+                      |_| Ok(helpers::hijri_month_day(10, 1)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("aïd el-kébir",
+                      b.reg(r#"(?:l')?a[iï]d(?: el| al)?[- ]?(?:adha|k[eé]bir)|(?:la )?f[eê]te du sacrifice"#)?,
+                      |_| Ok(helpers::hijri_month_day(12, 10)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("roch hachana",
+                      b.reg(r#"ro(?:s|ch|sh) ha(?:s|ch|sh)ann?ah?"#)?,
+                      |_| Ok(helpers::hebrew_month_day(7, 1)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("yom kippour",
+                      b.reg(r#"(?:le )?yom kipp?ou?r|(?:le jour du )?grand pardon"#)?,
+                      |_| Ok(helpers::hebrew_month_day(7, 10)?
+                          .form(Form::Celebration))
+    );
     b.rule_2("à <celebration>",
              b.reg(r#"au|[aà](?:l['a])?"#)?,
              datetime_check!(form!(Form::Celebration)),
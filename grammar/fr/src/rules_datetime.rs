@@ -203,16 +203,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      .datetime_kind(a.value().datetime_kind.clone()))
              }
     );
-    // TODO: add restrictions on datetime form?
-    b.rule_2("au dernier <datetime>",
-             b.reg(r#"(au |(?:[aà] )?l[ea] )derni[eè]re?"#)?,
-             datetime_check!(|datetime: &DatetimeValue| !form!(Form::PartOfDay(_))(datetime) && !form!(Form::Meal)(datetime)),
-             |_, a| {
-                 Ok(a.value().the_nth(-1)?
-                     .form(a.value().form.clone())
-                     .datetime_kind(a.value().datetime_kind.clone()))
-             }
-    );
     b.rule_2("<named-month> prochain",
              // The direction check is to avoid application of datetime_check(month) on rule result
              // "avant <named-month>"
@@ -302,16 +292,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  }
              }
     );
-    b.rule_2("l'année <year>",
-             b.reg(r#"l[' ]an(?:n[eé]+)?"#)?,
-             integer_check!(),
-             |_, integer| helpers::year(integer.value().value as i32)
-    );
-    b.rule_2("en <year>",
-             b.reg(r#"en"#)?,
-             datetime_check!(|datetime: &DatetimeValue| !datetime.latent && form!(Form::Year(_))(datetime)),
-             |_, year| Ok(year.value().clone())
-    );
     b.rule_1("year (latent)",
              integer_check_by_range!(2101, 3000),
              |integer| {
@@ -322,6 +302,16 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  }
              }
     );
+    b.rule_2("l'année <year>",
+             b.reg(r#"l[' ]an(?:n[eé]+)?"#)?,
+             integer_check!(),
+             |_, integer| helpers::year(integer.value().value as i32)
+    );
+    b.rule_2("en <year>",
+             b.reg(r#"en"#)?,
+             datetime_check!(|datetime: &DatetimeValue| !datetime.latent && form!(Form::Year(_))(datetime)),
+             |_, year| Ok(year.value().clone())
+    );
     b.rule_1_terminal("day of month (premier)",
                       b.reg(r#"premier|prem\.?|1er|1 er"#)?,
                       |_| helpers::day_of_month(1)
@@ -387,46 +377,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"pile"#)?,
              |a, _| Ok(a.value().clone().not_latent())
     );
-    b.rule_1_terminal("hh(:|h)mm (time-of-day)",
-        b.reg(r#"((?:[01]?\d)|(?:2[0-3]))[:h]([0-5]\d)"#)?,
-        |text_match| {
-            let hour: u32 = text_match.group(1).parse()?;
-            let minute: u32 = text_match.group(2).parse()?;
-            helpers::hour_minute(hour, minute, hour < 12)
-        }
-    );
-    b.rule_3_terminal("hh(:|h)mm - hh(:|h)mm (time-of-day interval)",
-                      b.reg(r#"((?:[01]?\d)|(?:2[0-3]))[:h]([0-5]\d)"#)?,
-                      b.reg(r#" ?\- ?"#)?,
-                      b.reg(r#"((?:[01]?\d)|(?:2[0-3]))[:h]([0-5]\d)"#)?,
-                      |a, _, b| {
-                          let hour_start: u32 = a.group(1).parse()?;
-                          let minute_start: u32 = a.group(2).parse()?;
-                          let hour_end: u32 = b.group(1).parse()?;
-                          let minute_end: u32 = b.group(2).parse()?;
-                          let start = helpers::hour_minute(hour_start, minute_start, hour_start < 12)?;
-                          let end = helpers::hour_minute(hour_end, minute_end, hour_end < 12)?;
-                          start.smart_span_to(&end, false)
-                      }
-    );
-    b.rule_1_terminal("hh:mm:ss",
-        b.reg(r#"((?:[01]?\d)|(?:2[0-3]))[:.]([0-5]\d)[:.]([0-5]\d)"#)?,
-        |text_match| helpers::hour_minute_second(
-                text_match.group(1).parse()?,
-                text_match.group(2).parse()?,
-                text_match.group(3).parse()?,
-                false
-                )
-
-    );
-    b.rule_1_terminal("hhmm (military time-of-day)",
-        b.reg(r#"((?:[01]?\d)|(?:2[0-3]))([0-5]\d)"#)?,
-        |text_match| Ok(helpers::hour_minute(
-            text_match.group(1).parse()?,
-            text_match.group(2).parse()?,
-            false
-            )?.latent())
-    );
     b.rule_1_terminal("quart (relative minutes)",
                       b.reg(r#"(?:un )?quart"#)?,
                       |_| helpers::relative_minute_value(15)
@@ -568,7 +518,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     );
     b.rule_1_terminal("hhmm (military time-of-day)",
-                      b.reg(r#"((?:[01]\d)|(?:2[0-3]))([0-5]\d)"#)?,
+                      b.reg(r#"((?:[01]?\d)|(?:2[0-3]))([0-5]\d)"#)?,
                       |text_match| Ok(helpers::hour_minute(
                           text_match.group(1).parse()?,
                           text_match.group(2).parse()?,
@@ -948,6 +898,10 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         b.reg(r#"(?:cet )?hiver"#)?,
         |_| helpers::month_day(12, 21)?.span_to(&helpers::month_day(3, 20)?, false)
     );
+    b.rule_1_terminal("season",
+        b.reg(r#"(?:ce )?printemps"#)?,
+        |_| helpers::month_day(3, 20)?.span_to(&helpers::month_day(6, 21)?, false)
+    );
     b.rule_2("season - winter <year>",
              b.reg(r#"hiver(?: de)?"#)?,
              datetime_check!(form!(Form::Year(_))),
@@ -955,76 +909,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  .span_to(&helpers::year_month_day(year.value().form_year()? + (1 as i32), 3, 20)?, false)?
                  .form(Form::Season))
     );
-    b.rule_1_terminal("season",
-        b.reg(r#"(?:ce )?printemps"#)?,
-        |_| helpers::month_day(3, 20)?.span_to(&helpers::month_day(6, 21)?, false)
-    );
-    b.rule_1_terminal("début de l'été",
-                      b.reg(r#"début de (?:cet |l')?(?:été|ete)"#)?,
-                      |_| helpers::month_day(6, 21)?.span_to(&helpers::month_day(7, 15)?, false)
-    );
-    b.rule_1_terminal("milieu de l'été",
-                      b.reg(r#"milieu de (?:cet |l')?(?:été|ete)"#)?,
-                      |_| helpers::month_day(7, 15)?.span_to(&helpers::month_day(8, 15)?, false)
-    );
-    b.rule_1_terminal("fin de l'été",
-                      b.reg(r#"fin de (?:cet |l')?(?:été|ete)"#)?,
-                      |_| helpers::month_day(8, 15)?.span_to(&helpers::month_day(9, 21)?, false)
-    );
-    b.rule_1_terminal("début de l'automne",
-                      b.reg(r#"début de (?:cet |l')?automne"#)?,
-                      |_| helpers::month_day(9, 21)?.span_to(&helpers::month_day(10, 15)?, false)
-    );
-    b.rule_1_terminal("milieu de l'automne",
-                      b.reg(r#"milieu de (?:cet |l')?automne"#)?,
-                      |_| helpers::month_day(10, 15)?.span_to(&helpers::month_day(11, 15)?, false)
-    );
-    b.rule_1_terminal("fin de l'automne",
-                      b.reg(r#"fin de (?:cet |l')?automne"#)?,
-                      |_| helpers::month_day(11, 15)?.span_to(&helpers::month_day(12, 21)?, false)
-    );
-    b.rule_1_terminal("début de l'hiver",
-                      b.reg(r#"début de (?:cet |l')?hiver"#)?,
-                      |_| helpers::month_day(12, 21)?.span_to(&helpers::month_day(1, 15)?, false)
-    );
-    b.rule_1_terminal("milieu de l'hiver",
-                      b.reg(r#"milieu de (?:cet |l')?hiver"#)?,
-                      |_| helpers::month_day(1, 15)?.span_to(&helpers::month_day(2, 15)?, false)
-    );
-    b.rule_1_terminal("fin de l'hiver",
-                      b.reg(r#"fin de (?:cet |l')?hiver"#)?,
-                      |_| helpers::month_day(2, 15)?.span_to(&helpers::month_day(3, 21)?, false)
-    );
-    b.rule_1_terminal("début du printemps",
-                      b.reg(r#"début (?:du|de ce)? printemps"#)?,
-                      |_| helpers::month_day(3, 21)?.span_to(&helpers::month_day(4, 15)?, false)
-    );
-    b.rule_1_terminal("milieu du printemps",
-                      b.reg(r#"milieu (?:du|de ce)? printemps"#)?,
-                      |_| helpers::month_day(4, 15)?.span_to(&helpers::month_day(5, 15)?, false)
-    );
-    b.rule_1_terminal("fin du printemps",
-                      b.reg(r#"fin (?:du|de ce)? printemps"#)?,
-                      |_| helpers::month_day(5, 15)?.span_to(&helpers::month_day(6, 21)?, false)
-    );
-    b.rule_1_terminal("fin de cette année",
-                      b.reg(r#"fin (?:de (?:l'|cette )|d')?ann[ée]e"#)?,
-                      |_| {
-                          let current_year = helpers::cycle_nth(Grain::Year, 0)?;
-                          let start = current_year.intersect(&helpers::month(10)?)?;
-                          let end = current_year.intersect(&helpers::month(12)?)?;
-                          start.span_to(&end, true)
-                      }
-    );
-    b.rule_1_terminal("début de cette année",
-                      b.reg(r#"d[ée]but (?:de (?:l'|cette )|d')?ann[ée]e"#)?,
-                      |_| {
-                          let current_year = helpers::cycle_nth(Grain::Year, 0)?;
-                          let start = current_year.intersect(&helpers::month(1)?)?;
-                          let end = current_year.intersect(&helpers::month(2)?)?;
-                          start.span_to(&end, true)
-                      }
-    );
     b.rule_1_terminal("début de l'été",
                       b.reg(r#"début de (?:cet |l')?(?:été|ete)"#)?,
                       |_| helpers::month_day(6, 21)?.span_to(&helpers::month_day(7, 15)?, false)
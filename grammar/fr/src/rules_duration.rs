@@ -53,6 +53,21 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, unit| Ok(DurationValue::new(PeriodComp::new(unit.value().grain, integer.value().value).into()))
     );
+    b.rule_2("<number> <unit-of-duration>",
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             unit_of_duration_check!(),
+             |number, unit| match number.value() {
+                 // Integers are handled above
+                 &NumberValue::Float(ref float) => Ok(DurationValue::new(helpers::fractional_period(float.value, unit.value().grain)?)),
+                 _ => Err(RuleError::Invalid.into()),
+             }
+    );
+    b.rule_3("<fraction> de <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.is_proper_fraction()),
+             b.reg(r#"d[e']"#)?,
+             unit_of_duration_check!(),
+             |fraction, _, unit| Ok(DurationValue::new(helpers::fractional_period(fraction.value().value(), unit.value().grain)?))
+    );
     b.rule_3("<integer> de <unit-of-duration>",
              integer_check!(|integer: &IntegerValue| integer.value >= 0 && integer.group),
              b.reg(r#"d[e']"#)?,
@@ -140,3 +155,51 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     Ok(())
 }
 
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<duration> old",
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             b.reg(r#"d'[âa]ge"#)?,
+             |duration, _| helpers::age(duration.value())
+    );
+    b.rule_2("aged <duration>",
+             b.reg(r#"[âa]g[ée]e?s? de"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| helpers::age(duration.value())
+    );
+    b.rule_4("aged <integer> to <duration>",
+             b.reg(r#"[âa]g[ée]e?s? de"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"[àa]|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, integer, _, duration| helpers::age_range(integer.value().value, &helpers::age(duration.value())?)
+    );
+    b.rule_4("between <integer> and <age>",
+             b.reg(r#"entre"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"et"#)?,
+             age_check!(|age: &AgeValue| age.is_single()),
+             |_, integer, _, age| helpers::age_range(integer.value().value, age.value())
+    );
+    b.rule_3("<integer> to <age>",
+             integer_check_by_range!(0),
+             b.reg(r#"[àa]|-"#)?,
+             age_check!(|age: &AgeValue| age.is_single()),
+             |integer, _, age| helpers::age_range(integer.value().value, age.value())
+    );
+    b.rule_2("I have <duration>",
+             b.reg(r#"j'ai|tu as|(?:il|elle|on) a|nous avons|vous avez|(?:ils|elles) ont"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| helpers::age_in_years_duration(duration.value())
+    );
+    b.rule_2("older than <duration>",
+             b.reg(r#"les plus de"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| Ok(helpers::age(duration.value())?.at_least())
+    );
+    b.rule_2("younger than <duration>",
+             b.reg(r#"les moins de"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| Ok(helpers::age(duration.value())?.at_most())
+    );
+    Ok(())
+}
@@ -3,6 +3,39 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
 
+// Words and abbreviations which happen to be valid roman numerals, with or without an ordinal
+// suffix
+const ROMAN_NUMERAL_STOP_WORDS: [&str; 31] = [
+    "cc", "cd", "ci", "cl", "cm", "cv", "dc", "di", "dl", "dx", "li", "mc", "md", "mi", "ml", "mm",
+    "mv", "xl", "civ", "div", "dix", "mix", "mdl", "cie", "lie", "mie", "vie", "mme", "dive", "live",
+    "mixe",
+];
+
+// Denominators are singular after "un" and plural otherwise, which keeps "vingt cinquième" an
+// ordinal
+fn fraction_denominator(word: &str, numerator: i64) -> RuleResult<i64> {
+    let (stem, plural) = match word {
+        "tiers" => (word, numerator > 1),
+        word if word.ends_with('s') => (&word[..word.len() - 1], true),
+        word => (word, false),
+    };
+    if plural == (numerator == 1) {
+        return Err(RuleError::Invalid.into());
+    }
+    match &*stem.replace("è", "e") {
+        "demi" | "demie" => Ok(2),
+        "tiers" => Ok(3),
+        "quart" => Ok(4),
+        "cinquieme" => Ok(5),
+        "sixieme" => Ok(6),
+        "septieme" => Ok(7),
+        "huitieme" => Ok(8),
+        "neuvieme" => Ok(9),
+        "dixieme" => Ok(10),
+        _ => Err(RuleError::Invalid.into()),
+    }
+}
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
              number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
@@ -191,19 +224,14 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"(\d*,\d+)"#)?,
                       |text_match| {
                           let reformatted_string = text_match.group(1).replace(",", ".");
-                          let value: f64 = reformatted_string.parse()?;
-                          FloatValue::new(value)
+                          FloatValue::from_decimal(reformatted_string.parse()?)
                       });
     b.rule_3("number dot number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
              b.reg(r#"virgule|point"#)?,
              integer_check!(|integer: &IntegerValue| !integer.suffixed),
              |a, _, b| {
-                let value: f64 = format!("{}.{}", a.value().value, b.value().value).parse()?;
-                 Ok(FloatValue {
-                     value,
-                     ..FloatValue::default()
-                 })
+                FloatValue::from_decimal(format!("{}.{}", a.value().value, b.value().value).parse()?)
              });
     b.rule_4("number dot zero ... number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
@@ -212,18 +240,13 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check!(|integer: &IntegerValue| !integer.suffixed),
              |a, _, zeros, b| {
                 let zeros_string =  std::iter::repeat("0").take(zeros.group(0).split_whitespace().count()).collect::<String>();
-                 let value: f64 = format!("{}.{}{}", a.value().value, zeros_string, b.value().value).parse()?;
-                 Ok(FloatValue {
-                     value,
-                     ..FloatValue::default()
-                 })
+                 FloatValue::from_decimal(format!("{}.{}{}", a.value().value, zeros_string, b.value().value).parse()?)
              });
     b.rule_1_terminal("decimal with thousands separator",
                       b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
                       |text_match| {
                           let reformatted_string = text_match.group(1).replace(".", "").replace(",", ".");
-                          let value: f64 = reformatted_string.parse()?;
-                          FloatValue::new(value)
+                          FloatValue::from_decimal(reformatted_string.parse()?)
                       });
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|moins"#)?,
@@ -242,6 +265,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      NumberValue::Float(float) => {
                          FloatValue {
                              value: float.value * -1.0,
+                             decimal: float.decimal.map(|it| -it),
                              prefixed: true,
                              ..float
                          }
@@ -303,6 +327,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             } else {
                 FloatValue {
                         value: product,
+                        decimal: float.decimal.and_then(|it| it.checked_mul(multiplier.into())),
                         suffixed: true,
                         ..float
                     }
@@ -335,244 +360,58 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           })
                       }
     );
-    b.rule_2("number dozen",
-             integer_check_by_range!(1, 9),
-             integer_check!(|integer: &IntegerValue| integer.group),
-             |a, b| {
-                 Ok(IntegerValue {
-                     value: a.value().value * b.value().value,
-                     grain: b.value().grain,
-                     group: true,
-                     ..IntegerValue::default()
-                 })
-    });
-    b.rule_1_terminal("ordinal 0",
-        b.reg(r#"z[eé]rot?i[eè]me"#)?,
-        |_| {
-            Ok(OrdinalValue::new(0))
-        }
-    );
-    b.rule_1_terminal("ordinal 1",
-        b.reg(r#"premi[eè]re?"#)?,
-        |_| {
-            Ok(OrdinalValue::new(1))
-        }
-    );
-    b.rule_1_terminal("ordinal 2",
-        b.reg(r#"seconde?|deuxi[eè]me"#)?,
-        |_| {
-            Ok(OrdinalValue::new(2))
-        }
-    );
-    b.rule_1_terminal(
-            "ordinals (premier..seizieme)",
-            b.reg(r#"(trois|quatr|cinqu|six|sept|huit|neuv|dix|onz|douz|treiz|quatorz|quinz|seiz)i[eè]me"#)?,
-            |text_match| {
-                let value = match text_match.group(1).as_ref() {
-                    "trois" => 3,
-                    "quatr" => 4,
-                    "cinqu" => 5,
-                    "six" => 6,
-                    "sept" => 7,
-                    "huit" => 8,
-                    "neuv" => 9,
-                    "dix" => 10,
-                    "onz" => 11,
-                    "douz" => 12,
-                    "treiz" => 13,
-                    "quatorz" => 14,
-                    "quinz" => 15,
-                    "seiz" => 16,
-                     _ => return Err(RuleError::Invalid.into()),
-                 };
-                 Ok(OrdinalValue::new(value))
-            });
-    b.rule_2("17ieme, 18ieme, 19ieme",
-        b.reg(r#"dix-?"#)?,
-        ordinal_check_by_range!(7, 9),
-        |_, ordinal| {
-            Ok(OrdinalValue::new(10 + ordinal.value().value))
-        }
-    );
-    b.rule_1_terminal("20ieme, 30ieme, 40ieme, 50ieme, 60ieme",
-        b.reg(r#"(vingt|trent|quarant|cinquant|soixant)i[èe]me"#)?,
-        |text_match| {
-            let value = match text_match.group(1).as_ref() {
-                "vingt" => 20,
-                "trent" => 30,
-                "quarant" => 40,
-                "cinquant" => 50,
-                "soixant" => 60,
-                _ => return Err(RuleError::Invalid.into()),
-            };
-            Ok(OrdinalValue::new(value))
-        }
-    );
-    b.rule_1_terminal("80ieme",
-        b.reg(r#"quatre[- ]vingts?i[èe]me"#)?,
-        |_| {
-            Ok(OrdinalValue::new(80))
-        }
-    );
-    b.rule_2("22ieme...29ieme, 32ieme...39ieme, 42ieme...49ieme, 52ieme...59ieme",
-        integer_check_by_range!(20, 50, |integer: &IntegerValue| integer.value % 10 == 0),
-        ordinal_check_by_range!(2, 9),
-        |integer, ordinal| {
-            Ok(OrdinalValue::new(integer.value().value + ordinal.value().value))
-        }
-    );
-    b.rule_3("22ieme...29ieme, 32ieme...39ieme, 42ieme...49ieme, 52ieme...59ieme",
-        integer_check_by_range!(20, 50, |integer: &IntegerValue| integer.value % 10 == 0),
-        b.reg(r"-")?,
-        ordinal_check_by_range!(2, 9),
-        |integer, _, ordinal| {
-            Ok(OrdinalValue::new(integer.value().value + ordinal.value().value))
-        }
-    );
-    b.rule_2("62ieme...70ieme, 72ieme...79ieme, 90ieme, 92ieme...99ieme",
-        integer_check_by_range!(60, 80, |integer: &IntegerValue| integer.value == 60 || integer.value == 80),
-        ordinal_check_by_range!(2, 19),
-        |integer, ordinal| {
-            Ok(OrdinalValue::new(integer.value().value + ordinal.value().value))
-        }
-    );
-    b.rule_3("62ieme...70ieme, 72ieme...79ieme, 90ieme, 92ieme...99ieme",
-        integer_check_by_range!(60, 80, |integer: &IntegerValue| integer.value == 60 || integer.value == 80),
-        b.reg(r"-")?,
-        ordinal_check_by_range!(2, 19),
-        |integer, _, ordinal| {
-            Ok(OrdinalValue::new(integer.value().value + ordinal.value().value))
-        }
-    );
-    b.rule_2("21, 31, 41, 51, 61",
-        integer_check_by_range!(20, 60, |integer: &IntegerValue| integer.value % 10 == 0),
-        b.reg(r#"(?:et |-)uni[èe]me"#)?,
-        |integer, _| {
-            Ok(OrdinalValue::new(integer.value().value + 1))
-        }
-    );
-    b.rule_2("81",
-        integer_check_by_range!(80, 80),
-        b.reg(r#"(?:et )?uni[èe]me"#)?,
-        |integer, _| {
-            Ok(OrdinalValue::new(integer.value().value + 1))
-        }
-    );
-    b.rule_2("71, 91",
-        integer_check_by_range!(60, 60),
-        b.reg(r#"et onzi[eè]me"#)?,
-        |integer, _| {
-            Ok(OrdinalValue::new(integer.value().value + 11))
-        }
-    );
-    b.rule_2("<number> et demi",
-        integer_check_by_range!(0, 99),
-        b.reg(r#"et demie?"#)?,
-        |integer, _| {
-            FloatValue::new(integer.value().value as f64 + 0.5)
-        }
-    );
-    b.rule_1_terminal("70, 80, 90 (Belgium and Switzerland)",
-        b.reg(r#"(sept|huit|non)ante"#)?,
-        |text_match| {
-            let value = match text_match.group(1).as_ref() {
-                "sept" => 70,
-                "huit" => 80,
-                "non" => 90,
-                _ => return Err(RuleError::Invalid.into()),
-            };
-            IntegerValue::new(value)
-        }
-    );
-    b.rule_1_terminal("71, 81, 91 (Belgium and Switzerland)",
-        b.reg(r#"(sept|huit|non)ante et une?"#)?,
-        |text_match| {
-            let value = match text_match.group(1).as_ref() {
-                "sept" => 71,
-                "huit" => 81,
-                "non" => 91,
-                _ => return Err(RuleError::Invalid.into()),
-            };
-            IntegerValue::new(value)
-        }
+    b.rule_2("<number> <multiplier>",
+             number_check!(|number: &NumberValue| !number.prefixed() && !number.suffixed()),
+             integer_check!(|integer: &IntegerValue| integer.is_multiplier()),
+             |number, multiplier| {
+                 // Integers followed by cent, mille... are already composed above
+                 if let &NumberValue::Integer(_) = number.value() {
+                     if !multiplier.value().group {
+                         return Err(RuleError::Invalid.into());
+                     }
+                 }
+                 helpers::multiply_number(number.value(), multiplier.value())
+             }
     );
-
-    b.rule_2("72..79, 82..89, 92..99, (Belgium and Switzerland)",
-        b.reg(r#"(sept|huit|non)ante"#)?,
-        integer_check_by_range!(2, 9),
-        |text_match, integer| {
-            let value = match text_match.group(1).as_ref() {
-                "sept" => 70,
-                "huit" => 80,
-                "non" => 90,
-                _ => return Err(RuleError::Invalid.into()),
-            };
-            IntegerValue::new(value + integer.value().value)
-        }
+    b.rule_1_terminal("integer (roman numerals)",
+                      b.reg(r#"([mdclxvi]{2,})"#)?,
+                      |text_match| IntegerValue::new(helpers::roman_numeral(text_match.group(1), &ROMAN_NUMERAL_STOP_WORDS)?)
     );
-    b.rule_1_terminal("ordinal (100, 1_000, 1_000_000)",
-        b.reg(r#"(cent|mill|million|milliard)i[èe]me"#)?,
-        |text_match| {
-            let (value, grain) = match text_match.group(1).as_ref() {
-                "cent" => (100, 2),
-                "mill" => (1_000, 3),
-                "million" => (1_000_000, 6),
-                "milliard" => (1_000_000_000, 9),
-                _ => return Err(RuleError::Invalid.into()),
-            };
-            Ok(OrdinalValue::new_with_grain(value, grain))
-        }
+    b.rule_1_terminal("fraction (unicode)",
+                      b.reg(r#"(\d*)([½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅐⅛⅜⅝⅞⅑⅒])"#)?,
+                      |text_match| {
+                          let fraction = helpers::vulgar_fraction(text_match.group(2))?;
+                          if text_match.group(1).is_empty() {
+                              return Ok(fraction);
+                          }
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
     );
-
-    b.rule_2("ordinal (200..900, 2_000..9_000, 2_000_000..9_000_000_000)",
-        integer_check_by_range!(2, 999),
-        b.reg(r#"(cent|mill|million|milliard)i[èe]me"#)?,
-        |integer, text_match| {
-            let (value, grain) = match text_match.group(1).as_ref() {
-                "cent" => (100, 2),
-                "mill" => (1_000, 3),
-                "million" => (1_000_000, 6),
-                "milliard" => (1_000_000_000, 9),
-                _ => return Err(RuleError::Invalid.into()),
-            };
-            Ok(OrdinalValue::new_with_grain(integer.value().value * value, grain))
-        }
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d+)/(\d+)"#)?,
+                      |text_match| helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
     );
-
-    b.rule_2("ordinal (1_1_000..9_999_999_000)",
-        integer_check_by_range!(1000, 99_999_999_000),
-        ordinal_check!(|ordinal: &OrdinalValue| {
-            let grain = ordinal.grain.unwrap_or(0);
-            grain == 2 || grain % 3 == 0
-        }),
-        |integer, ordinal| {
-            let grain = ordinal.value().grain.unwrap_or(0);
-            let next_grain = (grain / 3) * 3 + 3;
-            if integer.value().value % 10i64.pow(next_grain as u32) != 0 { return Err(RuleError::Invalid.into()); }
-            Ok(OrdinalValue::new(integer.value().value + ordinal.value().value))
-        }
+    b.rule_1_terminal("mixed number (numeric)",
+                      b.reg(r#"(\d+) (\d+)/(\d+)"#)?,
+                      |text_match| {
+                          let fraction = helpers::fraction(text_match.group(2).parse()?, text_match.group(3).parse()?)?;
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
     );
-
-    b.rule_2("ordinal (102...9_999_999)",
-        integer_check!(|integer: &IntegerValue| integer.value >= 100 || integer.value % 100 == 0),
-        ordinal_check_by_range!(2, 99),
-        |integer, ordinal| {
-            Ok(OrdinalValue::new(integer.value().value + ordinal.value().value))
-        }
+    b.rule_2("<integer> <fraction denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(demie?s?|tiers|quarts?|cinqui[eè]mes?|sixi[eè]mes?|septi[eè]mes?|huiti[eè]mes?|neuvi[eè]mes?|dixi[eè]mes?)"#)?,
+             |integer, text_match| {
+                 let denominator = fraction_denominator(text_match.group(1), integer.value().value)?;
+                 helpers::spelled_fraction(integer.value().value, denominator)
+             }
     );
-    b.rule_2("ordinal (101, 201, 301, ...)",
-        integer_check!(|integer: &IntegerValue| integer.value >= 100 || integer.value % 100 == 0),
-        b.reg(r#"(?:et |-)?uni[èe]me"#)?,
-        |integer, _| {
-            Ok(OrdinalValue::new(integer.value().value + 1))
-        }
+    b.rule_3("<integer> et <fraction>",
+             integer_check_by_range!(0),
+             b.reg(r#"et"#)?,
+             number_check!(|number: &NumberValue| number.is_proper_fraction()),
+             |integer, _, fraction| helpers::mixed_number(integer.value(), fraction.value())
     );
-    b.rule_1_terminal("ordinal (digits)",
-             b.reg(r#"0*(\d+) ?(ere?|ère|ème|eme|ieme|ième)"#)?,
-             |text_match| {
-                 let value: i64 = text_match.group(1).parse()?;
-                 Ok(OrdinalValue::new(value))
-    });
     b.rule_1_terminal("ordinal 0",
                       b.reg(r#"z[eé]rot?i[eè]me"#)?,
                       |_| {
@@ -696,7 +535,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check_by_range!(0, 99),
              b.reg(r#"et demie?"#)?,
              |integer, _| {
-                 FloatValue::new(integer.value().value as f64 + 0.5)
+                 helpers::mixed_number(integer.value(), &helpers::fraction(1, 2)?.into())
              }
     );
     b.rule_1_terminal("70, 80, 90 (Belgium and Switzerland)",
@@ -800,6 +639,22 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           let value: i64 = text_match.group(1).parse()?;
                           Ok(OrdinalValue::new(value))
                       });
+    b.rule_1_terminal("ordinal (roman numerals)",
+                      b.reg(r#"([mdclxvi]+)([eè]me|ère|er|re|e)"#)?,
+                      |text_match| {
+                          let numeral = text_match.group(1);
+                          let value = helpers::roman_numeral(numeral, &ROMAN_NUMERAL_STOP_WORDS)?;
+                          let is_first = match text_match.group(2) {
+                              "er" | "re" | "ère" => true,
+                              _ => false,
+                          };
+                          // Only "Ier" may be written with a single letter, e.g. "François Ier"
+                          if is_first != (value == 1) || (numeral.len() < 2 && !is_first) {
+                              return Err(RuleError::Invalid.into());
+                          }
+                          Ok(OrdinalValue::new(value))
+                      }
+    );
     b.rule_2("le <ordinal>",
              b.reg(r#"l[ea]"#)?,
              ordinal_check!(),
@@ -807,3 +662,24 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("digit sequence",
+                      b.reg(r#"\d+(?:[ .\-]\d+)*"#)?,
+                      |text_match| helpers::digit_sequence_value(text_match.group(0))
+    );
+    b.rule_1_terminal("phone number (international with country code)",
+                      b.reg(r#"(?:\+|00)(\d{1,3})(?: ?\(0\) ?|[ .\-])(\d+(?:[ .\-]\d+)*)"#)?,
+                      |text_match| helpers::international_phone_number(Some(text_match.group(1)), text_match.group(2))
+    );
+    b.rule_1_terminal("phone number (international)",
+                      b.reg(r#"\+(\d+)"#)?,
+                      |text_match| helpers::international_phone_number(None, text_match.group(1))
+    );
+    b.rule_2("flight <code>",
+             b.reg(r#"(?:le )?vol(?: num[ée]ro)?"#)?,
+             b.reg(r#"([a-z][a-z0-9]|[0-9][a-z]) ?(\d{1,4})"#)?,
+             |_, code| Ok(helpers::digit_code(code.group(2))?.prefix(code.group(1)))
+    );
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use rustling::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
+
+/// Digits read one by one, possibly interrupted by filler words
+const DIGIT_SEQUENCE: &str = r#"(?:z[ée]ro|un|deux|trois|quatre|cinq|six|sept|huit|neuf)(?:,?[ -]+(?:(?:euh+|heu+|bah|ben|hum+),?[ -]+)?(?:z[ée]ro|un|deux|trois|quatre|cinq|six|sept|huit|neuf)){2,}"#;
+
+pub fn rules_spoken(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("integer (digit sequence)",
+                      b.reg(DIGIT_SEQUENCE)?,
+                      |text_match| helpers::digit_sequence(&spoken_digits(text_match.group(0)))
+    );
+    b.rule_1_terminal("digit sequence (spoken)",
+                      b.reg(DIGIT_SEQUENCE)?,
+                      |text_match| helpers::spoken_digit_sequence_value(&spoken_digits(text_match.group(0)))
+    );
+    b.rule_2("<number> <filler>",
+             number_check!(),
+             b.reg(r#"(?:euh+|heu+|bah|ben|hum+)"#)?,
+             |number, _| Ok(number.value().clone())
+    );
+    // Years are read by hundreds ("dix-neuf cent quatre-vingt-dix-neuf"), which the written
+    // number rules already compose
+    b.rule_3("military time (zéro <hour> heures)",
+             b.reg(r#"z[ée]ro"#)?,
+             integer_check_by_range!(0, 9),
+             b.reg(r#"heures?"#)?,
+             |_, hour, _| helpers::hour(hour.value().value as u32, false)
+    );
+    b.rule_3("military time (zéro <hour> <minutes>)",
+             b.reg(r#"z[ée]ro"#)?,
+             integer_check_by_range!(0, 9),
+             integer_check_by_range!(10, 59),
+             |_, hour, minute| helpers::hour_minute(hour.value().value as u32, minute.value().value as u32, false)
+    );
+    Ok(())
+}
+
+fn spoken_digits(text: &str) -> Vec<i64> {
+    text.split(|c: char| c == ' ' || c == '-' || c == ',')
+        .filter_map(spoken_digit)
+        .collect()
+}
+
+fn spoken_digit(word: &str) -> Option<i64> {
+    match word {
+        "zéro" | "zero" => Some(0),
+        "un" => Some(1),
+        "deux" => Some(2),
+        "trois" => Some(3),
+        "quatre" => Some(4),
+        "cinq" => Some(5),
+        "six" => Some(6),
+        "sept" => Some(7),
+        "huit" => Some(8),
+        "neuf" => Some(9),
+        // Filler words
+        _ => None,
+    }
+}
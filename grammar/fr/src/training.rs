@@ -10,13 +10,15 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(3.0, Some("degree")), "trois degrés", "3 degrés", "3°", "+3°", "3 °");
-    example!(v, check_temperature(32.0, Some("celsius")), "trente deux degrés celsius", "trente deux degrés centigrade", "32°C", "32 °c");
-    example!(v, check_temperature(-27.0, Some("celsius")), "moins 27 celsius", "-27C", "- 27 c");
-    example!(v, check_temperature(-5.0, Some("fahrenheit")), "moins cinq degrés fahrenheit", "-5 °F", "- 5°f");
-    example!(v, check_temperature(168.0, Some("fahrenheit")), "cent soixante-huit fahrenheit", "168 F", "168f");
-    example!(v, check_temperature(10.0, Some("kelvin")), "dix degrés kelvin", "10 °K", "10°k");
-    example!(v, check_temperature(21.0, Some("kelvin")), "21 kelvin", "21 K", "21k");
+    example!(v, check_temperature(3.0, Some(TemperatureUnit::Degree)), "trois degrés", "3 degrés", "3°", "+3°", "3 °");
+    example!(v, check_temperature(32.0, Some(TemperatureUnit::Celsius)), "trente deux degrés celsius", "trente deux degrés centigrade", "32°C", "32 °c");
+    example!(v, check_temperature(-27.0, Some(TemperatureUnit::Celsius)), "moins 27 celsius", "-27C", "- 27 c", "27 degrés celsius sous zéro");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Degree)), "moins cinq degrés", "cinq degrés en dessous de zéro", "5° sous zéro");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Fahrenheit)), "moins cinq degrés fahrenheit", "-5 °F", "- 5°f");
+    example!(v, check_temperature(168.0, Some(TemperatureUnit::Fahrenheit)), "cent soixante-huit fahrenheit", "168 F", "168f");
+    example!(v, check_temperature(10.0, Some(TemperatureUnit::Kelvin)), "dix degrés kelvin", "10 °K", "10°k");
+    example!(v, check_temperature(21.0, Some(TemperatureUnit::Kelvin)), "21 kelvin", "21 K", "21k");
+    example!(v, check_temperature(38.5, Some(TemperatureUnit::Degree)), "trente huit et demi degrés", "38½°");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -42,8 +44,16 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(100.0, Some("CNY"), Precision::Exact), "pile cent yuans", "100 yuan pile");
     example!(v, check_finance(7.0, Some("KRW"), Precision::Exact), "7 wons", "7₩");
     example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿", "3 ฿", "trois bitcoins");
+    example!(v, check_finance(0.5, Some("฿"), Precision::Exact), "0,5 BTC", "0,5 bitcoin");
+    example!(v, check_finance(2.0, Some("ETH"), Precision::Exact), "2 eth", "deux ethers");
+    example!(v, check_finance(300.0, Some("sat"), Precision::Exact), "300 sats", "trois cents satoshis");
+    example!(v, check_finance(1200000.0, Some("EUR"), Precision::Exact), "1,2 M€", "1,2 M €");
+    example!(v, check_finance(5000.0, Some("EUR"), Precision::Exact), "5k€", "5 k€", "5 k euros");
+    example!(v, check_finance(3000000000.0, Some("EUR"), Precision::Exact), "3 mrds €", "3 mds €", "3 Mrd €");
+    example!(v, check_finance(50.0, None, Precision::Exact), "50 balles", "cinquante balles");
     example!(v, check_finance(15.0, Some("$"), Precision::Approximate), "une quinzaine de dollars");
     example!(v, check_finance(3000000.0, Some("EUR"), Precision::Exact), "trois millions d'euros");
+    example!(v, check_finance(2.5, Some("EUR"), Precision::Exact), "2½ euros", "2 1/2 euros");
 }
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -165,6 +175,11 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2014, 1, 1]), "jour de l'an", "nouvel an", "premier janvier");
     example!(v, check_moment!(c, [2013, 12, 31]), "le réveillon de la saint sylvestre", "pour la saint-sylvestre");
     example!(v, check_moment!(c, [2013, 11, 1]), "la toussaint", "le jour de la toussaint", "la journée de la toussaint", "toussaint", "le jour des morts");
+    example!(v, check_moment_span!(c, [2013, 7, 9], [2013, 8, 8]), "le ramadan", "le mois du ramadan");
+    example!(v, check_moment!(c, [2013, 8, 8]), "l'aïd el-fitr", "la fête de la rupture du jeûne");
+    example!(v, check_moment!(c, [2013, 10, 15]), "l'aïd el-kébir", "aid al-adha");
+    example!(v, check_moment!(c, [2013, 9, 5]), "roch hachana", "rosh hashana");
+    example!(v, check_moment!(c, [2013, 9, 14]), "yom kippour", "le grand pardon");
     example!(v, check_moment!(c, [2013, 05, 1]), "fête du travail", "à la prochaine fête du travail");
     //Part of day (morning, afternoon...)
     example!(v, check_moment_span!(c, [2013, 2, 12, 12], [2013, 2, 12, 19]), "cet après-midi", "l'après-midi");
@@ -276,6 +291,8 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "pendant environ un quart d'heure", "environ 1/4h");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "durant une heure", "pendant exactement une heure");
     example!(v, check_duration!([0, 0, 2]), "pendant 2 semaines");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 40]), "pendant deux tiers d'heure", "pendant 2/3 d'heure");
+    example!(v, check_duration!([0, 0, 0, 1, 18]), "pendant 1¾ jour", "pendant 1,75 jour");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -344,6 +361,20 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(0.03), "0,03", "zéro virgule zéro trois", "zero point zero trois");
     example!(v, check_float(32.75), "32,75", "trente-deux virgule soixante-quinze");
     example!(v, check_float(10.08), "10,08", "dix virgule zéro huit", "dix point zéro huit");
+    example!(v, check_integer(14), "xiv");
+    example!(v, check_integer(58), "lviii");
+    example!(v, check_integer(5000000), "5 millions", "cinq millions");
+    example!(v, check_integer(2500000), "2,5 millions", "deux virgule cinq millions");
+    example!(v, check_integer(36), "3 douzaines", "trois douzaines");
+    example!(v, check_integer(180), "15 douzaines");
+    example!(v, check_float(0.75), "trois quarts", "3/4", "¾");
+    example!(v, check_float(2.0 / 3.0), "deux tiers", "⅔");
+    example!(v, check_float(0.5), "un demi", "une demie", "½");
+    example!(v, check_float(0.2), "un cinquième", "1/5");
+    example!(v, check_float(1.5), "un et demi", "1½", "1 1/2");
+    example!(v, check_float(2.25), "deux et un quart");
+    example!(v, check_ordinal(20), "xxe", "xxème", "le xxe");
+    example!(v, check_ordinal(1), "ier");
     example!(v, check_ordinal(1), "1er", "1ere", "le 1er");
     example!(v,
              check_ordinal(3),
@@ -354,3 +385,26 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
              "troisième",
              "troisieme");
 }
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age!([7]), "il a 7 ans", "j'ai 7 ans", "âgé de 7 ans", "agée de sept ans");
+    example!(v, check_age!([0, 6]), "âgée de 6 mois");
+    example!(v, check_age!([18], [25]), "âgés de 18 à 25 ans");
+    example!(v, check_age(None, Some(period!(18))), "les moins de 18 ans");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("0612345678"), "06 12 34 56 78", "06.12.34.56.78");
+    example!(v, check_phone_number("33612345678"), "+33 6 12 34 56 78", "+33 (0)6 12 34 56 78");
+    example!(v, check_digit_sequence("4815"), "4 8 1 5");
+    example!(v, check_digit_sequence("1234"), "le vol AF 1234", "vol af1234");
+}
+
+pub fn examples_spoken(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_integer(6123456), "six un deux trois quatre cinq six", "six un deux, euh, trois quatre cinq six");
+    example!(v, check_integer(1200), "mille euh deux cents");
+    example!(v, check_phone_number("0612345678"), "zéro six un deux trois quatre cinq six sept huit");
+    example!(v, check_moment!(c, [2013, 2, 12, 7]), "zéro sept heures", "à zéro sept heures");
+    example!(v, check_moment!(c, [2013, 2, 12, 9, 15]), "zéro neuf quinze");
+}
@@ -7,7 +7,7 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
     b.rule_2("<number> per cent",
         number_check!(),
         b.reg(r"%|per ?cento?")?,
-        |number, _| Ok(PercentageValue(number.value().value()))
+        |number, _| Ok(PercentageValue::from(number.value()))
     );
     Ok(())
 }
@@ -127,6 +127,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     decimal: a.value().decimal(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -137,6 +138,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
+                     decimal: b.value().decimal(),
                      unit: a.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -148,6 +150,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, _, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value as f64,
+                     decimal: Some(a.value().value.into()),
                      precision: Exact,
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
@@ -210,7 +213,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("degree"),
+                     unit: Some(TemperatureUnit::Degree),
                      latent: false,
                  })
              });
@@ -220,7 +223,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
                  })
              });
@@ -230,7 +233,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
                  })
              });
@@ -240,7 +243,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("kelvin"),
+                     unit: Some(TemperatureUnit::Kelvin),
                      latent: false,
                  })
              });
@@ -252,7 +252,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              datetime_check!(),
              |_, datetime| helpers::cycle_nth_after_not_immediate(Grain::Day, -1, datetime.value())
     );
-    b.rule_4("last <day-of-week> of <datetime> (latent)",
+    b.rule_4("last <cycle> of <datetime>",
              b.reg(r#"ultim[oa]"#)?,
              cycle_check!(),
              b.reg(r#"d(?:['i]|el(?:l['ao])?)"#)?,
@@ -335,16 +335,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  }
              }
     );
-    b.rule_2("in <year>",
-             b.reg(r#"[dn]el(?: corso del(?:l' ?anno)?)?"#)?,
-             datetime_check!(|datetime: &DatetimeValue| !datetime.latent && form!(Form::Year(_))(datetime)),
-             |_, year| Ok(year.value().clone())
-    );
-    b.rule_2("in <year>",
-             b.reg(r#"nel corso del|(?:del)?l' ?anno"#)?,
-             datetime_check!(|datetime: &DatetimeValue| form!(Form::Year(_))(datetime)),
-             |_, year| Ok(year.value().clone())
-    );
     b.rule_1("year (latent)",
              integer_check_by_range!(2101, 3000),
              |integer| {
@@ -355,6 +345,16 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  }
              }
     );
+    b.rule_2("in <year>",
+             b.reg(r#"[dn]el(?: corso del(?:l' ?anno)?)?"#)?,
+             datetime_check!(|datetime: &DatetimeValue| !datetime.latent && form!(Form::Year(_))(datetime)),
+             |_, year| Ok(year.value().clone())
+    );
+    b.rule_2("in <year>",
+             b.reg(r#"nel corso del|(?:del)?l' ?anno"#)?,
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Year(_))(datetime)),
+             |_, year| Ok(year.value().clone())
+    );
     // Days of the month
     b.rule_1_terminal("first of the month",
                       b.reg(r#"(?:il )?(?:1|prim)[o°](?: di)?"#)?,
@@ -1107,19 +1107,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  start.span_to(&end, true)
              }
     );
-    b.rule_6("from dd-<day-of-week> dd <month> to (interval)",
-             b.reg(r#"dal?(?:l['o])?"#)?,
-             b.reg(r#"(3[01]|[12]\d|0?[1-9])"#)?,
-             b.reg(r#"\-|a(?:l(?:l['o]))"#)?,
-             datetime_check!(form!(Form::DayOfWeek{..})),
-             b.reg(r#"(3[01]|[12]\d|0?[1-9])"#)?,
-             datetime_check!(form!(Form::Month(_))),
-             |_, a, _, _, b, month| {
-                 let start = month.value().intersect(&helpers::day_of_month(a.group(1).parse()?)?)?;
-                 let end = month.value().intersect(&helpers::day_of_month(b.group(1).parse()?)?)?;
-                 start.span_to(&end, true)
-             }
-    );
     // TODO: fix this, doesn't make sense
 //    b.rule_6("from dd-<day-of-week> dd <month> to (interval)",
 //             b.reg(r#"dal?(?:l['o])?"#)?,
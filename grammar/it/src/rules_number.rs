@@ -3,6 +3,32 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
 
+// Words and abbreviations which are valid roman numerals, e.g. the pronouns "mi", "ci", "vi"
+// and "li", the preposition "di", or units like "cm"
+const ROMAN_NUMERAL_STOP_WORDS: [&str; 14] = [
+    "cc", "cd", "ci", "cl", "cm", "cv", "di", "dl", "li", "mi", "ml", "mm", "vi", "xl",
+];
+
+// Denominators are singular after "un" and plural otherwise, which keeps "ventesimo" forms apart
+fn fraction_denominator(word: &str, numerator: i64) -> RuleResult<i64> {
+    let stem = &word[..word.len() - 1];
+    if word.ends_with('i') == (numerator == 1) {
+        return Err(RuleError::Invalid.into());
+    }
+    match stem {
+        "mezz" => Ok(2),
+        "terz" => Ok(3),
+        "quart" => Ok(4),
+        "quint" => Ok(5),
+        "sest" => Ok(6),
+        "settim" => Ok(7),
+        "ottav" => Ok(8),
+        "non" => Ok(9),
+        "decim" => Ok(10),
+        _ => Err(RuleError::Invalid.into()),
+    }
+}
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
              number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
@@ -146,7 +172,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  })
              });
     b.rule_1_terminal("million",
-                      b.reg(r#"milione?"#)?,
+                      b.reg(r#"milion[ei]?"#)?,
                       |_| IntegerValue::new_with_grain(1000000, 6)
     );
     b.rule_2("N millions",
@@ -160,7 +186,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  })
              });
     b.rule_1_terminal("billion",
-                      b.reg(r#"miliardo"#)?,
+                      b.reg(r#"miliard[oi]"#)?,
                       |_| IntegerValue::new_with_grain(1000000000, 9)
     );
     b.rule_2("N billions",
@@ -173,6 +199,72 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..IntegerValue::default()
                  })
              });
+    b.rule_1_terminal("dozen",
+                      b.reg(r#"dozzin[ae]"#)?,
+                      |_| Ok(IntegerValue {
+                          value: 12,
+                          grain: Some(1),
+                          group: true,
+                          ..IntegerValue::default()
+                      })
+    );
+    b.rule_2("<number> <multiplier>",
+             number_check!(|number: &NumberValue| !number.prefixed() && !number.suffixed()),
+             integer_check!(|integer: &IntegerValue| integer.is_multiplier()),
+             |number, multiplier| {
+                 // Integers followed by cento, mille... are already composed above
+                 if let &NumberValue::Integer(_) = number.value() {
+                     if !multiplier.value().group {
+                         return Err(RuleError::Invalid.into());
+                     }
+                 }
+                 helpers::multiply_number(number.value(), multiplier.value())
+             }
+    );
+    b.rule_1_terminal("integer (roman numerals)",
+                      b.reg(r#"([mdclxvi]{2,})"#)?,
+                      |text_match| IntegerValue::new(helpers::roman_numeral(text_match.group(1), &ROMAN_NUMERAL_STOP_WORDS)?)
+    );
+    b.rule_1_terminal("fraction (unicode)",
+                      b.reg(r#"(\d*)([½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅐⅛⅜⅝⅞⅑⅒])"#)?,
+                      |text_match| {
+                          let fraction = helpers::vulgar_fraction(text_match.group(2))?;
+                          if text_match.group(1).is_empty() {
+                              return Ok(fraction);
+                          }
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
+    );
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d+)/(\d+)"#)?,
+                      |text_match| helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("mixed number (numeric)",
+                      b.reg(r#"(\d+) (\d+)/(\d+)"#)?,
+                      |text_match| {
+                          let fraction = helpers::fraction(text_match.group(2).parse()?, text_match.group(3).parse()?)?;
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
+    );
+    b.rule_2("<integer> <fraction denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(mezz[oi]|terz[oi]|quart[oi]|quint[oi]|sest[oi]|settim[oi]|ottav[oi]|non[oi]|decim[oi])"#)?,
+             |integer, text_match| {
+                 let denominator = fraction_denominator(text_match.group(1), integer.value().value)?;
+                 helpers::spelled_fraction(integer.value().value, denominator)
+             }
+    );
+    b.rule_2("<integer> e mezzo",
+             integer_check_by_range!(0),
+             b.reg(r#"e mezz[oa]"#)?,
+             |integer, _| helpers::mixed_number(integer.value(), &helpers::fraction(1, 2)?.into())
+    );
+    b.rule_3("<integer> e <fraction>",
+             integer_check_by_range!(0),
+             b.reg(r#"e"#)?,
+             number_check!(|number: &NumberValue| number.is_proper_fraction()),
+             |integer, _, fraction| helpers::mixed_number(integer.value(), fraction.value())
+    );
     b.rule_1_terminal("integer (numeric)",
                       b.reg(r#"(\d{1,18})"#)?,
                       |text_match| {
@@ -193,19 +285,14 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"(\d*,\d+)"#)?,
                       |text_match| {
                           let reformatted_string = text_match.group(1).replace(",", ".");
-                          let value: f64 = reformatted_string.parse()?;
-                          FloatValue::new(value)
+                          FloatValue::from_decimal(reformatted_string.parse()?)
                       });
     b.rule_3("number dot number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
              b.reg(r#"punto|virgola"#)?,
              integer_check!(|integer: &IntegerValue| !integer.suffixed),
              |a, _, b| {
-                 let value: f64 = format!("{}.{}", a.value().value, b.value().value).parse()?;
-                 Ok(FloatValue {
-                     value,
-                     ..FloatValue::default()
-                 })
+                 FloatValue::from_decimal(format!("{}.{}", a.value().value, b.value().value).parse()?)
              });
     b.rule_4("number dot zero ... number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
@@ -214,18 +301,13 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check!(|integer: &IntegerValue| !integer.suffixed),
              |a, _, zeros, b| {
                  let zeros_string =  std::iter::repeat("0").take(zeros.group(0).split_whitespace().count()).collect::<String>();
-                 let value: f64 = format!("{}.{}{}", a.value().value, zeros_string, b.value().value).parse()?;
-                 Ok(FloatValue {
-                     value,
-                     ..FloatValue::default()
-                 })
+                 FloatValue::from_decimal(format!("{}.{}{}", a.value().value, zeros_string, b.value().value).parse()?)
              });
     b.rule_1_terminal("decimal with thousands separator",
                       b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
                       |text_match| {
                           let reformatted_string = text_match.group(1).replace(".", "").replace(",", ".");
-                          let value: f64 = reformatted_string.parse()?;
-                          FloatValue::new(value)
+                          FloatValue::from_decimal(reformatted_string.parse()?)
                       });
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|meno"#)?,
@@ -244,6 +326,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      NumberValue::Float(float) => {
                          FloatValue {
                              value: float.value * -1.0,
+                             decimal: float.decimal.map(|it| -it),
                              prefixed: true,
                              ..float
                          }
@@ -305,6 +388,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                          } else {
                              FloatValue {
                                  value: product,
+                                 decimal: float.decimal.and_then(|it| it.checked_mul(multiplier.into())),
                                  suffixed: true,
                                  ..float
                              }
@@ -348,3 +432,24 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       });
     Ok(())
 }
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("digit sequence",
+                      b.reg(r#"\d+(?:[ .\-]\d+)*"#)?,
+                      |text_match| helpers::digit_sequence_value(text_match.group(0))
+    );
+    b.rule_1_terminal("phone number (international with country code)",
+                      b.reg(r#"(?:\+|00)(\d{1,3})(?: ?\(0\) ?|[ .\-])(\d+(?:[ .\-]\d+)*)"#)?,
+                      |text_match| helpers::international_phone_number(Some(text_match.group(1)), text_match.group(2))
+    );
+    b.rule_1_terminal("phone number (international)",
+                      b.reg(r#"\+(\d+)"#)?,
+                      |text_match| helpers::international_phone_number(None, text_match.group(1))
+    );
+    b.rule_2("flight <code>",
+             b.reg(r#"(?:il )?volo(?: numero)?"#)?,
+             b.reg(r#"([a-z][a-z0-9]|[0-9][a-z]) ?(\d{1,4})"#)?,
+             |_, code| Ok(helpers::digit_code(code.group(2))?.prefix(code.group(1)))
+    );
+    Ok(())
+}
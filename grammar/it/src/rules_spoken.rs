@@ -0,0 +1,46 @@
+use rustling::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
+
+pub fn rules_spoken(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("integer (digit sequence)",
+                      b.reg(r#"(?:zero|uno|due|tre|quattro|cinque|sei|sette|otto|nove)(?:,?[ -]+(?:(?:ehm+|eh+|uhm+|mmm+),?[ -]+)?(?:zero|uno|due|tre|quattro|cinque|sei|sette|otto|nove)){2,}"#)?,
+                      |text_match| {
+                          let digits = text_match.group(0)
+                              .split(|c: char| c == ' ' || c == '-' || c == ',')
+                              .filter_map(spoken_digit)
+                              .collect::<Vec<_>>();
+                          helpers::digit_sequence(&digits)
+                      }
+    );
+    b.rule_2("<number> <filler>",
+             number_check!(),
+             b.reg(r#"(?:ehm+|eh+|uhm+|mmm+)"#)?,
+             |number, _| Ok(number.value().clone())
+    );
+    // Years are read as a whole number ("millenovecentonovantanove"), there is no paired form
+    b.rule_3("military time (zero <hour> <minutes>)",
+             b.reg(r#"zero"#)?,
+             integer_check_by_range!(0, 9),
+             integer_check_by_range!(10, 59),
+             |_, hour, minute| helpers::hour_minute(hour.value().value as u32, minute.value().value as u32, false)
+    );
+    Ok(())
+}
+
+fn spoken_digit(word: &str) -> Option<i64> {
+    match word {
+        "zero" => Some(0),
+        "uno" => Some(1),
+        "due" => Some(2),
+        "tre" => Some(3),
+        "quattro" => Some(4),
+        "cinque" => Some(5),
+        "sei" => Some(6),
+        "sette" => Some(7),
+        "otto" => Some(8),
+        "nove" => Some(9),
+        // Filler words
+        _ => None,
+    }
+}
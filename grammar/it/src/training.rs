@@ -267,6 +267,8 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "durante un quarto d'ora all'incirca", "durante circa un quarto d'ora", "per più o meno 1/4 d'ora");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "durante un'ora", "per un'ora");
     example!(v, check_duration!([0, 0, 2]), "durante 2 settimane", "per due settimane");
+    example!(v, check_duration!([0, 0, 0, 0, 2, 45]), "durante due ore e tre quarti", "durante 2¾ ore", "per 2,75 ore");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 20]), "durante un terzo d'ora", "per 1/3 di ora");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -299,6 +301,16 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(0.03), "0,03", "zero punto zero tre", "zero virgola zero tre");
     example!(v, check_float(32.75), "32,75", "trenta due punto settanta cinque", "trenta due virgola settanta cinque");
     example!(v, check_float(10.08), "10,08", "dieci punto zero otto");//, "dieci virgola zero otto");
+    example!(v, check_integer(14), "xiv");
+    example!(v, check_integer(21), "xxi");
+    example!(v, check_integer(5000000), "5 milioni", "cinque milioni");
+    example!(v, check_integer(2500000), "2,5 milioni");
+    example!(v, check_integer(36), "3 dozzine", "tre dozzine");
+    example!(v, check_float(0.75), "tre quarti", "3/4", "¾");
+    example!(v, check_float(2.0 / 3.0), "due terzi", "⅔");
+    example!(v, check_float(0.5), "un mezzo", "½");
+    example!(v, check_float(1.5), "uno e mezzo", "1½", "1 1/2");
+    example!(v, check_float(2.25), "due e un quarto");
     example!(v, check_ordinal(1), "1o", "1a", "il 1o", "la 1a", "1°");
     example!(v, check_ordinal(3), "3o", "il 3o", "3a", "la 3a", "3°", "terzo", "terza");
 }
@@ -310,13 +322,14 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(3.0, Some("degree")), "tre gradi", "3 gradi", "3°", "+3°", "3 °");
-    example!(v, check_temperature(32.0, Some("celsius")), "trenta due gradi celsius", "trenta due gradi centigradi", "32°C", "32° C", "32° c", "32°c", "32 °c");
-    example!(v, check_temperature(-27.0, Some("celsius")), "meno 27 celsius", "meno 27 gradi celsius", "meno venti sette gradi celsius", "-27C", "-27°C", "-27° C", "-27°c", "-27° c", "- 27 c");
-    example!(v, check_temperature(-5.0, Some("fahrenheit")), "meno cinque gradi fahrenheit", "-5 °F", "-5°F", "-5°f", "-5° f", "- 5°f");
-    example!(v, check_temperature(168.0, Some("fahrenheit")), "cento sessant otto fahrenheit", "cento sessant otto gradi fahrenheit", "168 gradi fahrenheit", "168° F", "168 F", "168f");
-    example!(v, check_temperature(10.0, Some("kelvin")), "dieci gradi kelvin", "dieci kelvin", "10°K", "10° K", "10 °K", "10°k");
-    example!(v, check_temperature(21.0, Some("kelvin")), "21 kelvin", "vent uno gradi kelvin", "21°K", "21° K", "21 °K", "21°k", "21 K", "21k");
+    example!(v, check_temperature(3.0, Some(TemperatureUnit::Degree)), "tre gradi", "3 gradi", "3°", "+3°", "3 °");
+    example!(v, check_temperature(32.0, Some(TemperatureUnit::Celsius)), "trenta due gradi celsius", "trenta due gradi centigradi", "32°C", "32° C", "32° c", "32°c", "32 °c");
+    example!(v, check_temperature(-27.0, Some(TemperatureUnit::Celsius)), "meno 27 celsius", "meno 27 gradi celsius", "meno venti sette gradi celsius", "-27C", "-27°C", "-27° C", "-27°c", "-27° c", "- 27 c");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Fahrenheit)), "meno cinque gradi fahrenheit", "-5 °F", "-5°F", "-5°f", "-5° f", "- 5°f");
+    example!(v, check_temperature(168.0, Some(TemperatureUnit::Fahrenheit)), "cento sessant otto fahrenheit", "cento sessant otto gradi fahrenheit", "168 gradi fahrenheit", "168° F", "168 F", "168f");
+    example!(v, check_temperature(10.0, Some(TemperatureUnit::Kelvin)), "dieci gradi kelvin", "dieci kelvin", "10°K", "10° K", "10 °K", "10°k");
+    example!(v, check_temperature(21.0, Some(TemperatureUnit::Kelvin)), "21 kelvin", "vent uno gradi kelvin", "21°K", "21° K", "21 °K", "21°k", "21 K", "21k");
+    example!(v, check_temperature(37.5, Some(TemperatureUnit::Degree)), "trenta sette e mezzo gradi", "37½°");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -338,6 +351,7 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(2005.0, Some("SEK"), Precision::Exact), "2005 corone svedesi", "due mila cinque corone svedesi");
     example!(v, check_finance(96.0, Some("INR"), Precision::Approximate), "approssimativamente 96 rupie", "novanta sei rupie all'incirca");
     example!(v, check_finance(5.3, Some("RUB"), Precision::Exact), "cinque rubli e trenta", "5,3 rubli");
+    example!(v, check_finance(2.5, Some("EUR"), Precision::Exact), "2½ €", "2 1/2 euro");
     example!(v, check_finance(89.0, Some("JPY"), Precision::Exact), "esattamente 89 JPY", "89 yen esatti", "precisamente ottanta nove yen giapponesi");
     example!(v, check_finance(8.0, Some("¥"), Precision::Exact), "8¥");
     example!(v, check_finance(100.0, Some("CNY"), Precision::Exact), "cento yuan esatti", "esattamente 100 yuan cinesi", "100 renminbi precisi");
@@ -346,4 +360,24 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     // TODO: FIXME - add approx. numbers in IT rules
     // todo_example!(v, check_finance(15.0, Some("$"), Precision::Approximate), "una quindicina di dollari", "una 15ina di dollari");
     example!(v, check_finance(3000000.0, Some("EUR"), Precision::Exact), "tre milioni di euro");
-}
\ No newline at end of file
+}
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age!([34]), "34 anni di età", "ho 34 anni");
+    example!(v, check_age!([18], [25]), "tra 18 e 25 anni di età");
+    example!(v, check_age(None, Some(period!(18))), "minori di 18 anni");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("3471234567"), "347 123 4567");
+    example!(v, check_phone_number("0612345678"), "06 1234 5678");
+    example!(v, check_digit_sequence("4815"), "4 8 1 5");
+    example!(v, check_digit_sequence("1234"), "volo AZ 1234");
+}
+
+pub fn examples_spoken(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_integer(3471234), "tre quattro sette uno due tre quattro", "tre quattro sette, ehm, uno due tre quattro");
+    example!(v, check_integer(203), "duecento ehm tre");
+    example!(v, check_moment!(c, [2013, 2, 12, 9, 15]), "zero nove quindici");
+}
@@ -8,7 +8,7 @@ pub mod training;
 
 use rustling_ontology_values::DimensionKind::*;
 
-pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+fn rule_set_builder() -> ::rustling::RustlingResult<::rustling::RuleSetBuilder<rustling_ontology_values::Dimension>> {
     let mut b = ::rustling::RuleSetBuilder::new(
                     ::rustling::BoundariesChecker::no_check(),
                     ::rustling::BoundariesChecker::no_check());
@@ -19,6 +19,17 @@ pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ont
     rules::rules_temperature(&mut b)?;            
     rules::rules_finance(&mut b)?;
     rules::rules_percentage(&mut b)?;
+    Ok(b)
+}
+
+pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    Ok(rule_set_builder()?.build())
+}
+
+/// Rules for raw speech recognition transcripts, on top of the regular ones.
+pub fn spoken_rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    let mut b = rule_set_builder()?;
+    rules::rules_spoken(&mut b)?;
     Ok(b.build())
 }
 
@@ -37,6 +48,12 @@ pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Di
     v
 }
 
+pub fn spoken_examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
+    let mut v = examples();
+    training::examples_spoken(&mut v);
+    v
+}
+
 #[cfg(test)]
 mod test {
     use rustling::*;
@@ -59,4 +76,10 @@ mod test {
         let examples = examples();
         assert_examples(&rules, examples);
     }
+    #[test]
+    fn test_spoken_examples() {
+        let rules = spoken_rule_set().unwrap();
+        let examples = spoken_examples();
+        assert_examples(&rules, examples);
+    }
 }
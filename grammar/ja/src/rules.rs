@@ -2,6 +2,7 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::Decimal;
 use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
 
 fn ja_quantifier_regex() -> &'static str {
@@ -81,6 +82,78 @@ impl<'a> JapaneseReplace for &'a str {
   }
 }
 
+// Imperial eras since the Meiji restoration: kanji name, letter abbreviation and first day.
+// Abbreviation letters are lowercase, like the parsed input
+const JAPANESE_ERAS: [(&str, &str, (i32, u32, u32)); 5] = [
+    ("明治", "m", (1868, 10, 23)),
+    ("大正", "t", (1912, 7, 30)),
+    ("昭和", "s", (1926, 12, 25)),
+    ("平成", "h", (1989, 1, 8)),
+    ("令和", "r", (2019, 5, 1)),
+];
+
+fn japanese_era(era: &str) -> RuleResult<usize> {
+    JAPANESE_ERAS.iter()
+        .position(|&(name, letter, _)| name == era || letter == era)
+        .ok_or_else(|| RuleError::Invalid.into())
+}
+
+fn japanese_era_year_number(number: &str) -> RuleResult<i64> {
+    if number == "元" {
+        Ok(1)
+    } else {
+        Ok(number.parse()?)
+    }
+}
+
+fn japanese_era_year(era: &str, number: i64) -> RuleResult<i32> {
+    let index = japanese_era(era)?;
+    let (start_year, _, _) = JAPANESE_ERAS[index].2;
+    let year = start_year + number as i32 - 1;
+    // The last year of an era is the first year of the next one
+    let is_after_era = JAPANESE_ERAS.get(index + 1)
+        .map(|&(_, _, (next_start_year, _, _))| year > next_start_year)
+        .unwrap_or(false);
+    if number < 1 || is_after_era {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(year)
+}
+
+/// Year of an era. The first and last years of an era are shared with the neighbouring eras,
+/// so they only span the days of the era, e.g. 平成31年 ends on April 30th 2019.
+fn japanese_era_year_value(era: &str, number: i64) -> RuleResult<DatetimeValue> {
+    let index = japanese_era(era)?;
+    let year = japanese_era_year(era, number)?;
+    let start = match JAPANESE_ERAS[index].2 {
+        start if start.0 == year => start,
+        _ => (year, 1, 1),
+    };
+    let end = match JAPANESE_ERAS.get(index + 1) {
+        Some(&(_, _, next_start)) if next_start.0 == year => next_start,
+        _ => (year + 1, 1, 1),
+    };
+    if start == (year, 1, 1) && end == (year + 1, 1, 1) {
+        return helpers::year(year);
+    }
+    Ok(helpers::year_month_day(start.0, start.1, start.2)?
+        .span_to(&helpers::year_month_day(end.0, end.1, end.2)?, false)?
+        .form(Form::Year(year)))
+}
+
+fn japanese_era_date(era: &str, number: i64, month: u32, day: u32) -> RuleResult<DatetimeValue> {
+    let index = japanese_era(era)?;
+    let year = japanese_era_year(era, number)?;
+    let date = (year, month, day);
+    let is_after_era = JAPANESE_ERAS.get(index + 1)
+        .map(|&(_, _, next_start)| date >= next_start)
+        .unwrap_or(false);
+    if date < JAPANESE_ERAS[index].2 || is_after_era {
+        return Err(RuleError::Invalid.into());
+    }
+    helpers::year_month_day(year, month, day)
+}
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     b.rule_2("intersect",
@@ -236,22 +309,14 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1("float number", 
         b.reg(r#"((?:\d|〇)*[、,，\.](?:\d|〇)+)"#)?, |text_match| {
           let res = text_match.group(1).replace_japanese_digit().replace_comma();
-          let value: f64 = res.parse()?;
-          Ok(FloatValue {
-              value: value,
-              ..FloatValue::default()
-          })
+          FloatValue::from_decimal(res.parse()?)
     });
     b.rule_3("number dot number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
              b.reg(r#"てん|テン|[、,，\.]|点"#)?,
              integer_check!(|integer: &IntegerValue| !integer.suffixed),
              |a, _, b| {
-                 let value: f64 = format!("{}.{}", a.value().value, b.value().value).parse()?;
-                 Ok(FloatValue {
-                     value,
-                     ..FloatValue::default()
-                 })
+                 FloatValue::from_decimal(format!("{}.{}", a.value().value, b.value().value).parse()?)
              });
     b.rule_3("number dot number",
          number_check!(|number: &NumberValue| !number.prefixed()),
@@ -278,12 +343,43 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                                              decimal_part_string.chars()
                                                  .filter_map(number_mapping)
                                                  .collect::<String>());
-              let decimal_part: f64 = decimal_part_string.parse()?;
+              let decimal_part: Decimal = decimal_part_string.parse()?;
               Ok(FloatValue {
-                 value: a.value().value() + decimal_part,
+                 value: a.value().value() + decimal_part.to_f64(),
+                 decimal: a.value().decimal().and_then(|it| it.checked_add(decimal_part)),
                  ..FloatValue::default()
               })
     });
+    b.rule_1_terminal("fraction (unicode)",
+                      b.reg(r#"(\d*)([½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅐⅛⅜⅝⅞⅑⅒])"#)?,
+                      |text_match| {
+                          let fraction = helpers::vulgar_fraction(text_match.group(2))?;
+                          if text_match.group(1).is_empty() {
+                              return Ok(fraction);
+                          }
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
+    );
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d+)/(\d+)"#)?,
+                      |text_match| helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_3("<integer>分の<integer>",
+             integer_check_by_range!(2),
+             b.reg(r#"分の"#)?,
+             integer_check_by_range!(0),
+             |denominator, _, numerator| helpers::spelled_fraction(numerator.value().value, denominator.value().value)
+    );
+    b.rule_1_terminal("half - 半分",
+                      b.reg(r#"半分"#)?,
+                      |_| helpers::spelled_fraction(1, 2)
+    );
+    b.rule_3("<integer>と<fraction>",
+             integer_check_by_range!(0),
+             b.reg(r#"と"#)?,
+             number_check!(|number: &NumberValue| number.is_proper_fraction()),
+             |integer, _, fraction| helpers::mixed_number(integer.value(), fraction.value())
+    );
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|マイナス|零下|れいか"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -301,6 +397,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      NumberValue::Float(float) => {
                          FloatValue {
                              value: float.value * -1.0,
+                             decimal: float.decimal.map(|it| -it),
                              prefixed: true,
                              ..float
                          }
@@ -338,19 +435,19 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
     b.rule_2("<number> per cent",
         number_check!(),
         b.reg(r#"パーセント|%|％"#)?,
-        |number, _| Ok(PercentageValue(number.value().value()))
+        |number, _| Ok(PercentageValue::from(number.value()))
     );
     b.rule_1_terminal("ten per cent",
         b.reg(r#"割"#)?,
-        |_| Ok(PercentageValue(10.0))
+        |_| Ok(PercentageValue(10.0, Some(Decimal::new(10, 0))))
     );
         b.rule_1_terminal("one per cent",
         b.reg(r#"分"#)?,
-        |_| Ok(PercentageValue(1.0))
+        |_| Ok(PercentageValue(1.0, Some(Decimal::new(1, 0))))
     );
     b.rule_1_terminal("zero dot one per cent",
         b.reg(r#"厘"#)?,
-        |_| Ok(PercentageValue(0.1))
+        |_| Ok(PercentageValue(0.1, Some(Decimal::new(1, 1))))
     );
     Ok(())
 }
@@ -454,6 +551,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
+                     decimal: a.value().decimal(),
                      unit: b.value().unit,
                      ..AmountOfMoneyValue::default()
                  })
@@ -496,7 +594,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
-                     unit: Some("degree"),
+                     unit: Some(TemperatureUnit::Degree),
                      latent: false,
                  })
              });
@@ -506,7 +604,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
                  })
              });
@@ -516,7 +614,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
                  })
              });
@@ -526,18 +624,18 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
-                     unit: Some("kelvin"),
+                     unit: Some(TemperatureUnit::Kelvin),
                      latent: false,
                  })
              });
-    b.rule_3("<temp> Celsius",
+    b.rule_3("Celsius <temp>",
             b.reg(r#"摂氏"#)?,
             number_check!(),
             b.reg(r#"度|ど|°"#)?,
             |_, a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
                  })
              });
@@ -548,18 +646,18 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
             |_, a, _| {
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value(),
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
                  })
              });
-    b.rule_3("<temp> Fahrenheit",
+    b.rule_3("Fahrenheit <temp>",
         b.reg(r#"華氏|カ氏"#)?,
         number_check!(),
         b.reg(r#"度|ど|°"#)?,
         |_, a, _| {
             Ok(TemperatureValue {
                      value: a.value().value(),
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
                  })
         }
@@ -571,7 +669,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
         |_, a, _| {
             Ok(TemperatureValue {
                      value: -1.0 * a.value().value(),
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
                  })
         }
@@ -942,7 +1040,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_2("by the end of <datetime>",
              datetime_check!(),
-             b.reg(r#"の終わりまでに|末の前に"#)?,
+             b.reg(r#"の?(?:終わり|末)までに|末の前に"#)?,
              |a, _| helpers::cycle_nth(Grain::Second, 0)?.span_to(a.value(), true)
     );
     b.rule_2("last ten days of the month",
@@ -1875,6 +1973,20 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  start.span_to(&end, true)
              }
     );
+    b.rule_5("<month> dd-dd (interval)",
+             datetime_check!(form!(Form::Month(_))),
+             integer_check_by_range!(1, 31),
+             b.reg(r#"日?ー"#)?,
+             integer_check_by_range!(1, 31),
+             b.reg(r#"日"#)?,
+             |month, a, _, b, _| {
+                 let start = month.value()
+                     .intersect(&helpers::day_of_month(a.value().value as u32)?)?;
+                 let end = month.value()
+                     .intersect(&helpers::day_of_month(b.value().value as u32)?)?;
+                 start.span_to(&end, true)
+             }
+    );
     b.rule_4("dd-dd (interval)",
              integer_check_by_range!(1, 31),
              b.reg(r#"日?ー"#)?,
@@ -1897,20 +2009,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  start.span_to(&end, true)
              }
     );
-    b.rule_5("<month> dd-dd (interval)",
-             datetime_check!(form!(Form::Month(_))),
-             integer_check_by_range!(1, 31),
-             b.reg(r#"日?ー"#)?,
-             integer_check_by_range!(1, 31),
-             b.reg(r#"日"#)?,
-             |month, a, _, b, _| {
-                 let start = month.value()
-                     .intersect(&helpers::day_of_month(a.value().value as u32)?)?;
-                 let end = month.value()
-                     .intersect(&helpers::day_of_month(b.value().value as u32)?)?;
-                 start.span_to(&end, true)
-             }
-    );
     b.rule_4("<datetime> - <datetime> (interval)",
              datetime_check!(|datetime: &DatetimeValue| !datetime.latent && excluding_form!(Form::TimeOfDay(_))(datetime)),
              b.reg(r#"から"#)?,
@@ -1986,12 +2084,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"間"#)?,
              |_, a, _| helpers::cycle_nth(Grain::Second, 0)?.span_to(a.value(), false)
     );
-
-    b.rule_2("by the end of <datetime>",
-             datetime_check!(),
-             b.reg(r#"の?(?:終わり|末)までに"#)?,
-             |a, _| helpers::cycle_nth(Grain::Second, 0)?.span_to(a.value(), true)
-    );
     b.rule_1_terminal("until the begining of the evening",
         b.reg(r#"昼間に"#)?,
         |_| helpers::cycle_nth(Grain::Second, 0)?.span_to(&helpers::hour(18, false)?, false)
@@ -2052,21 +2144,29 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, _| Ok(a.value().clone().precision(Approximate))
     );
 
-    b.rule_4("date using emperor years",
-             b.reg(r#"(平成|昭和)"#)?,
-             integer_check!(),
+    b.rule_3("<era> <year>",
+             b.reg(r#"(明治|大正|昭和|平成|令和)"#)?,
+             integer_check_by_range!(1, 99),
              b.reg(r#"年"#)?,
-             datetime_check!(form!(Form::MonthDay(_))),
-             |emperor, integer, _, month_day| {
-            let start_year = match emperor.group(1).as_ref() {
-                "平成" => 1989,
-                "昭和" => 1926,
-                _ => return Err(RuleError::Invalid.into()),
-            };
-
-            let year = integer.value().value + &start_year - 1;
-            month_day.value().intersect(&helpers::year(year as i32)?)
-        }
+             |era, integer, _| japanese_era_year_value(era.group(1).as_ref(), integer.value().value)
+    );
+    b.rule_1_terminal("<era> first year",
+                      b.reg(r#"(明治|大正|昭和|平成|令和)元年"#)?,
+                      |text_match| japanese_era_year_value(text_match.group(1).as_ref(), 1)
+    );
+    b.rule_1_terminal("<era> <year> - abbreviated",
+                      b.reg(r#"([mtshr])(元|\d{1,2})年?"#)?,
+                      |text_match| japanese_era_year_value(
+                          text_match.group(1).as_ref(),
+                          japanese_era_year_number(text_match.group(2).as_ref())?)
+    );
+    b.rule_1_terminal("<era> yy.mm.dd - separator '/' allowed",
+                      b.reg(r#"(明治|大正|昭和|平成|令和|[mtshr])\.?(元|\d{1,2})[\./](0?[1-9]|1[0-2])[\./](3[01]|[12]\d|0?[1-9])"#)?,
+                      |text_match| japanese_era_date(
+                          text_match.group(1).as_ref(),
+                          japanese_era_year_number(text_match.group(2).as_ref())?,
+                          text_match.group(3).parse()?,
+                          text_match.group(4).parse()?)
     );
     Ok(())
 }
@@ -2117,6 +2217,15 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
     );
+    b.rule_2("<number> <unit-of-duration>",
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             unit_of_duration_check!(),
+             |number, uod| match number.value() {
+                 // Integers are handled above
+                 &NumberValue::Float(ref float) => Ok(DurationValue::new(helpers::fractional_period(float.value, uod.value().grain)?)),
+                 _ => Err(RuleError::Invalid.into()),
+             }
+    );
     b.rule_3("<integer> more <unit-of-duration>",
              b.reg(r#"もう|後|あと"#)?,
              integer_check_by_range!(0),
@@ -2398,4 +2507,40 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |datetime, _, integer, _| helpers::cycle_nth_after(Grain::Quarter, integer.value().value - 1, datetime.value())
     );
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn rules_spoken(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("integer (digit sequence)",
+                      b.reg(r#"(?:[〇零一二三四五六七八九]|ゼロ){3,}"#)?,
+                      |text_match| {
+                          let digits = text_match.group(0)
+                              .replace("ゼロ", "〇")
+                              .chars()
+                              .filter_map(spoken_digit)
+                              .collect::<Vec<_>>();
+                          helpers::digit_sequence(&digits)
+                      }
+    );
+    b.rule_2("<number> <filler>",
+             number_check!(),
+             b.reg(r#"(?:えーと|えっと|えー|あのー?)"#)?,
+             |number, _| Ok(number.value().clone())
+    );
+    Ok(())
+}
+
+fn spoken_digit(c: char) -> Option<i64> {
+    match c {
+        '〇' | '零' => Some(0),
+        '一' => Some(1),
+        '二' => Some(2),
+        '三' => Some(3),
+        '四' => Some(4),
+        '五' => Some(5),
+        '六' => Some(6),
+        '七' => Some(7),
+        '八' => Some(8),
+        '九' => Some(9),
+        _ => None,
+    }
+}
@@ -27,6 +27,9 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 
     example!(v, check_float(0.8), "0.8", "+0.8", "0点８", "零点八", "〇点８", "〇.８");
     example!(v, check_float(0.3), "0.3", "+0.3", "0点３", "零点三", "〇点３", "〇.３");
+    example!(v, check_float(2.0 / 3.0), "三分の二", "2/3", "⅔");
+    example!(v, check_float(0.5), "半分", "二分の一", "½");
+    example!(v, check_float(1.75), "一と四分の三", "1¾");
 
     example!(v, check_ordinal(1), "最初", "一番目", "一行目", "一錠目", "一匹目");
     example!(v, check_ordinal(7), "七番目", "七体目", "七問目", "七拍子目", "七種目", "七種類目");
@@ -66,29 +69,30 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(0.0, Some("degree")), "零度", "0度", "零ど", "0ど", "0 °", "0°");
-    example!(v, check_temperature(5.0, Some("degree")), "五度", "5度", "5 °", "+5°","5°");
-    example!(v, check_temperature(6.0, Some("degree")), "六ど", "6ど");
-    example!(v, check_temperature(14.0, Some("degree")), "14度");
-    example!(v, check_temperature(25.0, Some("degree")), "二十五度");
-    example!(v, check_temperature(0.0, Some("celsius")), "摂氏0度", "摂氏零ど", "0°C", "0℃");
-    example!(v, check_temperature(50.0, Some("celsius")), "摂氏50度", "摂氏五十ど");
-    example!(v, check_temperature(7.0, Some("celsius")), "七°C", "七℃", "7°C", "7℃");
-    example!(v, check_temperature(0.0, Some("fahrenheit")), "華氏0度", "華氏零ど", "カ氏0度", "カ氏零ど", "0°F", "零℉");
-    example!(v, check_temperature(5.0, Some("fahrenheit")), "華氏五度", "華氏5ど", "カ氏5ど");
-    example!(v, check_temperature(3.0, Some("fahrenheit")), "三°F", "三℉", "3 °F", "3 ℉");
-    example!(v, check_temperature(0.0, Some("kelvin")), "0ケルビン", "零ケルビン");
-    example!(v, check_temperature(12.0, Some("kelvin")), "12ケルビン", "十二ケルビン");
-    example!(v, check_temperature(25.0, Some("kelvin")), "25°K");
-    example!(v, check_temperature(-10.0, Some("degree")), "マイナス十度", "零下十度", "れいか十ど",  "れいか10ど", "-10°");
-    example!(v, check_temperature(-7.0, Some("celsius")), "マイナス七°C", "零下7℃", "摂氏マイナス七度", "摂氏零下7ど", "摂氏れいか7度");
-    example!(v, check_temperature(-3.0, Some("fahrenheit")), "マイナス3°F", "零下三℉", "華氏マイナス3度", "華氏零下三度", "カ氏れいか三ど");
+    example!(v, check_temperature(0.0, Some(TemperatureUnit::Degree)), "零度", "0度", "零ど", "0ど", "0 °", "0°");
+    example!(v, check_temperature(5.0, Some(TemperatureUnit::Degree)), "五度", "5度", "5 °", "+5°","5°");
+    example!(v, check_temperature(6.0, Some(TemperatureUnit::Degree)), "六ど", "6ど");
+    example!(v, check_temperature(14.0, Some(TemperatureUnit::Degree)), "14度");
+    example!(v, check_temperature(25.0, Some(TemperatureUnit::Degree)), "二十五度");
+    example!(v, check_temperature(0.0, Some(TemperatureUnit::Celsius)), "摂氏0度", "摂氏零ど", "0°C", "0℃");
+    example!(v, check_temperature(50.0, Some(TemperatureUnit::Celsius)), "摂氏50度", "摂氏五十ど");
+    example!(v, check_temperature(7.0, Some(TemperatureUnit::Celsius)), "七°C", "七℃", "7°C", "7℃");
+    example!(v, check_temperature(0.0, Some(TemperatureUnit::Fahrenheit)), "華氏0度", "華氏零ど", "カ氏0度", "カ氏零ど", "0°F", "零℉");
+    example!(v, check_temperature(5.0, Some(TemperatureUnit::Fahrenheit)), "華氏五度", "華氏5ど", "カ氏5ど");
+    example!(v, check_temperature(3.0, Some(TemperatureUnit::Fahrenheit)), "三°F", "三℉", "3 °F", "3 ℉");
+    example!(v, check_temperature(0.0, Some(TemperatureUnit::Kelvin)), "0ケルビン", "零ケルビン");
+    example!(v, check_temperature(12.0, Some(TemperatureUnit::Kelvin)), "12ケルビン", "十二ケルビン");
+    example!(v, check_temperature(25.0, Some(TemperatureUnit::Kelvin)), "25°K");
+    example!(v, check_temperature(-10.0, Some(TemperatureUnit::Degree)), "マイナス十度", "零下十度", "れいか十ど",  "れいか10ど", "-10°");
+    example!(v, check_temperature(-7.0, Some(TemperatureUnit::Celsius)), "マイナス七°C", "零下7℃", "摂氏マイナス七度", "摂氏零下7ど", "摂氏れいか7度");
+    example!(v, check_temperature(-3.0, Some(TemperatureUnit::Fahrenheit)), "マイナス3°F", "零下三℉", "華氏マイナス3度", "華氏零下三度", "カ氏れいか三ど");
 }
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 0, 1]), "一秒間");
     example!(v, check_duration!([0, 0, 0, 0, 0, 1]), "一分間");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "一時間");
+    example!(v, check_duration!([0, 0, 0, 0, 1, 30]), "1.5時間", "1½時間");
     example!(v, check_duration!([0, 0, 0, 5]), "五日間");
     example!(v, check_duration!([0, 10]), "十ヶ月間", "十カ月間");
     example!(v, check_duration!([0, 0, 0, 0, 2], Precision::Approximate), "およそ二時間", "二時間程", "二時間位");
@@ -165,6 +169,17 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 15]), "三日後");
     example!(v, check_moment!(c, [2013, 2, 9]), "三日前");
     example!(v, check_moment!(c, [2018, 1, 23]), "2018.1.23");
+    example!(v, check_moment!(c, [2023]), "令和5年", "令和五年", "R5", "R5年");
+    example!(v, check_moment_span!(c, [2019, 5, 1], [2020, 1, 1]), "令和元年", "R元");
+    example!(v, check_moment_span!(c, [2019, 1, 1], [2019, 5, 1]), "平成31年");
+    example!(v, check_moment_span!(c, [1989, 1, 8], [1990, 1, 1]), "平成元年", "平成1年", "H1");
+    example!(v, check_moment_span!(c, [1989, 1, 1], [1989, 1, 8]), "昭和64年");
+    example!(v, check_moment!(c, [1975]), "昭和五十年", "S50");
+    example!(v, check_moment_span!(c, [1868, 10, 23], [1869, 1, 1]), "明治元年");
+    example!(v, check_moment!(c, [2018, 4, 1]), "平成三十年四月一日", "H30.4.1", "H30/4/1", "平成30.4.1");
+    example!(v, check_moment!(c, [2019, 4, 30]), "平成三十一年四月三十日", "平成31年4月30日", "H31.4.30");
+    example!(v, check_moment!(c, [2019, 5, 1]), "令和元年五月一日", "R1.5.1", "R元.5.1");
+    example!(v, check_moment!(c, [2013, 2, 14]), "平成二十五年二月十四日", "H25.2.14");
     // TODO: date as "2018年1月23日"
 
     example!(v, check_moment_span!(c, [2013, 8, 1], [2013, 8, 11]), "八月の上旬");
@@ -187,4 +202,9 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 3, 1], [2013, 3, 6]), "一日から五日", "一日から五日まで");
     example!(v, check_moment_span!(c, [2013, 2, 28], [2013, 3, 4]), "二十八日から三日", "二十八日から三日まで");
     example!(v, check_moment!(c, [2013, 2, 10, 14, 57]), "一昨日の午後三時三分前");
-}
\ No newline at end of file
+}
+
+pub fn examples_spoken(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_integer(9012345), "九〇一二三四五", "九ゼロ一二三四五");
+    example!(v, check_integer(305), "三百えーと五");
+}
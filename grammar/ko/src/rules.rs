@@ -1,8 +1,9 @@
 use regex::Regex;
 use rustling::{RuleError, RuleResult, RuleSetBuilder, RustlingResult};
-use rustling_ontology_moment::{Grain, PeriodComp, Weekday};
+use rustling_ontology_moment::{Grain, LunarCalendar, PeriodComp, Weekday};
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::Decimal;
 
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2(
@@ -51,6 +52,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<unit> <amount>", money_unit!(), number_check!(), |a, b| {
         Ok(AmountOfMoneyValue {
             value: b.value().value(),
+            decimal: b.value().decimal(),
             unit: a.value().unit,
             ..AmountOfMoneyValue::default()
         })
@@ -58,6 +60,7 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<amount> <unit>", number_check!(), money_unit!(), |a, b| {
         Ok(AmountOfMoneyValue {
             value: a.value().value(),
+            decimal: a.value().decimal(),
             unit: b.value().unit,
             ..AmountOfMoneyValue::default()
         })
@@ -146,7 +149,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
         |a, _| {
             Ok(TemperatureValue {
                 value: a.value().value,
-                unit: Some("degree"),
+                unit: Some(TemperatureUnit::Degree),
                 latent: false,
             })
         },
@@ -158,7 +161,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
         |_, a| {
             Ok(TemperatureValue {
                 value: a.value().value,
-                unit: Some("celsius"),
+                unit: Some(TemperatureUnit::Celsius),
                 latent: false,
             })
         },
@@ -170,7 +173,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
         |a, _| {
             Ok(TemperatureValue {
                 value: a.value().value,
-                unit: Some("celsius"),
+                unit: Some(TemperatureUnit::Celsius),
                 latent: false,
             })
         },
@@ -182,7 +185,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
         |_, a| {
             Ok(TemperatureValue {
                 value: a.value().value,
-                unit: Some("fahrenheit"),
+                unit: Some(TemperatureUnit::Fahrenheit),
                 latent: false,
             })
         },
@@ -194,7 +197,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
         |a, _| {
             Ok(TemperatureValue {
                 value: a.value().value,
-                unit: Some("fahrenheit"),
+                unit: Some(TemperatureUnit::Fahrenheit),
                 latent: false,
             })
         },
@@ -345,25 +348,34 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         helpers::month_day(1, 1)
     });
 
-    // b.rule_1("Korean New Year",
-    //     b.reg(r#"설날|설|원일|구정"#)?,
-    //     based on the lunear calendar which is not supported yet
-    // );
-
-    // b.rule_1("Buddha’s Birthday",
-    //     b.reg(r#"부처님 오신 날|석존성탄절|석가탄신일|석탄일|석탄절"#)?,
-    //     based on the lunear calendar which is not supported yet
-    // );
-
-    // b.rule_1("Chuseok",
-    //     b.reg(r#"추석|한가위"#)?,
-    //     based on the lunear calendar which is not supported yet
-    // );
-
-    // b.rule_1("Chuseok Holidays",
-    //     b.reg(r#"추석연휴"#),
-    //     based on the lunear calendar which is not supported yet
-    // );
+    b.rule_1_terminal("Korean New Year", b.reg(r#"설날|원일|구정"#)?, |_| {
+        helpers::lunar_month_day(LunarCalendar::Korean, 1, 1)
+    });
+    b.rule_1_terminal(
+        "Korean New Year Holidays",
+        b.reg(r#"설 ?연휴|설날 ?연휴"#)?,
+        |_| {
+            let new_year = helpers::lunar_month_day(LunarCalendar::Korean, 1, 1)?;
+            helpers::cycle_nth_after(Grain::Day, -1, &new_year)?
+                .span_to(&helpers::cycle_nth_after(Grain::Day, 1, &new_year)?, true)
+        },
+    );
+    b.rule_1_terminal(
+        "Buddha’s Birthday",
+        b.reg(r#"부처님 ?오신 ?날|석존성탄절|석가탄신일|석탄일|석탄절"#)?,
+        |_| helpers::lunar_month_day(LunarCalendar::Korean, 4, 8),
+    );
+    b.rule_1_terminal("Dano", b.reg(r#"단오(?:절)?"#)?, |_| {
+        helpers::lunar_month_day(LunarCalendar::Korean, 5, 5)
+    });
+    b.rule_1_terminal("Chuseok", b.reg(r#"추석|한가위"#)?, |_| {
+        helpers::lunar_month_day(LunarCalendar::Korean, 8, 15)
+    });
+    b.rule_1_terminal("Chuseok Holidays", b.reg(r#"추석 ?연휴"#)?, |_| {
+        let chuseok = helpers::lunar_month_day(LunarCalendar::Korean, 8, 15)?;
+        helpers::cycle_nth_after(Grain::Day, -1, &chuseok)?
+            .span_to(&helpers::cycle_nth_after(Grain::Day, 1, &chuseok)?, true)
+    });
 
     b.rule_1_terminal("Independence Movement Day", b.reg(r#"삼일절"#)?, |_| {
         helpers::month_day(3, 1)
@@ -1251,6 +1263,18 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             ))
         },
     );
+    b.rule_2(
+        "<number> <unit-of-duration>",
+        number_check!(|number: &NumberValue| !number.prefixed()),
+        unit_of_duration_check!(),
+        |number, uod| match number.value() {
+            // Integers are handled above
+            &NumberValue::Float(ref float) => Ok(DurationValue::new(
+                helpers::fractional_period(float.value, uod.value().grain)?,
+            )),
+            _ => Err(RuleError::Invalid.into()),
+        },
+    );
     b.rule_2_terminal(
         "number.number hours",
         b.reg(r#"(\d+)\.(\d+)"#)?,
@@ -1382,7 +1406,7 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         |_, a| helpers::cycle_nth(a.value().grain, 1),
     );
     b.rule_3(
-        "<datetime> 마지막 <cycle>",
+        "<datetime> 다음 <cycle>",
         datetime_check!(),
         b.reg(r#"다음|오는|차|내"#)?,
         cycle_check!(),
@@ -1476,7 +1500,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("integer 0", b.reg(r#"영|공|빵"#)?, |_| {
         IntegerValue::new(0)
     });
-    b.rule_1_terminal("half - 반", b.reg(r#"반"#)?, |_| FloatValue::new(0.5));
+    b.rule_1_terminal("half - 반", b.reg(r#"반"#)?, |_| helpers::spelled_fraction(1, 2));
     b.rule_1_terminal("few 몇", b.reg(r#"몇"#)?, |_| {
         Ok(IntegerValue {
             value: 3,
@@ -1714,7 +1738,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
 
     b.rule_1_terminal("decimal number", b.reg(r#"(\d*\.\d+)"#)?, |text_match| {
-        FloatValue::new(text_match.group(1).parse()?)
+        FloatValue::from_decimal(text_match.group(1).parse()?)
     });
 
     b.rule_2(
@@ -1745,14 +1769,19 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                     .filter_map(number_mapping)
                     .collect::<String>()
             );
-            FloatValue::new(a.value().value() + number_string.parse::<f64>()?)
+            let decimal_part: Decimal = number_string.parse()?;
+            Ok(FloatValue {
+                value: a.value().value() + decimal_part.to_f64(),
+                decimal: a.value().decimal().and_then(|it| it.checked_add(decimal_part)),
+                ..FloatValue::default()
+            })
         },
     );
 
     b.rule_1_terminal(
         "decimal with thousands separator",
         b.reg(r#"(\d+(,\d\d\d)+\.\d+)"#)?,
-        |text_match| FloatValue::new(text_match.group(1).replace(",", "").parse()?),
+        |text_match| FloatValue::from_decimal(text_match.group(1).replace(",", "").parse()?),
     );
     b.rule_2(
         "numbers prefix with -, 마이너스, or 마이나스",
@@ -1768,6 +1797,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                 .into(),
                 NumberValue::Float(float) => FloatValue {
                     value: float.value * -1.0,
+                    decimal: float.decimal.map(|it| -it),
                     prefixed: true,
                     ..float
                 }
@@ -1801,6 +1831,20 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         b.reg(r#"번째|째|째번"#)?,
         |a, _| Ok(OrdinalValue::new(a.value().value)),
     );
+    b.rule_1_terminal(
+        "fraction (unicode)",
+        b.reg(r#"(\d*)([½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅐⅛⅜⅝⅞⅑⅒])"#)?,
+        |text_match| {
+            let fraction = helpers::vulgar_fraction(text_match.group(2))?;
+            if text_match.group(1).is_empty() {
+                return Ok(fraction);
+            }
+            helpers::mixed_number(
+                &IntegerValue::new(text_match.group(1).parse()?)?,
+                &fraction.into(),
+            )
+        },
+    );
     b.rule_3(
         "fraction",
         number_check!(|number: &NumberValue| !number.prefixed()),
@@ -1817,3 +1861,42 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_spoken(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal(
+        "integer (digit sequence)",
+        b.reg(r#"[공영일이삼사오육칠팔구]{3,}"#)?,
+        |text_match| {
+            let digits = text_match
+                .group(0)
+                .chars()
+                .filter_map(spoken_digit)
+                .collect::<Vec<_>>();
+            helpers::digit_sequence(&digits)
+        },
+    );
+    b.rule_2(
+        "<number> <filler>",
+        number_check!(),
+        b.reg(r#"음+|어+"#)?,
+        |number, _| Ok(number.value().clone()),
+    );
+    Ok(())
+}
+
+fn spoken_digit(c: char) -> Option<i64> {
+    match c {
+        // 공 is used for zero when reading digits, e.g. phone numbers
+        '공' | '영' => Some(0),
+        '일' => Some(1),
+        '이' => Some(2),
+        '삼' => Some(3),
+        '사' => Some(4),
+        '오' => Some(5),
+        '육' => Some(6),
+        '칠' => Some(7),
+        '팔' => Some(8),
+        '구' => Some(9),
+        _ => None,
+    }
+}
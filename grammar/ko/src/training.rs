@@ -17,15 +17,15 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(37.0, Some("celsius")), "+37°C", "37°C", "섭씨37°", "섭씨37도");
-    example!(v, check_temperature(70.0, Some("fahrenheit")), "70°F", "화씨70°", "화씨70도");
-    example!(v, check_temperature(45.0, Some("degree")), "45°", "45도");
-    example!(v, check_temperature(-15.0, Some("degree")), "영하 15도");
-    example!(v, check_temperature(-3.0, Some("degree")), "영하 삼도");
-    example!(v, check_temperature(15.0, Some("degree")), "영상 15도");
-    example!(v, check_temperature(3.0, Some("degree")), "영상 삼도");
-    example!(v, check_temperature(6.0, Some("celsius")), "섭씨 6도");
-    example!(v, check_temperature(32.0, Some("fahrenheit")), "화씨 32도");
+    example!(v, check_temperature(37.0, Some(TemperatureUnit::Celsius)), "+37°C", "37°C", "섭씨37°", "섭씨37도");
+    example!(v, check_temperature(70.0, Some(TemperatureUnit::Fahrenheit)), "70°F", "화씨70°", "화씨70도");
+    example!(v, check_temperature(45.0, Some(TemperatureUnit::Degree)), "45°", "45도");
+    example!(v, check_temperature(-15.0, Some(TemperatureUnit::Degree)), "영하 15도");
+    example!(v, check_temperature(-3.0, Some(TemperatureUnit::Degree)), "영하 삼도");
+    example!(v, check_temperature(15.0, Some(TemperatureUnit::Degree)), "영상 15도");
+    example!(v, check_temperature(3.0, Some(TemperatureUnit::Degree)), "영상 삼도");
+    example!(v, check_temperature(6.0, Some(TemperatureUnit::Celsius)), "섭씨 6도");
+    example!(v, check_temperature(32.0, Some(TemperatureUnit::Fahrenheit)), "화씨 32도");
 }
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -256,6 +256,12 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2015]), "후년", "재명년", "내명년");
 
     // Holidays
+    example!(v, check_moment!(c, [2014, 1, 31]), "설날", "구정");
+    example!(v, check_moment_span!(c, [2014, 1, 30], [2014, 2, 2]), "설 연휴", "설날 연휴");
+    example!(v, check_moment!(c, [2013, 5, 17]), "부처님 오신 날", "석가탄신일");
+    example!(v, check_moment!(c, [2013, 6, 13]), "단오", "단오절");
+    example!(v, check_moment!(c, [2013, 9, 19]), "추석", "한가위");
+    example!(v, check_moment_span!(c, [2013, 9, 18], [2013, 9, 21]), "추석연휴", "추석 연휴");
     example!(v, check_moment!(c, [2013, 6, 16]), "아버지날");
     example!(v, check_moment!(c, [2013, 5, 12]), "어머니날");
     example!(v, check_moment!(c, [2013, 5, 8]), "어버이날");
@@ -310,7 +316,12 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(4123.3), "4123.3", "사천백이십삼점삼");
     example!(v, check_float(1.23), "일점이삼");
     example!(v, check_integer(-3), "-3", "마이너스3", "마이너스삼", "마이너스 3", "마이나스3", "마이나스 3");
-    example!(v, check_float(3.0 / 4.0), "3/4", "사분의삼");
+    example!(v, check_float(3.0 / 4.0), "3/4", "사분의삼", "¾");
+    example!(v, check_float(1.5), "1½");
     example!(v, check_ordinal(25), "스물다섯번째", "이십오번째");
     example!(v, check_ordinal(1), "첫번째", "첫째", "첫번", "첫");
 }
+
+pub fn examples_spoken(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_integer(1012345), "일공일이삼사오", "일영일이삼사오");
+}
@@ -869,6 +869,11 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              datetime_check!(form!(Form::TimeOfDay(_))),
              |_, time| Ok(time.value().clone().mark_after_start())
     );
+    b.rule_2("from <time-of-day>",
+             b.reg(r#"a partir(?: d[oe])?|desde(?: as?)?"#)?,
+             datetime_check!(form!(Form::TimeOfDay(_))),
+             |_, time| Ok(time.value().clone().mark_after_start())
+    );
     b.rule_2("after <date-time>",
              b.reg(r#"(desde|a partir|depois)( desta| das?| de| d?o)"#)?,
              datetime_check!(),
@@ -884,11 +889,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              datetime_check!(),
              |_, time| Ok(time.value().clone().mark_after_start())
     );
-    b.rule_2("from <time-of-day>",
-             b.reg(r#"a partir(?: d[oe])?|desde(?: as?)?"#)?,
-             datetime_check!(form!(Form::TimeOfDay(_))),
-             |_, time| Ok(time.value().clone().mark_after_start())
-    );
     b.rule_3("from <time-of-day> on",
              b.reg(r#"do|de|das"#)?,
              datetime_check!(form!(Form::TimeOfDay(_))),
@@ -1028,13 +1028,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
 
 pub fn rules_datetime_with_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
-    b.rule_2("ago <duration>",
-             b.reg(r#"h[áà]"#)?,
-             duration_check!(),
-             |_, a| {
-                 a.value().ago()
-             }
-    );
     b.rule_2("in <duration>",
              b.reg(r#"dentro de|daqui a"#)?,
              duration_check!(),
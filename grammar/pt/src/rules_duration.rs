@@ -1,5 +1,6 @@
 use rustling::*;
 use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
 use rustling_ontology_moment::{Grain, PeriodComp, Period};
 
 pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
@@ -62,6 +63,21 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
     );
+    b.rule_2("<number> <unit-of-duration>",
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             unit_of_duration_check!(),
+             |number, uod| match number.value() {
+                 // Integers are handled above
+                 &NumberValue::Float(ref float) => Ok(DurationValue::new(helpers::fractional_period(float.value, uod.value().grain)?)),
+                 _ => Err(RuleError::Invalid.into()),
+             }
+    );
+    b.rule_3("<fraction> de <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.is_proper_fraction()),
+             b.reg(r#"de"#)?,
+             unit_of_duration_check!(),
+             |fraction, _, uod| Ok(DurationValue::new(helpers::fractional_period(fraction.value().value(), uod.value().grain)?))
+    );
     b.rule_3("<integer> <unit-of-duration> and a half",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
@@ -121,7 +137,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     // PAST
     b.rule_2("ago <duration>",
-             b.reg(r#"há|faz"#)?,
+             b.reg(r#"h[áà]|faz"#)?,
              duration_check!(),
              |_, duration| duration.value().ago()
     );
@@ -147,3 +163,52 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<duration> old",
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             b.reg(r#"de idade"#)?,
+             |duration, _| helpers::age(duration.value())
+    );
+    b.rule_2("aged <duration>",
+             b.reg(r#"(?:com )?idade de"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| helpers::age(duration.value())
+    );
+    b.rule_4("aged <integer> to <duration>",
+             b.reg(r#"(?:com )?idade de"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"a|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, integer, _, duration| helpers::age_range(integer.value().value, &helpers::age(duration.value())?)
+    );
+    b.rule_4("between <integer> and <age>",
+             b.reg(r#"entre"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"e"#)?,
+             age_check!(|age: &AgeValue| age.is_single()),
+             |_, integer, _, age| helpers::age_range(integer.value().value, age.value())
+    );
+    b.rule_3("<integer> to <age>",
+             integer_check_by_range!(0),
+             b.reg(r#"a|-"#)?,
+             age_check!(|age: &AgeValue| age.is_single()),
+             |integer, _, age| helpers::age_range(integer.value().value, age.value())
+    );
+    b.rule_2("I have <duration>",
+             b.reg(r#"tenho|tens|temos|tem|t[êe]m"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| helpers::age_in_years_duration(duration.value())
+    );
+    b.rule_2("older than <duration>",
+             b.reg(r#"maior(?:es)? de"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| Ok(helpers::age(duration.value())?.at_least())
+    );
+    b.rule_2("younger than <duration>",
+             b.reg(r#"menor(?:es)? de"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, duration| Ok(helpers::age(duration.value())?.at_most())
+    );
+    Ok(())
+}
@@ -3,6 +3,35 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
 
+// Words and abbreviations which are valid roman numerals, e.g. "vi" and "li" for "I saw" and "I
+// read", or units like "cm"
+const ROMAN_NUMERAL_STOP_WORDS: [&str; 11] = [
+    "cc", "cd", "cl", "cm", "cv", "dl", "li", "ml", "mm", "vi", "xl",
+];
+
+// Denominators are singular after "um" and plural otherwise
+fn fraction_denominator(word: &str, numerator: i64) -> RuleResult<i64> {
+    let (stem, plural) = match word {
+        word if word.ends_with('s') => (&word[..word.len() - 1], true),
+        word => (word, false),
+    };
+    if plural == (numerator == 1) {
+        return Err(RuleError::Invalid.into());
+    }
+    match stem {
+        "meio" => Ok(2),
+        "terço" | "terco" => Ok(3),
+        "quarto" => Ok(4),
+        "quinto" => Ok(5),
+        "sexto" => Ok(6),
+        "sétimo" | "setimo" => Ok(7),
+        "oitavo" => Ok(8),
+        "nono" => Ok(9),
+        "décimo" | "decimo" => Ok(10),
+        _ => Err(RuleError::Invalid.into()),
+    }
+}
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     b.rule_2("intersect numbers",
@@ -192,6 +221,87 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 //                 |x, _, y| IntegerValue::new_with_grain(x.value().value + y.value().value,2)
 //    );
 
+    // Only decimals, integers are composed with milhões and bilhões above
+    b.rule_2("<decimal> million|billion",
+             number_check!(|number: &NumberValue| !number.prefixed() && !number.suffixed()
+                 && if let &NumberValue::Float(_) = number { true } else { false }),
+             b.reg(r#"(milh|bilh)(?:ão|ões)"#)?,
+             |number, text_match| {
+                 let multiplier = match text_match.group(1) {
+                     "milh" => IntegerValue::new_with_grain(1000000, 6)?,
+                     _ => IntegerValue::new_with_grain(1000000000, 9)?,
+                 };
+                 helpers::multiply_number(number.value(), &multiplier)
+             }
+    );
+
+    b.rule_1_terminal("dozen",
+                      b.reg(r#"dúzias?"#)?,
+                      |_| Ok(IntegerValue {
+                          value: 12,
+                          grain: Some(1),
+                          group: true,
+                          ..IntegerValue::default()
+                      })
+    );
+    b.rule_2("<number> <multiplier>",
+             number_check!(|number: &NumberValue| !number.prefixed() && !number.suffixed()),
+             integer_check!(|integer: &IntegerValue| integer.is_multiplier()),
+             |number, multiplier| {
+                 // Integers followed by cem, mil... are already composed above
+                 if let &NumberValue::Integer(_) = number.value() {
+                     if !multiplier.value().group {
+                         return Err(RuleError::Invalid.into());
+                     }
+                 }
+                 helpers::multiply_number(number.value(), multiplier.value())
+             }
+    );
+    b.rule_1_terminal("integer (roman numerals)",
+                      b.reg(r#"([mdclxvi]{2,})"#)?,
+                      |text_match| IntegerValue::new(helpers::roman_numeral(text_match.group(1), &ROMAN_NUMERAL_STOP_WORDS)?)
+    );
+    b.rule_1_terminal("fraction (unicode)",
+                      b.reg(r#"(\d*)([½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅐⅛⅜⅝⅞⅑⅒])"#)?,
+                      |text_match| {
+                          let fraction = helpers::vulgar_fraction(text_match.group(2))?;
+                          if text_match.group(1).is_empty() {
+                              return Ok(fraction);
+                          }
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
+    );
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d+)/(\d+)"#)?,
+                      |text_match| helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("mixed number (numeric)",
+                      b.reg(r#"(\d+) (\d+)/(\d+)"#)?,
+                      |text_match| {
+                          let fraction = helpers::fraction(text_match.group(2).parse()?, text_match.group(3).parse()?)?;
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
+    );
+    b.rule_2("<integer> <fraction denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(meios?|ter[çc]os?|quartos?|quintos?|sextos?|s[ée]timos?|oitavos?|nonos?|d[ée]cimos?)"#)?,
+             |integer, text_match| {
+                 let denominator = fraction_denominator(text_match.group(1), integer.value().value)?;
+                 helpers::spelled_fraction(integer.value().value, denominator)
+             }
+    );
+    b.rule_2("<integer> e meio",
+             integer_check_by_range!(0),
+             b.reg(r#"e mei[oa]"#)?,
+             |integer, _| helpers::mixed_number(integer.value(), &helpers::fraction(1, 2)?.into())
+    );
+    b.rule_3("<integer> e <fraction>",
+             integer_check_by_range!(0),
+             b.reg(r#"e"#)?,
+             number_check!(|number: &NumberValue| number.is_proper_fraction()),
+             |integer, _, fraction| helpers::mixed_number(integer.value(), fraction.value())
+    );
+
     b.rule_1_terminal("some",
                       b.reg(r#"algumas|alguns"#)?,
                       |_| IntegerValue::new_with_grain(3, 1)
@@ -214,19 +324,14 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"(\d*,\d+)"#)?,
                       |text_match| {
                           let reformatted_string = text_match.group(1).replace(",", ".");
-                          let value: f64 = reformatted_string.parse()?;
-                          FloatValue::new(value)
+                          FloatValue::from_decimal(reformatted_string.parse()?)
                       });
     b.rule_3("number dot number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
              b.reg(r#"v[íi]rgula"#)?,
              integer_check!(|integer: &IntegerValue| !integer.suffixed),
              |a, _, b| {
-                 let value: f64 = format!("{}.{}", a.value().value, b.value().value).parse()?;
-                 Ok(FloatValue {
-                     value,
-                     ..FloatValue::default()
-                 })
+                 FloatValue::from_decimal(format!("{}.{}", a.value().value, b.value().value).parse()?)
              });
     b.rule_4("number dot zero ... number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
@@ -235,18 +340,13 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check!(|integer: &IntegerValue| !integer.suffixed),
              |a, _, zeros, b| {
                  let zeros_string =  std::iter::repeat("0").take(zeros.group(0).split_whitespace().count()).collect::<String>();
-                 let value: f64 = format!("{}.{}{}", a.value().value, zeros_string, b.value().value).parse()?;
-                 Ok(FloatValue {
-                     value,
-                     ..FloatValue::default()
-                 })
+                 FloatValue::from_decimal(format!("{}.{}{}", a.value().value, zeros_string, b.value().value).parse()?)
              });
     b.rule_1_terminal("decimal with thousands separator",
                       b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
                       |text_match| {
                           let reformatted_string = text_match.group(1).replace(".", "").replace(",", ".");
-                          let value: f64 = reformatted_string.parse()?;
-                          FloatValue::new(value)
+                          FloatValue::from_decimal(reformatted_string.parse()?)
                       });
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|menos"#)?,
@@ -265,6 +365,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      NumberValue::Float(float) => {
                          FloatValue {
                              value: float.value * -1.0,
+                             decimal: float.decimal.map(|it| -it),
                              prefixed: true,
                              ..float
                          }
@@ -326,6 +427,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                          } else {
                              FloatValue {
                                  value: product,
+                                 decimal: float.decimal.and_then(|it| it.checked_mul(multiplier.into())),
                                  suffixed: true,
                                  ..float
                              }
@@ -506,3 +608,24 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_digit_sequence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("digit sequence",
+                      b.reg(r#"\d+(?:[ .\-]\d+)*"#)?,
+                      |text_match| helpers::digit_sequence_value(text_match.group(0))
+    );
+    b.rule_1_terminal("phone number (international with country code)",
+                      b.reg(r#"(?:\+|00)(\d{1,3})(?: ?\(0\) ?|[ .\-])(\d+(?:[ .\-]\d+)*)"#)?,
+                      |text_match| helpers::international_phone_number(Some(text_match.group(1)), text_match.group(2))
+    );
+    b.rule_1_terminal("phone number (international)",
+                      b.reg(r#"\+(\d+)"#)?,
+                      |text_match| helpers::international_phone_number(None, text_match.group(1))
+    );
+    b.rule_2("flight <code>",
+             b.reg(r#"(?:o )?voo(?: n[uú]mero)?"#)?,
+             b.reg(r#"([a-z][a-z0-9]|[0-9][a-z]) ?(\d{1,4})"#)?,
+             |_, code| Ok(helpers::digit_code(code.group(2))?.prefix(code.group(1)))
+    );
+    Ok(())
+}
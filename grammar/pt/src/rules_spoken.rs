@@ -0,0 +1,53 @@
+use rustling::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
+
+pub fn rules_spoken(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("integer (digit sequence)",
+                      b.reg(r#"(?:zero|um|dois|duas|tr[eê]s|quatro|cinco|seis|meia|sete|oito|nove)(?:,?[ -]+(?:(?:hum+|ahn+|[ée]h+),?[ -]+)?(?:zero|um|dois|duas|tr[eê]s|quatro|cinco|seis|meia|sete|oito|nove)){2,}"#)?,
+                      |text_match| {
+                          let digits = text_match.group(0)
+                              .split(|c: char| c == ' ' || c == '-' || c == ',')
+                              .filter_map(spoken_digit)
+                              .collect::<Vec<_>>();
+                          helpers::digit_sequence(&digits)
+                      }
+    );
+    b.rule_2("<number> <filler>",
+             number_check!(),
+             b.reg(r#"(?:hum+|ahn+|[ée]h+)"#)?,
+             |number, _| Ok(number.value().clone())
+    );
+    // Years are read as a whole number ("mil novecentos e noventa e nove")
+    b.rule_3("military time (zero <hour> horas)",
+             b.reg(r#"zero"#)?,
+             integer_check_by_range!(0, 9),
+             b.reg(r#"horas?"#)?,
+             |_, hour, _| helpers::hour(hour.value().value as u32, false)
+    );
+    b.rule_3("military time (zero <hour> <minutes>)",
+             b.reg(r#"zero"#)?,
+             integer_check_by_range!(0, 9),
+             integer_check_by_range!(10, 59),
+             |_, hour, minute| helpers::hour_minute(hour.value().value as u32, minute.value().value as u32, false)
+    );
+    Ok(())
+}
+
+fn spoken_digit(word: &str) -> Option<i64> {
+    match word {
+        "zero" => Some(0),
+        "um" => Some(1),
+        "dois" | "duas" => Some(2),
+        "três" | "tres" => Some(3),
+        "quatro" => Some(4),
+        "cinco" => Some(5),
+        // "meia" (dúzia) is commonly used for six when reading digits
+        "seis" | "meia" => Some(6),
+        "sete" => Some(7),
+        "oito" => Some(8),
+        "nove" => Some(9),
+        // Filler words
+        _ => None,
+    }
+}
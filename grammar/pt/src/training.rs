@@ -34,6 +34,16 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(0.03), "0,03", "zero vírgula zero três");
     example!(v, check_float(32.75), "32,75", "trinta e dois vírgula setenta e cinco");
     example!(v, check_float(10.08), "10,08", "dez vírgula zero oito");
+    example!(v, check_integer(14), "xiv");
+    example!(v, check_integer(21), "xxi");
+    example!(v, check_integer(5000000), "5 milhões", "cinco milhões");
+    example!(v, check_integer(2500000), "2,5 milhões");
+    example!(v, check_integer(36), "3 dúzias", "três dúzias");
+    example!(v, check_float(0.75), "três quartos", "3/4", "¾");
+    example!(v, check_float(2.0 / 3.0), "dois terços", "⅔");
+    example!(v, check_float(0.5), "um meio", "½");
+    example!(v, check_float(2.5), "dois e meio", "duas e meia", "2½", "2 1/2");
+    example!(v, check_float(1.25), "um e um quarto");
     example!(v, check_ordinal(1), "1o", "1a", "primeiro", "primeira", "1º", "1ª");
     example!(v, check_ordinal(3), "3o", "3a", "3º", "3ª", "terceiro", "terceira");
     example!(v, check_ordinal(2), "segundo", "2º", "2o");
@@ -47,13 +57,14 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(3.0, Some("degree")), "três graus", "+3 graus", "3 graus");
-    example!(v, check_temperature(32.0, Some("celsius")), "trinta e dois graus celsius", "trinta e dois graus centígrados", "32°C", "32°c");
-    example!(v, check_temperature(-27.0, Some("degree")), "menos 27 graus", "27 graus abaixo de zero","menos vinte e sete graus");
-    example!(v, check_temperature(-27.0, Some("celsius")), "menos 27 graus celsius", "menos 27 graus centígrados", "-27°C", "-27°c");
-    example!(v, check_temperature(-5.0, Some("fahrenheit")), "menos cinco graus fahrenheit", "cinco graus fahrenheit abaixo de zero", "-5°F", "-5°f");
-    example!(v, check_temperature(168.0, Some("fahrenheit")), "cento e sessenta e oito fahrenheit", "cento e sessenta e oito graus fahrenheit", "168 graus fahrenheit", "168°F", "168°f");
-    example!(v, check_temperature(10.0, Some("kelvin")), "dez kelvin", "10K", "10k", "dez graus kelvin");
+    example!(v, check_temperature(3.0, Some(TemperatureUnit::Degree)), "três graus", "+3 graus", "3 graus");
+    example!(v, check_temperature(32.0, Some(TemperatureUnit::Celsius)), "trinta e dois graus celsius", "trinta e dois graus centígrados", "32°C", "32°c");
+    example!(v, check_temperature(-27.0, Some(TemperatureUnit::Degree)), "menos 27 graus", "27 graus abaixo de zero","menos vinte e sete graus");
+    example!(v, check_temperature(-27.0, Some(TemperatureUnit::Celsius)), "menos 27 graus celsius", "menos 27 graus centígrados", "-27°C", "-27°c");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Fahrenheit)), "menos cinco graus fahrenheit", "cinco graus fahrenheit abaixo de zero", "-5°F", "-5°f");
+    example!(v, check_temperature(168.0, Some(TemperatureUnit::Fahrenheit)), "cento e sessenta e oito fahrenheit", "cento e sessenta e oito graus fahrenheit", "168 graus fahrenheit", "168°F", "168°f");
+    example!(v, check_temperature(10.0, Some(TemperatureUnit::Kelvin)), "dez kelvin", "10K", "10k", "dez graus kelvin");
+    example!(v, check_temperature(36.5, Some(TemperatureUnit::Degree)), "trinta e seis e meio graus", "36½ graus");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -84,6 +95,7 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(3.0, Some("฿"), Precision::Exact), "3฿", "3BTC", "3XBT", "três bitcoins");
     example!(v, check_finance(15.0, Some("$"), Precision::Approximate), "uns quinze dólares");
     example!(v, check_finance(3000000.0, Some("EUR"), Precision::Exact), "três milhões de euros");
+    example!(v, check_finance(2.5, Some("EUR"), Precision::Exact), "2½€", "2 1/2 euros", "dois e meio euros");
 }
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -346,4 +358,26 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "durante um quarto de hora mais o menos", "aproximadamente durante um quarto de hora");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "durante uma hora", "por uma hora");
     example!(v, check_duration!([0, 0, 2]), "durante 2 semanas", "por duas semanas");
+    example!(v, check_duration!([0, 0, 0, 0, 2, 45]), "durante 2¾ horas", "por 2,75 horas");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 40]), "durante dois terços de hora");
+}
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age!([34]), "34 anos de idade", "tenho 34 anos");
+    example!(v, check_age!([18], [25]), "entre 18 e 25 anos de idade");
+    example!(v, check_age(Some(period!(18)), None), "maiores de 18 anos");
+}
+
+pub fn examples_digit_sequence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("351912345678"), "+351 912 345 678");
+    example!(v, check_digit_sequence("4815"), "4 8 1 5");
+    example!(v, check_digit_sequence("1234"), "voo TP 1234");
+}
+
+pub fn examples_spoken(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_integer(9661234), "nove seis seis um dois três quatro", "nove meia meia um dois três quatro", "nove seis seis, hum, um dois três quatro");
+    example!(v, check_integer(100000), "cem hum mil");
+    example!(v, check_moment!(c, [2013, 2, 12, 7]), "zero sete horas", "às zero sete horas");
+    example!(v, check_moment!(c, [2013, 2, 12, 9, 15]), "zero nove quinze");
 }
@@ -51,6 +51,48 @@ macro_rules! lang_enum {
 
 lang_enum!([DE, EN, ES, FR, PT, JA, KO, ZH, IT]);
 
+/// Kind of text the rules are meant for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InputMode {
+    /// Regular written text.
+    Written,
+    /// Raw speech recognition (ASR) transcripts, adding rules for numbers read digit by digit,
+    /// filler words between number tokens and spoken forms of years and times.
+    Spoken,
+}
+
+impl InputMode {
+    pub fn all() -> Vec<InputMode> {
+        vec![InputMode::Written, InputMode::Spoken]
+    }
+}
+
+impl Default for InputMode {
+    fn default() -> InputMode {
+        InputMode::Written
+    }
+}
+
+impl std::str::FromStr for InputMode {
+    type Err = String;
+    fn from_str(it: &str) -> result::Result<InputMode, Self::Err> {
+        match &*it.to_lowercase() {
+            "written" => Ok(InputMode::Written),
+            "spoken" | "asr" => Ok(InputMode::Spoken),
+            _ => Err(format!("Unknown input mode {}", it)),
+        }
+    }
+}
+
+impl ::std::string::ToString for InputMode {
+    fn to_string(&self) -> String {
+        match self {
+            &InputMode::Written => "written".to_string(),
+            &InputMode::Spoken => "spoken".to_string(),
+        }
+    }
+}
+
 /// Obtain rules for a given language.
 pub fn rules(lang: Lang) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
     match lang {
@@ -95,3 +137,53 @@ pub fn examples(lang: Lang) -> Vec<::rustling::train::Example<rustling_ontology_
         Lang::IT => it::examples(),
     }
 }
+
+/// Obtain rules for a given language and input mode.
+pub fn rules_with_mode(lang: Lang, mode: InputMode) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    if mode == InputMode::Written {
+        return rules(lang);
+    }
+    match lang {
+        Lang::DE => de::spoken_rule_set(),
+        Lang::EN => en::spoken_rule_set(),
+        Lang::ES => es::spoken_rule_set(),
+        Lang::FR => fr::spoken_rule_set(),
+        Lang::PT => pt::spoken_rule_set(),
+        Lang::JA => ja::spoken_rule_set(),
+        Lang::KO => ko::spoken_rule_set(),
+        Lang::ZH => zh::spoken_rule_set(),
+        Lang::IT => it::spoken_rule_set(),
+    }
+}
+
+/// Obtain the names of the rules of a rule set, in the order they are declared. Rules declared
+/// several times under the same name share it.
+pub fn rule_names(rules: &::rustling::RuleSet<rustling_ontology_values::Dimension>) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+    for sym in rules.all_syms() {
+        if let Some(name) = rules.resolve_sym(&sym) {
+            if !names.iter().any(|it| it == name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names
+}
+
+/// Obtain examples for a given language and input mode.
+pub fn examples_with_mode(lang: Lang, mode: InputMode) -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
+    if mode == InputMode::Written {
+        return examples(lang);
+    }
+    match lang {
+        Lang::DE => de::spoken_examples(),
+        Lang::EN => en::spoken_examples(),
+        Lang::ES => es::spoken_examples(),
+        Lang::FR => fr::spoken_examples(),
+        Lang::PT => pt::spoken_examples(),
+        Lang::JA => ja::spoken_examples(),
+        Lang::KO => ko::spoken_examples(),
+        Lang::ZH => zh::spoken_examples(),
+        Lang::IT => it::spoken_examples(),
+    }
+}
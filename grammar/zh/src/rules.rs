@@ -1,7 +1,7 @@
 use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp};
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, LunarCalendar};
 
 
 pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
@@ -46,6 +46,16 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
     );
 
+    b.rule_2("<number> <unit-of-duration>",
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             unit_of_duration_check!(),
+             |number, uod| match number.value() {
+                 // Integers are handled above
+                 &NumberValue::Float(ref float) => Ok(DurationValue::new(helpers::fractional_period(float.value, uod.value().grain)?)),
+                 _ => Err(RuleError::Invalid.into()),
+             }
+    );
+
     Ok(())
 }
 
@@ -320,7 +330,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       }
     );
 
-    b.rule_1_terminal("army's day",
+    b.rule_1_terminal("children's day",
                       b.reg(r#"(?:儿|兒)童(?:节|節)"#)?,
                       |_| helpers::month_day(6, 1)
     );
@@ -430,6 +440,15 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              }
     );
 
+    b.rule_3("nth <datetime> of <datetime>",
+             datetime_check!(),
+             ordinal_check!(),
+             datetime_check!(),
+             |a, ordinal, b| {
+                 b.value().intersect(a.value())?.the_nth(ordinal.value().value - 1)
+             }
+    );
+
     b.rule_2("last <datetime>",
              b.reg(r#"去|上(?:个|個)?"#)?,
              datetime_check!(),
@@ -450,6 +469,46 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       |_| helpers::month_day(10, 1)
     );
 
+    b.rule_1_terminal("chinese new year",
+                      b.reg(r#"春(?:节|節)|(?:农|農)(?:历|曆)新年|大年初一"#)?,
+                      |_| helpers::lunar_month_day(LunarCalendar::Chinese, 1, 1)
+    );
+
+    b.rule_1_terminal("chinese new year's eve",
+                      b.reg(r#"除夕|大年夜|年三十"#)?,
+                      |_| helpers::cycle_nth_after(Grain::Day, -1, &helpers::lunar_month_day(LunarCalendar::Chinese, 1, 1)?)
+    );
+
+    b.rule_1_terminal("lantern festival",
+                      b.reg(r#"元宵(?:节|節)?"#)?,
+                      |_| helpers::lunar_month_day(LunarCalendar::Chinese, 1, 15)
+    );
+
+    b.rule_1_terminal("dragon boat festival",
+                      b.reg(r#"端午(?:节|節)?"#)?,
+                      |_| helpers::lunar_month_day(LunarCalendar::Chinese, 5, 5)
+    );
+
+    b.rule_1_terminal("qixi festival",
+                      b.reg(r#"七夕(?:节|節)?"#)?,
+                      |_| helpers::lunar_month_day(LunarCalendar::Chinese, 7, 7)
+    );
+
+    b.rule_1_terminal("mid-autumn festival",
+                      b.reg(r#"中秋(?:节|節)?"#)?,
+                      |_| helpers::lunar_month_day(LunarCalendar::Chinese, 8, 15)
+    );
+
+    b.rule_1_terminal("double ninth festival",
+                      b.reg(r#"重(?:阳|陽)(?:节|節)?"#)?,
+                      |_| helpers::lunar_month_day(LunarCalendar::Chinese, 9, 9)
+    );
+
+    b.rule_1_terminal("laba festival",
+                      b.reg(r#"(?:腊|臘)八(?:节|節)?"#)?,
+                      |_| helpers::lunar_month_day(LunarCalendar::Chinese, 12, 8)
+    );
+
     b.rule_4("the <cycle> after <datetime>",
              b.reg(r#"那"#)?,
              cycle_check!(),
@@ -572,15 +631,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| a.value().intersect(b.value())
     );
 
-    b.rule_3("nth <datetime> of <datetime>",
-             datetime_check!(),
-             ordinal_check!(),
-             datetime_check!(),
-             |a, ordinal, b| {
-                 b.value().intersect(a.value())?.the_nth(ordinal.value().value - 1)
-             }
-    );
-
     b.rule_2("<datetime> <part-of-day>",
              datetime_check!(),
              datetime_check!(|datetime: &DatetimeValue| form!(Form::PartOfDay(_))(datetime) || form!(Form::Meal)(datetime)),
@@ -645,14 +695,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              }
     );
 
-    b.rule_2("last <datetime>",
-             b.reg(r#"上"#)?,
-             datetime_check!(),
-             |_, a| {
-                 a.value().the_nth(-1)
-             }
-    );
-
     b.rule_2("<part-of-day> <datetime>",
              datetime_check!(|datetime: &DatetimeValue| form!(Form::PartOfDay(_))(datetime) || form!(Form::Meal)(datetime)),
              datetime_check!(),
@@ -692,18 +734,18 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("degree"),
+                     unit: Some(TemperatureUnit::Degree),
                      latent: false,
                  })
              });
 
     b.rule_2("<temp> Celcius",
              temperature_check!(),
-             b.reg(r#"(?:摄|攝)氏(?:°|度)|°c"#)?,
+             b.reg(r#"(?:摄|攝)氏(?:°|度)|°c|℃"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
                  })
              });
@@ -715,7 +757,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |_, b, _| {
                  Ok(TemperatureValue {
                      value: b.value().value,
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
                  })
              }
@@ -723,11 +765,11 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
 
     b.rule_2("<temp> Fahrenheit",
              temperature_check!(),
-             b.reg(r#"(?:华|華)氏(?:°|度)|°f"#)?,
+             b.reg(r#"(?:华|華)氏(?:°|度)|°f|℉"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
                  })
              });
@@ -739,12 +781,23 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |_, b, _| {
                  Ok(TemperatureValue {
                      value: b.value().value,
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
                  })
              }
     );
 
+    b.rule_2("below zero <temp>",
+             b.reg(r#"零下"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent && temp.value >= 0.0),
+             |_, a| {
+                 Ok(TemperatureValue {
+                     value: -1.0 * a.value().value,
+                     latent: false,
+                     ..*a.value()
+                 })
+             });
+
     Ok(())
 }
 
@@ -779,13 +832,50 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         |text_match| IntegerValue::new(text_match.group(0).parse()?));
 
     b.rule_1("decimal number", b.reg(r#"(\d*\.\d+)"#)?, |text_match| {
-        let value: f64 = text_match.group(0).parse()?;
-        Ok(FloatValue {
-            value: value,
-            ..FloatValue::default()
-        })
+        FloatValue::from_decimal(text_match.group(0).parse()?)
     });
 
+    b.rule_1_terminal("fraction (unicode)",
+                      b.reg(r#"(\d*)([½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅐⅛⅜⅝⅞⅑⅒])"#)?,
+                      |text_match| {
+                          let fraction = helpers::vulgar_fraction(text_match.group(2))?;
+                          if text_match.group(1).is_empty() {
+                              return Ok(fraction);
+                          }
+                          helpers::mixed_number(&IntegerValue::new(text_match.group(1).parse()?)?, &fraction.into())
+                      }
+    );
+
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d+)/(\d+)"#)?,
+                      |text_match| helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+
+    b.rule_3("<integer>分之<integer>",
+             integer_check_by_range!(2),
+             b.reg(r#"分之"#)?,
+             integer_check_by_range!(0),
+             |denominator, _, numerator| helpers::spelled_fraction(numerator.value().value, denominator.value().value)
+    );
+
+    b.rule_1_terminal("half - 一半",
+                      b.reg(r#"一半"#)?,
+                      |_| helpers::spelled_fraction(1, 2)
+    );
+
+    b.rule_3("<integer>又<fraction>",
+             integer_check_by_range!(0),
+             b.reg(r#"又"#)?,
+             number_check!(|number: &NumberValue| number.is_proper_fraction()),
+             |integer, _, fraction| helpers::mixed_number(integer.value(), fraction.value())
+    );
+
+    b.rule_2("<integer>个半",
+             integer_check_by_range!(0),
+             b.reg(r#"(?:个|個)?半"#)?,
+             |integer, _| helpers::mixed_number(integer.value(), &helpers::fraction(1, 2)?.into())
+    );
+
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|负\s?|負\s?"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -803,6 +893,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      NumberValue::Float(float) => {
                          FloatValue {
                              value: float.value * -1.0,
+                             decimal: float.decimal.map(|it| -it),
                              prefixed: true,
                              ..float
                          }
@@ -837,11 +928,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"(\d+(,\d\d\d)+\.\d+)"#)?,
                       |text_match| {
                           let reformatted_string = text_match.group(1).replace(",", "");
-                          let value: f64 = reformatted_string.parse()?;
-                          Ok(FloatValue {
-                              value: value,
-                              ..FloatValue::default()
-                          })
+                          FloatValue::from_decimal(reformatted_string.parse()?)
                       });
 
     b.rule_2("<number>个",
@@ -891,6 +978,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                          } else {
                              FloatValue {
                                  value: product,
+                                 decimal: float.decimal.and_then(|it| it.checked_mul(multiplier.into())),
                                  suffixed: true,
                                  ..float
                              }
@@ -931,3 +1019,41 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     Ok(())
 }
+
+pub fn rules_spoken(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("integer (digit sequence)",
+                      b.reg(r#"[〇零幺一二两三四五六七八九]{3,}"#)?,
+                      |text_match| {
+                          let digits = text_match.group(0)
+                              .chars()
+                              .filter_map(spoken_digit)
+                              .collect::<Vec<_>>();
+                          helpers::digit_sequence(&digits)
+                      }
+    );
+
+    b.rule_2("<number> <filler>",
+             number_check!(),
+             b.reg(r#"(?:嗯|呃|那个|那個)"#)?,
+             |number, _| Ok(number.value().clone())
+    );
+
+    Ok(())
+}
+
+fn spoken_digit(c: char) -> Option<i64> {
+    match c {
+        '〇' | '零' => Some(0),
+        // 幺 is used for one when reading digits, e.g. phone numbers
+        '一' | '幺' => Some(1),
+        '二' | '两' => Some(2),
+        '三' => Some(3),
+        '四' => Some(4),
+        '五' => Some(5),
+        '六' => Some(6),
+        '七' => Some(7),
+        '八' => Some(8),
+        '九' => Some(9),
+        _ => None,
+    }
+}
@@ -9,6 +9,7 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 1]), "1 小時", "一 小時");
     example!(v, check_duration!([0, 0, 0, 5]), "5 天", "五 天", "五 日");
     example!(v, check_duration!([0, 10]), "10 月", "十 月");
+    example!(v, check_duration!([0, 0, 0, 0, 2, 30]), "两个半小时", "2.5 小时");
 }
 
 
@@ -95,16 +96,25 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 6, 1]), "儿童节", "兒童節");
     example!(v, check_moment!(c, [2013, 10, 1]), "国庆", "國慶", "国庆节", "國慶節");
     example!(v, check_moment!(c, [2013, 12, 25]), "圣诞", "聖誕", "圣诞节", "聖誕節");
+    example!(v, check_moment!(c, [2014, 1, 31]), "春节", "春節", "农历新年", "農曆新年", "大年初一");
+    example!(v, check_moment!(c, [2014, 1, 30]), "除夕", "大年夜", "年三十");
+    example!(v, check_moment!(c, [2013, 2, 24]), "元宵", "元宵节", "元宵節");
+    example!(v, check_moment!(c, [2013, 6, 12]), "端午", "端午节", "端午節");
+    example!(v, check_moment!(c, [2013, 8, 13]), "七夕", "七夕节", "七夕節");
+    example!(v, check_moment!(c, [2013, 9, 19]), "中秋", "中秋节", "中秋節");
+    example!(v, check_moment!(c, [2013, 10, 13]), "重阳节", "重陽節");
     example!(v, check_moment_span!(c, [2013, 10, 1, 18], [2013, 10, 2, 0]), "国庆节晚上", "國慶節晚上");
     example!(v, check_moment!(c, [2013, 6, 1, 15, 15]), "儿童节下午三点十五", "兒童節下午三點十五");
 }
 
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(45.0, Some("degree")), "45°", "+45°", "45度");
-    example!(v, check_temperature(45.0, Some("degree")), "45°", "45度");
-    example!(v, check_temperature(50.0, Some("fahrenheit")), "50°F");
-    example!(v, check_temperature(23.0, Some("celsius")), "23°C");
+    example!(v, check_temperature(45.0, Some(TemperatureUnit::Degree)), "45°", "+45°", "45度");
+    example!(v, check_temperature(45.0, Some(TemperatureUnit::Degree)), "45°", "45度");
+    example!(v, check_temperature(50.0, Some(TemperatureUnit::Fahrenheit)), "50°F");
+    example!(v, check_temperature(23.0, Some(TemperatureUnit::Celsius)), "23°C", "23℃");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Degree)), "零下5度", "负5度", "-5°");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Celsius)), "零下摄氏5度", "-5°C");
 }
 
 
@@ -122,6 +132,9 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(10), "10", "十");
     example!(v, check_float(1.1), "1.1", "1.10", "01.10");
     example!(v, check_float(0.77), "0.77", ".77");
+    example!(v, check_float(2.0 / 3.0), "三分之二", "2/3", "⅔");
+    example!(v, check_float(0.5), "一半", "二分之一", "½");
+    example!(v, check_float(2.5), "两个半", "二又二分之一", "2½");
     example!(v, check_integer(20), "20", "二十");
     example!(v, check_integer(30), "30", "三十");
     example!(v, check_integer(40), "40", "四十");
@@ -149,3 +162,8 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(11), "第十一");
     example!(v, check_ordinal(91), "第九十一");
 }
+
+pub fn examples_spoken(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_integer(1381234), "幺三八幺二三四", "一三八一二三四");
+    example!(v, check_integer(25), "二十嗯五");
+}
@@ -3,22 +3,65 @@ extern crate rustling_ontology_moment as moment;
 extern crate serde;
 extern crate serde_json;
 
-use moment::{Local, Moment};
+use moment::{Local, Moment, TimeZone};
 use rustling_ontology::{dimension, output::DatetimeIntervalKind, Output};
 use serde::{Serialize, Deserialize};
-use std::f64;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::{env, f64, fs, io};
+
+/// Reference time of the utterance files, for the utterances not carrying their own.
+pub fn default_reference() -> Moment<Local> {
+    Moment(Local.ymd(2017, 6, 1).and_hms(5, 0, 0))
+}
+
+/// Reference time to resolve an utterance with: its own context, unless it is the default
+/// reference which older utterance files carry in every utterance, in which case `reference`
+/// applies.
+pub fn utterance_reference(context: Option<Moment<Local>>, reference: Moment<Local>) -> Moment<Local> {
+    context.filter(|it| *it != default_reference()).unwrap_or(reference)
+}
+
+/// Parses a reference time in the local timezone, e.g. "2026-10-18T09:00:00".
+pub fn parse_reference(reference: &str) -> Result<Moment<Local>, String> {
+    ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S"]
+        .iter()
+        .filter_map(|format| Local.datetime_from_str(reference, format).ok())
+        .next()
+        .map(Moment)
+        .ok_or_else(|| format!("Invalid reference time {:?}, expected a local time like \"2026-10-18T09:00:00\"", reference))
+}
+
+/// Sets the local timezone, e.g. "Europe/Paris", which must be in the tz database of the
+/// system. Must be called before the first use of the local timezone.
+pub fn set_timezone(tz: &str) -> Result<(), String> {
+    // An unknown TZ silently falls back to UTC
+    let database = env::var_os("TZDIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("/usr/share/zoneinfo"));
+    if tz.is_empty() || tz.starts_with('/') || tz.contains("..") || !database.join(tz).is_file() {
+        return Err(format!("Unknown timezone {:?}, expected a name of the tz database like \"Europe/Paris\"", tz));
+    }
+    env::set_var("TZ", tz);
+    Ok(())
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Utterance {
     pub phrase: String,
-    #[serde(with = "moment_json")]
-    pub context: Moment<Local>,
+    /// Reference time of the utterance, which overrides the default one of the test run.
+    #[serde(default, with = "optional_moment_json", skip_serializing_if = "Option::is_none")]
+    pub context: Option<Moment<Local>>,
     #[serde(rename = "in_grammar")]
     pub in_grammar: Option<bool>,
     pub skip_rustling: Option<bool>,
     pub translation: Option<String>,
     pub value: Option<SlotValue>,
+    /// All the entities expected in the utterance, which are checked instead of the single
+    /// full match `value` when present.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<Entity>>,
 }
 
 impl Utterance {
@@ -34,11 +77,16 @@ impl Utterance {
 #[serde(rename_all = "camelCase")]
 pub struct PartialUtterance {
     pub phrase: String,
+    /// Reference time to resolve the utterance with instead of the default one.
+    #[serde(default, with = "optional_moment_json", skip_serializing_if = "Option::is_none")]
+    pub context: Option<Moment<Local>>,
     #[serde(rename = "in_grammar")]
     pub in_grammar: Option<bool>,
     pub skip_rustling: Option<bool>,
     pub translation: Option<String>,
     pub value: Option<SlotValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<Entity>>,
 }
 
 impl PartialUtterance {
@@ -79,6 +127,17 @@ impl<A, B> TestAssertion<A, B> {
     }
 }
 
+/// Reads the phrases of the utterances which are known to fail, one per line. Empty lines and
+/// lines starting with `#` are ignored.
+pub fn read_expected_failures<P: AsRef<Path>>(path: P) -> io::Result<HashSet<String>> {
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.to_string())
+        .collect())
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TestOutput {
@@ -90,6 +149,136 @@ pub struct TestOutput {
     pub context: Moment<Local>,
     pub translation: Option<String>,
     pub output: TestAssertion<Vec<SlotValue>, Vec<SlotValue>>,
+    /// Scores of the entities found, for the utterances expecting a list of entities.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scores: Option<EntityScores>,
+}
+
+/// Entity of an utterance, with the character range of its text, e.g. `{"start": 5, "end": 6,
+/// "value": {"kind": "Number", "value": 2.0}}` for "book 2 tickets".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entity {
+    pub start: usize,
+    pub end: usize,
+    pub value: SlotValue,
+}
+
+/// Counts of the entities found against the expected ones, where a found entity is correct if
+/// an expected one has the same range and a value it matches.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityScores {
+    pub correct: usize,
+    pub found: usize,
+    pub expected: usize,
+}
+
+impl EntityScores {
+    pub fn new(expected: &[Entity], found: &[Entity]) -> EntityScores {
+        let mut unmatched = expected.iter().collect::<Vec<_>>();
+        let mut correct = 0;
+        for entity in found {
+            if let Some(ix) = unmatched.iter().position(|it| it.start == entity.start && it.end == entity.end && entity.value.matches_expected(&it.value)) {
+                unmatched.remove(ix);
+                correct += 1;
+            }
+        }
+        EntityScores {
+            correct,
+            found: found.len(),
+            expected: expected.len(),
+        }
+    }
+
+    pub fn add(&mut self, other: &EntityScores) {
+        self.correct += other.correct;
+        self.found += other.found;
+        self.expected += other.expected;
+    }
+
+    pub fn is_perfect(&self) -> bool {
+        self.correct == self.found && self.correct == self.expected
+    }
+
+    /// Ratio of the found entities which are correct, 1 when none is found.
+    pub fn precision(&self) -> f64 {
+        if self.found == 0 {
+            1.0
+        } else {
+            self.correct as f64 / self.found as f64
+        }
+    }
+
+    /// Ratio of the expected entities which are found, 1 when none is expected.
+    pub fn recall(&self) -> f64 {
+        if self.expected == 0 {
+            1.0
+        } else {
+            self.correct as f64 / self.expected as f64
+        }
+    }
+}
+
+/// Entities found in an utterance by a version of the parser, to compare them with the ones
+/// found by another version.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    pub phrase: String,
+    #[serde(default, with = "optional_moment_json", skip_serializing_if = "Option::is_none")]
+    pub context: Option<Moment<Local>>,
+    pub entities: Vec<Entity>,
+}
+
+/// What changed in the entities of an utterance between two snapshots.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Change {
+    Count,
+    Span,
+    Kind,
+    Value,
+}
+
+impl Snapshot {
+    /// Changes from the entities of this snapshot to the ones of `other`, comparing entities in
+    /// order, e.g. `[Change::Span]` when the same value is found for a longer text.
+    pub fn changes(&self, other: &Snapshot) -> Vec<Change> {
+        let mut changes = vec![];
+        if self.entities.len() != other.entities.len() {
+            changes.push(Change::Count);
+        }
+        for (before, after) in self.entities.iter().zip(other.entities.iter()) {
+            if (before.start, before.end) != (after.start, after.end) {
+                changes.push(Change::Span);
+            }
+            if before.value.kind() != after.value.kind() {
+                changes.push(Change::Kind);
+            } else if before.value != after.value {
+                changes.push(Change::Value);
+            }
+        }
+        let mut unique = vec![];
+        for change in changes {
+            if !unique.contains(&change) {
+                unique.push(change);
+            }
+        }
+        unique
+    }
+}
+
+/// Utterance whose entities changed between two snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotDiff {
+    pub phrase: String,
+    #[serde(default, with = "optional_moment_json", skip_serializing_if = "Option::is_none")]
+    pub context: Option<Moment<Local>>,
+    pub changes: Vec<Change>,
+    pub before: Vec<Entity>,
+    pub after: Vec<Entity>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -103,6 +292,58 @@ pub enum SlotValue {
     AmountOfMoney(AmountOfMoneyValue),
     Temperature(TemperatureValue),
     Duration(DurationValue),
+    DigitSequence(DigitSequenceValue),
+    PhoneNumber(PhoneNumberValue),
+    Age(AgeValue),
+}
+
+impl SlotValue {
+    /// Name of the kind of value, as in its "kind" field.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SlotValue::Number(_) => "Number",
+            SlotValue::Ordinal(_) => "Ordinal",
+            SlotValue::Percentage(_) => "Percentage",
+            SlotValue::InstantTime(_) => "InstantTime",
+            SlotValue::TimeInterval(_) => "TimeInterval",
+            SlotValue::AmountOfMoney(_) => "AmountOfMoney",
+            SlotValue::Temperature(_) => "Temperature",
+            SlotValue::Duration(_) => "Duration",
+            SlotValue::DigitSequence(_) => "DigitSequence",
+            SlotValue::PhoneNumber(_) => "PhoneNumber",
+            SlotValue::Age(_) => "Age",
+        }
+    }
+
+    /// Whether this value found by the parser is the expected one of an utterance. Unlike `==`,
+    /// the optional time and currency fields missing from the expected value aren't compared.
+    pub fn matches_expected(&self, expected: &SlotValue) -> bool {
+        match (self, expected) {
+            (SlotValue::InstantTime(found), SlotValue::InstantTime(expected)) => {
+                found.value == expected.value
+                    && found.grain == expected.grain
+                    && found.precision == expected.precision
+                    && optional_matches(&found.latent, &expected.latent)
+                    && optional_matches(&found.datetime_kind, &expected.datetime_kind)
+            }
+            (SlotValue::TimeInterval(found), SlotValue::TimeInterval(expected)) => {
+                found.from == expected.from
+                    && found.to == expected.to
+                    && optional_matches(&found.grain, &expected.grain)
+                    && optional_matches(&found.precision, &expected.precision)
+                    && optional_matches(&found.latent, &expected.latent)
+                    && optional_matches(&found.datetime_kind, &expected.datetime_kind)
+            }
+            (SlotValue::AmountOfMoney(found), SlotValue::AmountOfMoney(expected)) => {
+                found.value == expected.value
+                    && optional_matches(&found.major_value, &expected.major_value)
+                    && found.precision == expected.precision
+                    && found.unit == expected.unit
+                    && optional_matches(&found.currency, &expected.currency)
+            }
+            (found, expected) => found == expected,
+        }
+    }
 }
 
 impl From<Output> for SlotValue {
@@ -124,46 +365,74 @@ impl From<Output> for SlotValue {
                 value: datetime.moment,
                 grain: datetime.grain.into(),
                 precision: datetime.precision.into(),
+                latent: Some(datetime.latent),
+                datetime_kind: Some(datetime.datetime_kind.into()),
             }),
-            Output::DatetimeInterval(datetime_interval) => match datetime_interval.interval_kind {
-                DatetimeIntervalKind::After(datetime) => {
-                    SlotValue::TimeInterval(TimeIntervalValue {
-                        from: Some(datetime.moment),
-                        to: None,
-                    })
+            Output::DatetimeInterval(datetime_interval) => {
+                let datetime_kind = Some(datetime_interval.datetime_kind.into());
+                match datetime_interval.interval_kind {
+                    DatetimeIntervalKind::After(datetime) => {
+                        SlotValue::TimeInterval(TimeIntervalValue {
+                            from: Some(datetime.moment),
+                            to: None,
+                            grain: Some(datetime.grain.into()),
+                            precision: Some(datetime.precision.into()),
+                            latent: Some(datetime.latent),
+                            datetime_kind,
+                        })
+                    }
+                    DatetimeIntervalKind::Before(datetime) => {
+                        SlotValue::TimeInterval(TimeIntervalValue {
+                            from: None,
+                            to: Some(datetime.moment),
+                            grain: Some(datetime.grain.into()),
+                            precision: Some(datetime.precision.into()),
+                            latent: Some(datetime.latent),
+                            datetime_kind,
+                        })
+                    }
+                    DatetimeIntervalKind::Between { start, end, grain, precision, latent } => {
+                        SlotValue::TimeInterval(TimeIntervalValue {
+                            from: Some(start),
+                            to: Some(end),
+                            grain: Some(grain.into()),
+                            precision: Some(precision.into()),
+                            latent: Some(latent),
+                            datetime_kind,
+                        })
+                    }
                 }
-                DatetimeIntervalKind::Before(datetime) => {
-                    SlotValue::TimeInterval(TimeIntervalValue {
-                        from: None,
-                        to: Some(datetime.moment),
-                    })
-                }
-                DatetimeIntervalKind::Between { start, end, .. } => {
-                    SlotValue::TimeInterval(TimeIntervalValue {
-                        from: Some(start),
-                        to: Some(end),
-                    })
-                }
-            },
+            }
             Output::AmountOfMoney(amount) => SlotValue::AmountOfMoney(AmountOfMoneyValue {
-                value: amount.value,
+                value: amount.minor_unit_value().unwrap_or(amount.value),
+                major_value: amount.minor_unit_value().map(|_| amount.value),
                 precision: amount.precision.into(),
                 unit: amount.unit.map(|it| it.to_string()),
+                currency: amount.currency.map(|it| it.code().to_string()),
             }),
             Output::Temperature(temperature) => SlotValue::Temperature(TemperatureValue {
                 value: temperature.value,
-                unit: temperature.unit.map(|it| it.to_string()),
+                unit: temperature.unit.map(|it| it.as_str().to_string()),
             }),
-            Output::Duration(duration) => SlotValue::Duration(DurationValue {
-                years: *duration.period.0.get(Grain::Year as usize).unwrap_or(&0),
-                quarters: *duration.period.0.get(Grain::Quarter as usize).unwrap_or(&0),
-                months: *duration.period.0.get(Grain::Month as usize).unwrap_or(&0),
-                weeks: *duration.period.0.get(Grain::Week as usize).unwrap_or(&0),
-                days: *duration.period.0.get(Grain::Day as usize).unwrap_or(&0),
-                hours: *duration.period.0.get(Grain::Hour as usize).unwrap_or(&0),
-                minutes: *duration.period.0.get(Grain::Minute as usize).unwrap_or(&0),
-                seconds: *duration.period.0.get(Grain::Second as usize).unwrap_or(&0),
-                precision: duration.precision.into(),
+            Output::Duration(duration) => SlotValue::Duration(DurationValue::from_period(
+                &duration.period,
+                duration.precision.into(),
+            )),
+            Output::DigitSequence(sequence) => match sequence.kind {
+                dimension::DigitSequenceKind::PhoneNumber => SlotValue::PhoneNumber(PhoneNumberValue {
+                    value: sequence.digits.clone(),
+                    groups: sequence.groups.clone(),
+                    e164: sequence.e164(None),
+                }),
+                dimension::DigitSequenceKind::Generic => SlotValue::DigitSequence(DigitSequenceValue {
+                    value: sequence.digits,
+                    groups: sequence.groups,
+                    prefix: sequence.prefix,
+                }),
+            },
+            Output::Age(age) => SlotValue::Age(AgeValue {
+                from: age.min.map(|min| DurationValue::from_period(&min, Precision::Exact)),
+                to: age.max.map(|max| DurationValue::from_period(&max, Precision::Exact)),
             }),
         }
     }
@@ -213,12 +482,36 @@ impl PartialEq for PercentageValue {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DigitSequenceValue {
+    pub value: String,
+    pub groups: Vec<usize>,
+    pub prefix: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PhoneNumberValue {
+    pub value: String,
+    pub groups: Vec<usize>,
+    pub e164: Option<String>,
+}
+
+/// Optional fields added to the values after the first corpora were written are only compared
+/// when the expected value has them.
+fn optional_matches<T: PartialEq>(found: &Option<T>, expected: &Option<T>) -> bool {
+    expected.is_none() || found == expected
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct InstantTimeValue {
     #[serde(with = "moment_json")]
     pub value: Moment<Local>,
     pub grain: Grain,
     pub precision: Precision,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latent: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datetime_kind: Option<DatetimeKind>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
@@ -227,13 +520,29 @@ pub struct TimeIntervalValue {
     pub from: Option<Moment<Local>>,
     #[serde(with = "optional_moment_json")]
     pub to: Option<Moment<Local>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grain: Option<Grain>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precision: Option<Precision>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latent: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datetime_kind: Option<DatetimeKind>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct AmountOfMoneyValue {
+    /// Value in the unit as written in the input, e.g. 50 for "50 cents"
     pub value: f64,
+    /// Value in the major unit when the unit is a minor one, e.g. 0.5 for "50 cents"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub major_value: Option<f64>,
     pub precision: Precision,
+    /// Unit as written in the input, e.g. "cent" for "50 cents"
     pub unit: Option<String>,
+    /// ISO-4217 code of the currency, when the unit isn't ambiguous
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
@@ -255,6 +564,29 @@ pub struct DurationValue {
     pub precision: Precision,
 }
 
+impl DurationValue {
+    fn from_period(period: &moment::Period, precision: Precision) -> DurationValue {
+        DurationValue {
+            years: *period.0.get(Grain::Year as usize).unwrap_or(&0),
+            quarters: *period.0.get(Grain::Quarter as usize).unwrap_or(&0),
+            months: *period.0.get(Grain::Month as usize).unwrap_or(&0),
+            weeks: *period.0.get(Grain::Week as usize).unwrap_or(&0),
+            days: *period.0.get(Grain::Day as usize).unwrap_or(&0),
+            hours: *period.0.get(Grain::Hour as usize).unwrap_or(&0),
+            minutes: *period.0.get(Grain::Minute as usize).unwrap_or(&0),
+            seconds: *period.0.get(Grain::Second as usize).unwrap_or(&0),
+            precision,
+        }
+    }
+}
+
+/// Age, or age range when the bounds differ. Open ranges like "over 18" have a single bound.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct AgeValue {
+    pub from: Option<DurationValue>,
+    pub to: Option<DurationValue>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Hash)]
 pub enum Grain {
     Year = 0,
@@ -282,6 +614,29 @@ impl From<moment::Grain> for Grain {
     }
 }
 
+/// Kind of a datetime value, e.g. `TimePeriod` for "this afternoon" or `Date` for "tomorrow".
+#[derive(Serialize, Deserialize, Debug, PartialEq, Copy, Clone)]
+pub enum DatetimeKind {
+    Date,
+    Time,
+    DatePeriod,
+    TimePeriod,
+    Datetime,
+}
+
+impl From<dimension::DatetimeKind> for DatetimeKind {
+    fn from(o: dimension::DatetimeKind) -> DatetimeKind {
+        match o {
+            dimension::DatetimeKind::Date => DatetimeKind::Date,
+            dimension::DatetimeKind::Time => DatetimeKind::Time,
+            dimension::DatetimeKind::DatePeriod => DatetimeKind::DatePeriod,
+            dimension::DatetimeKind::TimePeriod => DatetimeKind::TimePeriod,
+            // Complements and unknown kinds are output as datetimes
+            _ => DatetimeKind::Datetime,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Copy, Clone)]
 pub enum Precision {
     Approximate,
@@ -352,3 +707,90 @@ mod optional_moment_json {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_interval_without_grain_and_kind() {
+        let old: SlotValue = serde_json::from_str(
+            r#"{"kind": "TimeInterval", "from": "2017-06-01 12:00:00", "to": "2017-06-01 19:00:00"}"#,
+        )
+        .unwrap();
+        let new: SlotValue = serde_json::from_str(
+            r#"{"kind": "TimeInterval", "from": "2017-06-01 12:00:00", "to": "2017-06-01 19:00:00",
+                "grain": "Hour", "precision": "Exact", "latent": false, "datetime_kind": "TimePeriod"}"#,
+        )
+        .unwrap();
+        assert!(new.matches_expected(&old));
+        assert!(!old.matches_expected(&new));
+        assert_ne!(old, new);
+
+        let mut other_kind = new.clone();
+        if let SlotValue::TimeInterval(ref mut value) = other_kind {
+            value.datetime_kind = Some(DatetimeKind::DatePeriod);
+        }
+        assert!(!other_kind.matches_expected(&new));
+        assert_ne!(new, other_kind);
+    }
+
+    #[test]
+    fn test_utterance_reference() {
+        let reference = Moment(Local.ymd(2026, 10, 18).and_hms(9, 0, 0));
+        let own = Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0));
+        assert_eq!(reference, utterance_reference(None, reference));
+        assert_eq!(reference, utterance_reference(Some(default_reference()), reference));
+        assert_eq!(own, utterance_reference(Some(own), reference));
+    }
+
+    #[test]
+    fn test_amount_of_money_in_minor_unit() {
+        let amount = rustling_ontology::output::AmountOfMoneyOutput::new(
+            50.0,
+            Some(rustling_ontology::Decimal::from(50)),
+            dimension::Precision::Exact,
+            Some("cent"),
+        );
+        let found = SlotValue::from(Output::AmountOfMoney(amount));
+        let old: SlotValue = serde_json::from_str(
+            r#"{"kind": "AmountOfMoney", "value": 50.0, "precision": "Exact", "unit": "cent"}"#,
+        )
+        .unwrap();
+        assert!(found.matches_expected(&old));
+        if let SlotValue::AmountOfMoney(ref value) = found {
+            assert_eq!(50.0, value.value);
+            assert_eq!(Some(0.5), value.major_value);
+        }
+    }
+
+    #[test]
+    fn test_instant_time_kind_round_trip() {
+        let value: SlotValue = serde_json::from_str(
+            r#"{"kind": "InstantTime", "value": "2017-06-02 00:00:00", "grain": "Day",
+                "precision": "Exact", "datetime_kind": "Date"}"#,
+        )
+        .unwrap();
+        let json = serde_json::to_string(&value).unwrap();
+        assert!(json.contains(r#""datetime_kind":"Date""#));
+        assert!(!json.contains("latent"));
+        assert_eq!(value, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn test_snapshot_changes() {
+        let number = |start, end, value| Entity { start, end, value: SlotValue::Number(NumberValue { value }) };
+        let snapshot = |entities| Snapshot { phrase: "book 2 tickets".to_string(), context: None, entities };
+        let before = snapshot(vec![number(5, 6, 2.0)]);
+        assert!(before.changes(&before.clone()).is_empty());
+        assert_eq!(vec![Change::Span], before.changes(&snapshot(vec![number(0, 6, 2.0)])));
+        assert_eq!(vec![Change::Value], before.changes(&snapshot(vec![number(5, 6, 3.0)])));
+        let ordinal = Entity { start: 5, end: 6, value: SlotValue::Ordinal(OrdinalValue { value: 2 }) };
+        assert_eq!(vec![Change::Kind], before.changes(&snapshot(vec![ordinal])));
+        assert_eq!(
+            vec![Change::Count, Change::Span],
+            before.changes(&snapshot(vec![number(0, 4, 2.0), number(5, 6, 2.0)]))
+        );
+        assert_eq!(vec![Change::Count], before.changes(&snapshot(vec![])));
+    }
+}
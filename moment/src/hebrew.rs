@@ -0,0 +1,168 @@
+use chrono::{Datelike, NaiveDate};
+
+// Day 1 of the Hebrew calendar (7 October 3761 BCE, Julian), counted in days from 0001-01-01
+// (day 1)
+const HEBREW_EPOCH: i64 = -1_373_427;
+
+/// Months of the Hebrew calendar are numbered from Nisan, as in the biblical reckoning, while
+/// years start with Tishrei. Adar I is the 12th month of leap years and Adar II the 13th.
+pub const NISAN: u32 = 1;
+pub const TISHREI: u32 = 7;
+pub const ADAR: u32 = 12;
+pub const ADAR_II: u32 = 13;
+
+/// Whether the given year of the Hebrew calendar has a 13th month.
+pub fn is_leap_year(year: i32) -> bool {
+    (7 * year as i64 + 1).rem_euclid(19) < 7
+}
+
+fn last_month_of_year(year: i32) -> u32 {
+    if is_leap_year(year) {
+        ADAR_II
+    } else {
+        ADAR
+    }
+}
+
+// Days elapsed from the epoch to the molad of Tishrei of the given year, postponed by a day when
+// it falls on a sunday, a wednesday or a friday
+fn elapsed_days(year: i32) -> i64 {
+    let months_elapsed = (235 * year as i64 - 234).div_euclid(19);
+    let parts_elapsed = 12_084 + 13_753 * months_elapsed;
+    let days = 29 * months_elapsed + parts_elapsed.div_euclid(25_920);
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+// Postponements preventing years of 356 or 382 days
+fn year_length_correction(year: i32) -> i64 {
+    let previous = elapsed_days(year - 1);
+    let current = elapsed_days(year);
+    let next = elapsed_days(year + 1);
+    if next - current == 356 {
+        2
+    } else if current - previous == 382 {
+        1
+    } else {
+        0
+    }
+}
+
+fn fixed_new_year(year: i32) -> i64 {
+    HEBREW_EPOCH + elapsed_days(year) + year_length_correction(year)
+}
+
+/// Number of days in a year of the Hebrew calendar.
+pub fn year_days(year: i32) -> u32 {
+    (fixed_new_year(year + 1) - fixed_new_year(year)) as u32
+}
+
+/// Number of days in a month of the Hebrew calendar.
+pub fn month_days(year: i32, month: u32) -> Option<u32> {
+    if month < 1 || month > last_month_of_year(year) {
+        return None;
+    }
+    let year_days = year_days(year);
+    let is_short = match month {
+        2 | 4 | 6 | 10 | 13 => true,
+        // Adar has 30 days only when it is Adar I
+        12 => !is_leap_year(year),
+        // Heshvan has 30 days on complete years only
+        8 => year_days % 10 != 5,
+        // Kislev has 29 days on deficient years only
+        9 => year_days % 10 == 3,
+        _ => false,
+    };
+    if is_short {
+        Some(29)
+    } else {
+        Some(30)
+    }
+}
+
+/// Maximum number of days a month of the Hebrew calendar may have.
+pub fn max_month_days(month: u32) -> Option<u32> {
+    match month {
+        2 | 4 | 6 | 10 | 13 => Some(29),
+        1..=12 => Some(30),
+        _ => None,
+    }
+}
+
+/// Gregorian date of Rosh Hashanah, the first day of the given Hebrew year.
+pub fn new_year(year: i32) -> Option<NaiveDate> {
+    NaiveDate::from_num_days_from_ce_opt(fixed_new_year(year) as i32)
+}
+
+/// Converts a date of the Hebrew calendar to the gregorian calendar.
+///
+/// Returns `None` if the date does not exist, e.g. for Adar II on a common year.
+pub fn to_gregorian(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    if day < 1 || day > month_days(year, month)? {
+        return None;
+    }
+    let months_before: u32 = if month < TISHREI {
+        (TISHREI..=last_month_of_year(year))
+            .chain(NISAN..month)
+            .filter_map(|m| month_days(year, m))
+            .sum()
+    } else {
+        (TISHREI..month).filter_map(|m| month_days(year, m)).sum()
+    };
+    let fixed = fixed_new_year(year) + months_before as i64 + day as i64 - 1;
+    NaiveDate::from_num_days_from_ce_opt(fixed as i32)
+}
+
+/// Hebrew year a gregorian date belongs to.
+pub fn year_of(date: NaiveDate) -> i32 {
+    let year = date.year() + 3760;
+    if date.num_days_from_ce() as i64 >= fixed_new_year(year + 1) {
+        year + 1
+    } else {
+        year
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_year() {
+        assert_eq!(Some(NaiveDate::from_ymd(2013, 9, 5)), new_year(5774));
+        assert_eq!(Some(NaiveDate::from_ymd(2023, 9, 16)), new_year(5784));
+        assert_eq!(Some(NaiveDate::from_ymd(2024, 10, 3)), new_year(5785));
+        assert_eq!(Some(NaiveDate::from_ymd(2025, 9, 23)), new_year(5786));
+    }
+
+    #[test]
+    fn test_to_gregorian() {
+        // Yom Kippur
+        assert_eq!(Some(NaiveDate::from_ymd(2023, 9, 25)), to_gregorian(5784, TISHREI, 10));
+        // Passover
+        assert_eq!(Some(NaiveDate::from_ymd(2024, 4, 23)), to_gregorian(5784, NISAN, 15));
+        // Purim, on Adar II on leap years
+        assert_eq!(Some(NaiveDate::from_ymd(2024, 3, 24)), to_gregorian(5784, ADAR_II, 14));
+        assert_eq!(Some(NaiveDate::from_ymd(2025, 3, 14)), to_gregorian(5785, ADAR, 14));
+        assert_eq!(None, to_gregorian(5785, ADAR_II, 14));
+        assert_eq!(None, to_gregorian(5785, 4, 30));
+    }
+
+    #[test]
+    fn test_year_of() {
+        assert_eq!(5784, year_of(NaiveDate::from_ymd(2023, 9, 16)));
+        assert_eq!(5783, year_of(NaiveDate::from_ymd(2023, 9, 15)));
+        assert_eq!(5784, year_of(NaiveDate::from_ymd(2024, 1, 1)));
+    }
+
+    #[test]
+    fn test_year_days() {
+        assert!(is_leap_year(5784));
+        assert!(!is_leap_year(5785));
+        assert_eq!(383, year_days(5784));
+        assert_eq!(355, year_days(5785));
+    }
+}
@@ -0,0 +1,95 @@
+use chrono::{Datelike, NaiveDate};
+
+// Day 1 of the Hijri calendar (16 July 622, Julian), counted in days from 0001-01-01 (day 1)
+const HIJRI_EPOCH: i64 = 227_015;
+
+/// Whether the given year of the tabular Hijri calendar has 355 days.
+///
+/// The tabular calendar inserts 11 leap days in a 30 years cycle, on years 2, 5, 7, 10, 13,
+/// 16, 18, 21, 24, 26 and 29 of the cycle.
+pub fn is_leap_year(year: i32) -> bool {
+    (14 + 11 * year as i64).rem_euclid(30) < 11
+}
+
+/// Number of days in a month of the tabular Hijri calendar.
+///
+/// Odd months have 30 days and even months 29, except the last month of a leap year which has
+/// 30 days.
+pub fn month_days(year: i32, month: u32) -> Option<u32> {
+    match month {
+        12 if is_leap_year(year) => Some(30),
+        1..=12 if month % 2 == 1 => Some(30),
+        1..=12 => Some(29),
+        _ => None,
+    }
+}
+
+/// Maximum number of days a month of the tabular Hijri calendar may have.
+pub fn max_month_days(month: u32) -> Option<u32> {
+    match month {
+        12 => Some(30),
+        1..=11 => month_days(1, month),
+        _ => None,
+    }
+}
+
+fn fixed_from_hijri(year: i32, month: u32, day: u32) -> i64 {
+    let (year, month, day) = (year as i64, month as i64, day as i64);
+    HIJRI_EPOCH - 1
+        + (year - 1) * 354
+        + (3 + 11 * year).div_euclid(30)
+        + 29 * (month - 1)
+        + (6 * month - 1).div_euclid(11)
+        + day
+}
+
+/// Converts a date of the tabular Hijri calendar to the gregorian calendar.
+///
+/// Returns `None` if the date does not exist.
+pub fn to_gregorian(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    if day < 1 || day > month_days(year, month)? {
+        return None;
+    }
+    NaiveDate::from_num_days_from_ce_opt(fixed_from_hijri(year, month, day) as i32)
+}
+
+/// Converts a gregorian date to a `(year, month, day)` date of the tabular Hijri calendar.
+pub fn from_gregorian(date: NaiveDate) -> (i32, u32, u32) {
+    let fixed = date.num_days_from_ce() as i64;
+    let year = (30 * (fixed - HIJRI_EPOCH) + 10_646).div_euclid(10_631) as i32;
+    let prior_days = fixed - fixed_from_hijri(year, 1, 1);
+    let month = (11 * prior_days + 330).div_euclid(325) as u32;
+    let day = (fixed - fixed_from_hijri(year, month, 1) + 1) as u32;
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_gregorian() {
+        assert_eq!(Some(NaiveDate::from_ymd(2013, 7, 9)), to_gregorian(1434, 9, 1));
+        assert_eq!(Some(NaiveDate::from_ymd(2024, 3, 11)), to_gregorian(1445, 9, 1));
+        assert_eq!(Some(NaiveDate::from_ymd(2024, 4, 10)), to_gregorian(1445, 10, 1));
+        assert_eq!(Some(NaiveDate::from_ymd(2024, 6, 17)), to_gregorian(1445, 12, 10));
+        assert_eq!(None, to_gregorian(1445, 2, 30));
+        assert_eq!(None, to_gregorian(1445, 13, 1));
+    }
+
+    #[test]
+    fn test_from_gregorian() {
+        for &(year, month, day) in &[(1434, 9, 1), (1445, 12, 29), (1446, 1, 1), (1500, 6, 29)] {
+            let date = to_gregorian(year, month, day).unwrap();
+            assert_eq!((year, month, day), from_gregorian(date));
+        }
+    }
+
+    #[test]
+    fn test_leap_years() {
+        let leap_years = (1..31).filter(|y| is_leap_year(*y)).collect::<Vec<_>>();
+        assert_eq!(vec![2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29], leap_years);
+        assert_eq!(Some(30), month_days(1445, 12));
+        assert_eq!(Some(29), month_days(1444, 12));
+    }
+}
@@ -1,8 +1,10 @@
 use crate::bidirectional_walker::*;
+use crate::lunar::*;
+use crate::{hebrew, hijri};
 use crate::period::*;
 use crate::walker::*;
 use crate::{last_day_in_month, Interval, Moment, MomentError, MomentResult};
-use chrono::{Datelike, Local, TimeZone, Timelike, Weekday};
+use chrono::{Datelike, Local, NaiveDate, TimeZone, Timelike, Weekday};
 use std::fmt;
 use std::ops;
 use std::rc::Rc;
@@ -427,6 +429,283 @@ where
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LunarMonthDay {
+    pub calendar: LunarCalendar,
+    pub month: u32,
+    pub day: u32,
+    pub leap: bool,
+}
+
+impl LunarMonthDay {
+    pub fn new<T: TimeZone + 'static>(
+        calendar: LunarCalendar,
+        m: u32,
+        d: u32,
+    ) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = LunarMonthDay {
+            calendar,
+            month: m,
+            day: d,
+            leap: false,
+        };
+        if is_valid_month(m) && 1 <= d && d <= 30 {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for LunarMonthDay
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let lunar_date = *self;
+        let timezone = origin.timezone();
+        // The lunar year Y may end in the gregorian year Y + 1
+        let to_gregorian = move |year_interval: Interval<T>| -> Option<Interval<T>> {
+            lunar_date
+                .calendar
+                .to_gregorian(
+                    year_interval.start.year(),
+                    lunar_date.month,
+                    lunar_date.day,
+                    lunar_date.leap,
+                )
+                .map(|date| {
+                    let moment = Moment(
+                        timezone
+                            .ymd(date.year(), date.month(), date.day())
+                            .and_hms(0, 0, 0),
+                    );
+                    Interval::starting_at(moment, Grain::Day)
+                })
+        };
+        let anchor = origin.start_round_to(Grain::Year);
+        // Boundaries for iteration
+        let origin_start = origin.start;
+        let max_context_moment = context.max.end_moment();
+        let min_context_moment = context.min.start;
+
+        let forward_walker = Walker::generator(anchor - PeriodComp::years(1), |prev| {
+            prev + PeriodComp::years(1)
+        })
+        .take_while(|i| i.start.year() <= LAST_LUNAR_YEAR)
+        .filter_map(to_gregorian.clone())
+        .skip_while(move |i| i.end_moment() <= origin_start)
+        .take_while(move |i| i.end_moment() <= max_context_moment);
+
+        let backward_walker = Walker::generator(anchor, |prev| prev - PeriodComp::years(1))
+            .take_while(|i| i.start.year() >= FIRST_LUNAR_YEAR)
+            .filter_map(to_gregorian)
+            .skip_while(move |i| i.end_moment() > origin_start)
+            .take_while(move |i| i.start >= min_context_moment);
+
+        BidirectionalWalker::new()
+            .forward(forward_walker)
+            .backward(backward_walker)
+    }
+}
+
+// Walks the occurrences of a date recurring every year of a non gregorian calendar.
+// `to_gregorian` converts this date for a given year of the calendar, if it exists that year, and
+// `year_of` gives the year of the calendar a gregorian date belongs to. The generated year
+// intervals are only used to carry the year number of the calendar.
+fn calendar_date_walker<T, F, Y>(
+    origin: &Interval<T>,
+    context: &Context<T>,
+    to_gregorian: F,
+    year_of: Y,
+) -> IntervalWalker<T>
+where
+    T: TimeZone + 'static,
+    <T as TimeZone>::Offset: Copy,
+    F: Fn(i32) -> Option<NaiveDate> + Copy + 'static,
+    Y: Fn(NaiveDate) -> i32,
+{
+    let timezone = origin.timezone();
+    let to_interval = move |year_interval: Interval<T>| -> Option<Interval<T>> {
+        to_gregorian(year_interval.start.year()).map(|date| {
+            let moment = Moment(
+                timezone
+                    .ymd(date.year(), date.month(), date.day())
+                    .and_hms(0, 0, 0),
+            );
+            Interval::starting_at(moment, Grain::Day)
+        })
+    };
+    let origin_date = NaiveDate::from_ymd(
+        origin.start.year(),
+        origin.start.month(),
+        origin.start.day(),
+    );
+    let year_offset = year_of(origin_date) - origin.start.year();
+    let anchor = origin.start_round_to(Grain::Year) + PeriodComp::years(year_offset as i64);
+    // Boundaries for iteration
+    let origin_start = origin.start;
+    let max_context_moment = context.max.end_moment();
+    let min_context_moment = context.min.start;
+
+    let forward_walker = Walker::generator(anchor - PeriodComp::years(1), |prev| {
+        prev + PeriodComp::years(1)
+    })
+    .filter_map(to_interval.clone())
+    .skip_while(move |i| i.end_moment() <= origin_start)
+    .take_while(move |i| i.end_moment() <= max_context_moment);
+
+    let backward_walker = Walker::generator(anchor + PeriodComp::years(1), |prev| {
+        prev - PeriodComp::years(1)
+    })
+    .filter_map(to_interval)
+    .skip_while(move |i| i.end_moment() > origin_start)
+    .take_while(move |i| i.start >= min_context_moment);
+
+    BidirectionalWalker::new()
+        .forward(forward_walker)
+        .backward(backward_walker)
+}
+
+/// A day of a month of the tabular Hijri calendar.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HijriMonthDay {
+    pub month: u32,
+    pub day: u32,
+}
+
+impl HijriMonthDay {
+    pub fn new<T: TimeZone + 'static>(m: u32, d: u32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = HijriMonthDay { month: m, day: d };
+        match hijri::max_month_days(m) {
+            Some(max_days) if 1 <= d && d <= max_days => Ok(rc!(args)),
+            _ => Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            }),
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for HijriMonthDay
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let HijriMonthDay { month, day } = *self;
+        calendar_date_walker(
+            origin,
+            context,
+            move |year| hijri::to_gregorian(year, month, day),
+            |date| hijri::from_gregorian(date).0,
+        )
+    }
+}
+
+/// A day of a month of the Hebrew calendar, months being numbered from Nisan.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HebrewMonthDay {
+    pub month: u32,
+    pub day: u32,
+}
+
+impl HebrewMonthDay {
+    pub fn new<T: TimeZone + 'static>(m: u32, d: u32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = HebrewMonthDay { month: m, day: d };
+        match hebrew::max_month_days(m) {
+            Some(max_days) if 1 <= d && d <= max_days => Ok(rc!(args)),
+            _ => Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            }),
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for HebrewMonthDay
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let HebrewMonthDay { month, day } = *self;
+        calendar_date_walker(
+            origin,
+            context,
+            move |year| hebrew::to_gregorian(year, month, day),
+            hebrew::year_of,
+        )
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Month(pub u32);
 
@@ -2093,6 +2372,156 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_lunar_new_year() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let new_year = LunarMonthDay {
+            calendar: LunarCalendar::Chinese,
+            month: 1,
+            day: 1,
+            leap: false,
+        };
+        let walker = new_year.to_walker(&context.reference, &context);
+
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2018, 02, 16).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2019, 02, 05).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().skip(1).next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 01, 28).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2016, 02, 08).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().skip(1).next()
+        );
+    }
+
+    #[test]
+    fn test_lunar_month_day_across_gregorian_years() {
+        // The 12th lunar month of 2016 starts on the 28th of December 2016
+        let context = build_context(Moment(Paris.ymd(2017, 01, 10).and_hms(9, 10, 11)));
+        let laba = LunarMonthDay {
+            calendar: LunarCalendar::Chinese,
+            month: 12,
+            day: 8,
+            leap: false,
+        };
+        let walker = laba.to_walker(&context.reference, &context);
+
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2018, 01, 24).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 01, 05).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().next()
+        );
+    }
+
+    #[test]
+    fn test_hijri_month_day() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let eid_al_fitr = HijriMonthDay { month: 10, day: 1 };
+        let walker = eid_al_fitr.to_walker(&context.reference, &context);
+
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 06, 26).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2018, 06, 15).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().skip(1).next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2016, 07, 07).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().next()
+        );
+    }
+
+    #[test]
+    fn test_hijri_month_day_twice_in_gregorian_year() {
+        // The hijri year 1429 started on the 10th of January 2008 and ended on the 28th of December
+        let context = build_context(Moment(Paris.ymd(2008, 02, 01).and_hms(9, 10, 11)));
+        let new_year = HijriMonthDay { month: 1, day: 1 };
+        let walker = new_year.to_walker(&context.reference, &context);
+
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2008, 12, 29).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2008, 01, 10).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().next()
+        );
+    }
+
+    #[test]
+    fn test_hebrew_month_day() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let rosh_hashanah = HebrewMonthDay { month: 7, day: 1 };
+        let walker = rosh_hashanah.to_walker(&context.reference, &context);
+
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 09, 21).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2018, 09, 10).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().skip(1).next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2016, 10, 03).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().next()
+        );
+    }
+
     #[test]
     fn test_month_above_current_month() {
         let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
@@ -3051,5 +3480,13 @@ mod tests {
         assert!(Month::new::<Local>(13).is_err());
         assert!(DayOfMonth::new::<Local>(0).is_err());
         assert!(DayOfMonth::new::<Local>(32).is_err());
+        assert!(LunarMonthDay::new::<Local>(LunarCalendar::Chinese, 13, 1).is_err());
+        assert!(LunarMonthDay::new::<Local>(LunarCalendar::Korean, 8, 31).is_err());
+        assert!(HijriMonthDay::new::<Local>(2, 30).is_err());
+        assert!(HijriMonthDay::new::<Local>(13, 1).is_err());
+        assert!(HijriMonthDay::new::<Local>(12, 30).is_ok());
+        assert!(HebrewMonthDay::new::<Local>(13, 30).is_err());
+        assert!(HebrewMonthDay::new::<Local>(0, 1).is_err());
+        assert!(HebrewMonthDay::new::<Local>(13, 14).is_ok());
     }
 }
@@ -8,7 +8,10 @@ extern crate failure;
 extern crate vec_map;
 
 pub mod bidirectional_walker;
+pub mod hebrew;
+pub mod hijri;
 pub mod interval_constraints;
+pub mod lunar;
 mod period;
 pub mod walker;
 
@@ -16,9 +19,10 @@ use std::cmp::Ordering;
 use std::fmt;
 use std::ops;
 
-use chrono::{DateTime, Datelike, Duration, Timelike};
+use chrono::{DateTime, Datelike, Duration, LocalResult, NaiveDateTime, Offset, Timelike};
 pub use chrono::{Local, TimeZone, Weekday};
 pub use interval_constraints::*;
+pub use lunar::LunarCalendar;
 pub use period::*;
 
 pub type MomentResult<T> = Result<T, MomentError>;
@@ -27,6 +31,8 @@ pub type MomentResult<T> = Result<T, MomentError>;
 pub enum MomentError {
     #[fail(display = "Invalid args given for this constraints: {}", context)]
     ConstraintsInvalidArgs { context: String },
+    #[fail(display = "Invalid ISO-8601 duration: {}", context)]
+    InvalidPeriod { context: String },
 }
 
 #[derive(Clone)]
@@ -69,8 +75,37 @@ where
     }
 }
 
+impl<T: TimeZone> Moment<T>
+where
+    T::Offset: fmt::Display,
+{
+    /// RFC-3339 representation with the UTC offset, e.g. "2026-10-19T14:30:00+02:00".
+    pub fn to_rfc3339(&self) -> String {
+        self.0.format("%Y-%m-%dT%H:%M:%S%:z").to_string()
+    }
+
+    /// ISO-8601 representation truncated to the given grain, e.g. "2026-10" for a month,
+    /// "2026-W42" for a week or "2026-10-19T14+02:00" for an hour. Quarters, which ISO-8601
+    /// doesn't cover, are written "2026-Q4".
+    pub fn to_iso8601(&self, grain: Grain) -> String {
+        match grain {
+            Grain::Year => self.0.format("%Y").to_string(),
+            Grain::Quarter => format!("{}-Q{}", self.0.format("%Y"), self.0.month0() / 3 + 1),
+            Grain::Month => self.0.format("%Y-%m").to_string(),
+            Grain::Week => self.0.format("%G-W%V").to_string(),
+            Grain::Day => self.0.format("%Y-%m-%d").to_string(),
+            Grain::Hour => self.0.format("%Y-%m-%dT%H%:z").to_string(),
+            Grain::Minute => self.0.format("%Y-%m-%dT%H:%M%:z").to_string(),
+            Grain::Second => self.to_rfc3339(),
+        }
+    }
+}
+
+/// Last day of a month, or 0 for an invalid month which has no days.
 fn last_day_in_month<T: TimeZone>(y: i32, m: u32, tz: T) -> u32 {
-    assert!(m >= 1 && m <= 12);
+    if m < 1 || m > 12 {
+        return 0;
+    }
     for d in 28..31 {
         if (tz.ymd_opt(y, m, d + 1)).single().is_none() {
             return d as u32;
@@ -130,11 +165,26 @@ where
         };
         let target_month_days = last_day_in_month(year, month0 + 1, self.timezone());
         let day = ::std::cmp::min(target_month_days, self.day());
-        Moment(self.timezone().ymd(year, month0 + 1, day).and_hms(
-            self.hour(),
-            self.minute(),
-            self.second(),
-        ))
+        let naive = chrono::NaiveDate::from_ymd(year, month0 + 1, day).and_time(self.time());
+        Moment::from_local(self.timezone(), &naive)
+    }
+
+    /// Moment at the given wall clock time, which doesn't panic around daylight saving time
+    /// changes: an ambiguous time resolves to its earliest occurrence, and a time skipped by
+    /// the clocks moving forward is shifted forward by the size of the gap, e.g. 02:30 becomes
+    /// 03:30 on the night clocks go from 02:00 to 03:00.
+    fn from_local(tz: T, naive: &NaiveDateTime) -> Moment<T> {
+        match tz.from_local_datetime(naive) {
+            LocalResult::Single(dt) => Moment(dt),
+            LocalResult::Ambiguous(earliest, _) => Moment(earliest),
+            LocalResult::None => {
+                let offset = tz
+                    .offset_from_utc_datetime(&(*naive - Duration::days(1)))
+                    .fix()
+                    .local_minus_utc();
+                Moment(tz.from_utc_datetime(&(*naive - Duration::seconds(offset as i64))))
+            }
+        }
     }
 
     fn round_to(self, g: Grain) -> Moment<T> {
@@ -160,12 +210,9 @@ where
         }
     }
 
-    fn adjust_for_daylight_saving(self) -> Moment<T> {
-        Moment(
-            self.timezone()
-                .ymd(self.year(), self.month(), self.day())
-                .and_hms(self.hour(), self.minute(), self.second()),
-        )
+    /// Adds calendar days, keeping the wall clock time across daylight saving time changes.
+    fn add_days(self, n: i64) -> Moment<T> {
+        Moment::from_local(self.timezone(), &(self.naive_local() + Duration::days(n)))
     }
 }
 
@@ -218,10 +265,8 @@ where
             Grain::Year => self.add_months(12 * p.quantity as i32),
             Grain::Quarter => self.add_months(3 * p.quantity as i32),
             Grain::Month => self.add_months(p.quantity as i32),
-            Grain::Week => {
-                Moment(self.0 + Duration::weeks(p.quantity)).adjust_for_daylight_saving()
-            }
-            Grain::Day => Moment(self.0 + Duration::days(p.quantity)).adjust_for_daylight_saving(),
+            Grain::Week => self.add_days(7 * p.quantity),
+            Grain::Day => self.add_days(p.quantity),
             Grain::Hour => Moment(self.0 + Duration::hours(p.quantity)),
             Grain::Minute => Moment(self.0 + Duration::minutes(p.quantity)),
             Grain::Second => Moment(self.0 + Duration::seconds(p.quantity)),
@@ -497,6 +542,24 @@ mod tests {
         assert_eq!(last_day_in_month(2016, 10, Paris), 31);
         assert_eq!(last_day_in_month(2016, 11, Paris), 30);
         assert_eq!(last_day_in_month(2016, 12, Paris), 31);
+        assert_eq!(last_day_in_month(2016, 0, Paris), 0);
+        assert_eq!(last_day_in_month(2016, 13, Paris), 0);
+    }
+
+    #[test]
+    fn format_moment() {
+        let now = Moment(Paris.ymd(2026, 10, 19).and_hms(14, 30, 5));
+        assert_eq!("2026-10-19T14:30:05+02:00", now.to_rfc3339());
+        assert_eq!("2026", now.to_iso8601(Grain::Year));
+        assert_eq!("2026-Q4", now.to_iso8601(Grain::Quarter));
+        assert_eq!("2026-10", now.to_iso8601(Grain::Month));
+        assert_eq!("2026-W43", now.to_iso8601(Grain::Week));
+        assert_eq!("2026-10-19", now.to_iso8601(Grain::Day));
+        assert_eq!("2026-10-19T14+02:00", now.to_iso8601(Grain::Hour));
+        assert_eq!("2026-10-19T14:30+02:00", now.to_iso8601(Grain::Minute));
+        assert_eq!("2026-10-19T14:30:05+02:00", now.to_iso8601(Grain::Second));
+        let new_year = Moment(Paris.ymd(2027, 1, 1).and_hms(0, 0, 0));
+        assert_eq!("2026-W53", new_year.to_iso8601(Grain::Week));
     }
 
     #[test]
@@ -761,4 +824,99 @@ mod tests {
         };
         assert_eq!(5 * 86400, interval.seconds());
     }
+
+    /// Central European time in 2019: UTC+1, and UTC+2 from March 31st 01:00 UTC to
+    /// October 27th 01:00 UTC.
+    #[derive(Debug, Copy, Clone)]
+    struct Cet2019;
+
+    impl Cet2019 {
+        fn summer_start() -> NaiveDateTime {
+            NaiveDate::from_ymd(2019, 3, 31).and_hms(1, 0, 0)
+        }
+
+        fn summer_end() -> NaiveDateTime {
+            NaiveDate::from_ymd(2019, 10, 27).and_hms(1, 0, 0)
+        }
+    }
+
+    impl TimeZone for Cet2019 {
+        type Offset = FixedOffset;
+        fn from_offset(_: &FixedOffset) -> Cet2019 {
+            Cet2019
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(&local.and_hms(12, 0, 0))
+        }
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            let winter = FixedOffset::east(3600);
+            let summer = FixedOffset::east(2 * 3600);
+            let as_winter = self.offset_from_utc_datetime(&(*local - Duration::hours(1)));
+            let as_summer = self.offset_from_utc_datetime(&(*local - Duration::hours(2)));
+            match (as_winter == winter, as_summer == summer) {
+                (true, true) => LocalResult::Ambiguous(summer, winter),
+                (true, false) => LocalResult::Single(winter),
+                (false, true) => LocalResult::Single(summer),
+                (false, false) => LocalResult::None,
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+            self.offset_from_utc_datetime(&utc.and_hms(12, 0, 0))
+        }
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            if *utc >= Cet2019::summer_start() && *utc < Cet2019::summer_end() {
+                FixedOffset::east(2 * 3600)
+            } else {
+                FixedOffset::east(3600)
+            }
+        }
+    }
+
+    #[test]
+    fn add_days_across_daylight_saving() {
+        let now = Moment(Cet2019.ymd(2019, 3, 30).and_hms(12, 0, 0));
+        assert_eq!(
+            Moment(Cet2019.ymd(2019, 3, 31).and_hms(12, 0, 0)),
+            now + PeriodComp::days(1)
+        );
+        assert_eq!(
+            Moment(Cet2019.ymd(2019, 4, 6).and_hms(12, 0, 0)),
+            now + PeriodComp::weeks(1)
+        );
+        let now = Moment(Cet2019.ymd(2019, 10, 26).and_hms(12, 0, 0));
+        assert_eq!(
+            Moment(Cet2019.ymd(2019, 10, 27).and_hms(12, 0, 0)),
+            now + PeriodComp::days(1)
+        );
+    }
+
+    #[test]
+    fn add_hours_across_daylight_saving() {
+        let now = Moment(Cet2019.ymd(2019, 3, 30).and_hms(12, 0, 0));
+        let later = now + PeriodComp::hours(24);
+        assert_eq!(Moment(Cet2019.ymd(2019, 3, 31).and_hms(13, 0, 0)), later);
+        assert_eq!(24 * 3600, later.timestamp() - now.timestamp());
+    }
+
+    #[test]
+    fn add_period_into_daylight_saving_gap() {
+        let now = Moment(Cet2019.ymd(2019, 3, 30).and_hms(2, 30, 0));
+        let period = Period::default() + PeriodComp::days(1);
+        let later = now + period;
+        assert_eq!(3, later.hour());
+        assert_eq!(30, later.minute());
+        assert_eq!(24 * 3600, later.timestamp() - now.timestamp());
+        let now = Moment(Cet2019.ymd(2018, 3, 31).and_hms(2, 30, 0));
+        assert_eq!(Moment(Cet2019.ymd(2019, 3, 31).and_hms(3, 30, 0)), now + PeriodComp::years(1));
+    }
+
+    #[test]
+    fn add_period_into_ambiguous_time() {
+        let now = Moment(Cet2019.ymd(2019, 10, 26).and_hms(2, 30, 0));
+        let later = now + PeriodComp::days(1);
+        assert_eq!(2, later.hour());
+        assert_eq!(24 * 3600, later.timestamp() - now.timestamp());
+    }
 }
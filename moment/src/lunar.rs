@@ -0,0 +1,207 @@
+use chrono::{Duration, NaiveDate};
+
+/// First lunar year covered by the conversion tables.
+pub const FIRST_LUNAR_YEAR: i32 = 1900;
+/// Last lunar year covered by the conversion tables.
+pub const LAST_LUNAR_YEAR: i32 = 2100;
+
+/// Lunisolar calendars supported by the conversion tables.
+///
+/// The Chinese and the Korean (Dangi) calendars share the same rules but are computed for
+/// different meridians, so that month lengths and leap months differ on some years.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LunarCalendar {
+    Chinese,
+    Korean,
+}
+
+// Each entry describes a lunar year, starting from 1900:
+// - bits 0..3: leap month (0 if there is no leap month this year)
+// - bits 15 down to 4: length of months 1 to 12 (1 for a 30 days month, 0 for 29 days)
+// - bit 16: length of the leap month
+static CHINESE_LUNAR_INFO: [u32; 201] = [
+    0x04bd8, 0x04ae0, 0x0a570, 0x054d5, 0x0d260, 0x0d950, 0x15554, 0x056a0, 0x09ad0, 0x055d2,
+    0x04ae0, 0x0a5b6, 0x0a4d0, 0x0d250, 0x1d255, 0x0b540, 0x0d6a0, 0x0ada2, 0x095b0, 0x14977,
+    0x04970, 0x0a4b0, 0x0b4b5, 0x06a50, 0x06d40, 0x1ab54, 0x02b60, 0x09570, 0x052f2, 0x04970,
+    0x06566, 0x0d4a0, 0x0ea50, 0x16a95, 0x05ad0, 0x02b60, 0x186e3, 0x092e0, 0x1c8d7, 0x0c950,
+    0x0d4a0, 0x1d8a6, 0x0b550, 0x056a0, 0x1a5b4, 0x025d0, 0x092d0, 0x0d2b2, 0x0a950, 0x0b557,
+    0x06ca0, 0x0b550, 0x15355, 0x04da0, 0x0a5b0, 0x14573, 0x052b0, 0x0a9a8, 0x0e950, 0x06aa0,
+    0x0aea6, 0x0ab50, 0x04b60, 0x0aae4, 0x0a570, 0x05260, 0x0f263, 0x0d950, 0x05b57, 0x056a0,
+    0x096d0, 0x04dd5, 0x04ad0, 0x0a4d0, 0x0d4d4, 0x0d250, 0x0d558, 0x0b540, 0x0b6a0, 0x195a6,
+    0x095b0, 0x049b0, 0x0a974, 0x0a4b0, 0x0b27a, 0x06a50, 0x06d40, 0x0af46, 0x0ab60, 0x09570,
+    0x04af5, 0x04970, 0x064b0, 0x074a3, 0x0ea50, 0x06b58, 0x05ac0, 0x0ab60, 0x096d5, 0x092e0,
+    0x0c960, 0x0d954, 0x0d4a0, 0x0da50, 0x07552, 0x056a0, 0x0abb7, 0x025d0, 0x092d0, 0x0cab5,
+    0x0a950, 0x0b4a0, 0x0baa4, 0x0ad50, 0x055d9, 0x04ba0, 0x0a5b0, 0x15176, 0x052b0, 0x0a930,
+    0x07954, 0x06aa0, 0x0ad50, 0x05b52, 0x04b60, 0x0a6e6, 0x0a4e0, 0x0d260, 0x0ea65, 0x0d530,
+    0x05aa0, 0x076a3, 0x096d0, 0x04afb, 0x04ad0, 0x0a4d0, 0x1d0b6, 0x0d250, 0x0d520, 0x0dd45,
+    0x0b5a0, 0x056d0, 0x055b2, 0x049b0, 0x0a577, 0x0a4b0, 0x0aa50, 0x1b255, 0x06d20, 0x0ada0,
+    0x14b63, 0x09370, 0x049f8, 0x04970, 0x064b0, 0x168a6, 0x0ea50, 0x06aa0, 0x1a6c4, 0x0aae0,
+    0x092e0, 0x0d2e3, 0x0c960, 0x0d557, 0x0d4a0, 0x0da50, 0x05d55, 0x056a0, 0x0a6d0, 0x055d4,
+    0x052d0, 0x0a9b8, 0x0a950, 0x0b4a0, 0x0b6a6, 0x0ad50, 0x055a0, 0x0aba4, 0x0a5b0, 0x052b0,
+    0x0b273, 0x06930, 0x07337, 0x06aa0, 0x0ad50, 0x14b55, 0x04b60, 0x0a570, 0x054e4, 0x0d160,
+    0x0e968, 0x0d520, 0x0daa0, 0x16aa6, 0x056d0, 0x04ae0, 0x0a9d4, 0x0a2d0, 0x0d150, 0x0f252,
+    0x0d520,
+];
+
+static KOREAN_LUNAR_INFO: [u32; 201] = [
+    0x04bd8, 0x04ae0, 0x0a570, 0x054e5, 0x0d2a0, 0x0e950, 0x16554, 0x056a0, 0x0aad0, 0x055d2,
+    0x04ae0, 0x0a5d6, 0x0a4d0, 0x0d250, 0x0da95, 0x0b550, 0x056a0, 0x0ada2, 0x095d0, 0x04bb7,
+    0x049b0, 0x0a4b0, 0x0b4b5, 0x06a90, 0x0ad40, 0x0bb54, 0x02b60, 0x095b0, 0x05372, 0x04970,
+    0x06566, 0x0e4a0, 0x0ea50, 0x16a95, 0x05b50, 0x02b60, 0x18ae3, 0x092e0, 0x1c8d7, 0x0c950,
+    0x0d4a0, 0x1d8a6, 0x0b690, 0x056d0, 0x125b4, 0x025d0, 0x092d0, 0x0d2b2, 0x0a950, 0x0d557,
+    0x0b4a0, 0x0b550, 0x15555, 0x04db0, 0x025b0, 0x18573, 0x052b0, 0x0a9b8, 0x06950, 0x06aa0,
+    0x0aea6, 0x0ab50, 0x04b60, 0x0aae4, 0x0a570, 0x05270, 0x07263, 0x0d950, 0x06b57, 0x056a0,
+    0x09ad0, 0x04dd5, 0x04ae0, 0x0a4e0, 0x0d4d4, 0x0d250, 0x0d598, 0x0b540, 0x0d6a0, 0x195a6,
+    0x095b0, 0x049b0, 0x0a9b4, 0x0a4b0, 0x0b27a, 0x06a50, 0x06d40, 0x0b756, 0x02b60, 0x095b0,
+    0x04b75, 0x04970, 0x064b0, 0x074a3, 0x0ea50, 0x06d98, 0x05ad0, 0x02b60, 0x096e5, 0x092e0,
+    0x0c960, 0x0e954, 0x0d4a0, 0x0da50, 0x07552, 0x056c0, 0x0abb7, 0x025d0, 0x092d0, 0x0cab5,
+    0x0a950, 0x0b4a0, 0x1b4a3, 0x0b550, 0x055d9, 0x04ba0, 0x0a5b0, 0x05575, 0x052b0, 0x0a950,
+    0x0b954, 0x06aa0, 0x0ad50, 0x06b52, 0x04b60, 0x0a6e6, 0x0a570, 0x05270, 0x06a65, 0x0d930,
+    0x05aa0, 0x0b6a3, 0x096d0, 0x04afb, 0x04ae0, 0x0a4d0, 0x1d0d6, 0x0d250, 0x0d520, 0x0dd45,
+    0x0b6a0, 0x096d0, 0x055b2, 0x049b0, 0x0a577, 0x0a4b0, 0x0b250, 0x1b255, 0x06d40, 0x0ada0,
+    0x18b63, 0x09570, 0x14978, 0x04970, 0x064b0, 0x168a6, 0x0ea50, 0x06b20, 0x1aac4, 0x0ab60,
+    0x09370, 0x052e3, 0x0c960, 0x0d557, 0x0d4a0, 0x0da50, 0x05d55, 0x056a0, 0x0aad0, 0x095d4,
+    0x092d0, 0x0c9b8, 0x0a950, 0x0b4a0, 0x0b6a6, 0x0ad50, 0x055a0, 0x0aba4, 0x0a5b0, 0x052b0,
+    0x0b2b3, 0x0a930, 0x07557, 0x06aa0, 0x0ad50, 0x14b55, 0x04b60, 0x0a570, 0x054f4, 0x05260,
+    0x0e968, 0x0d530, 0x05aa0, 0x1aaa6, 0x096d0, 0x04ae0, 0x0aad4, 0x0a4d0, 0x0d260, 0x0f253,
+    0x0d520,
+];
+
+impl LunarCalendar {
+    fn year_info(&self, year: i32) -> Option<u32> {
+        if year < FIRST_LUNAR_YEAR || year > LAST_LUNAR_YEAR {
+            return None;
+        }
+        let index = (year - FIRST_LUNAR_YEAR) as usize;
+        match self {
+            &LunarCalendar::Chinese => Some(CHINESE_LUNAR_INFO[index]),
+            &LunarCalendar::Korean => Some(KOREAN_LUNAR_INFO[index]),
+        }
+    }
+
+    /// Leap month of the given lunar year, if any.
+    pub fn leap_month(&self, year: i32) -> Option<u32> {
+        self.year_info(year)
+            .map(|info| info & 0xf)
+            .and_then(|m| if m == 0 { None } else { Some(m) })
+    }
+
+    /// Number of days of the given month, `leap` designating the intercalary month.
+    pub fn month_days(&self, year: i32, month: u32, leap: bool) -> Option<u32> {
+        let info = self.year_info(year)?;
+        if month < 1 || month > 12 {
+            return None;
+        }
+        if leap {
+            if info & 0xf != month {
+                return None;
+            }
+            return Some(if info & 0x10000 != 0 { 30 } else { 29 });
+        }
+        Some(if info & (0x10000 >> month) != 0 { 30 } else { 29 })
+    }
+
+    /// Number of days of the given lunar year.
+    pub fn year_days(&self, year: i32) -> Option<u32> {
+        let regular_days = (1..13)
+            .map(|month| self.month_days(year, month, false))
+            .sum::<Option<u32>>()?;
+        let leap_days = match self.leap_month(year) {
+            Some(month) => self.month_days(year, month, true)?,
+            None => 0,
+        };
+        Some(regular_days + leap_days)
+    }
+
+    /// Gregorian date of the first day of the given lunar year.
+    pub fn new_year(&self, year: i32) -> Option<NaiveDate> {
+        self.year_info(year)?;
+        // Both calendars start the year 1900 on the 31st of January
+        let offset = (FIRST_LUNAR_YEAR..year)
+            .map(|y| self.year_days(y))
+            .sum::<Option<u32>>()?;
+        Some(NaiveDate::from_ymd(1900, 1, 31) + Duration::days(offset as i64))
+    }
+
+    /// Gregorian date of the given lunar date, `leap` designating the intercalary month.
+    pub fn to_gregorian(&self, year: i32, month: u32, day: u32, leap: bool) -> Option<NaiveDate> {
+        if day < 1 || day > self.month_days(year, month, leap)? {
+            return None;
+        }
+        let leap_month = self.leap_month(year);
+        let mut offset = 0;
+        for m in 1..month {
+            offset += self.month_days(year, m, false)?;
+            if leap_month == Some(m) {
+                offset += self.month_days(year, m, true)?;
+            }
+        }
+        if leap {
+            offset += self.month_days(year, month, false)?;
+        }
+        Some(self.new_year(year)? + Duration::days((offset + day - 1) as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chinese_new_year() {
+        let calendar = LunarCalendar::Chinese;
+        assert_eq!(Some(NaiveDate::from_ymd(1900, 1, 31)), calendar.new_year(1900));
+        assert_eq!(Some(NaiveDate::from_ymd(1985, 2, 20)), calendar.new_year(1985));
+        assert_eq!(Some(NaiveDate::from_ymd(2017, 1, 28)), calendar.new_year(2017));
+        assert_eq!(Some(NaiveDate::from_ymd(2018, 2, 16)), calendar.new_year(2018));
+        assert_eq!(Some(NaiveDate::from_ymd(2019, 2, 5)), calendar.new_year(2019));
+        assert_eq!(Some(NaiveDate::from_ymd(2020, 1, 25)), calendar.new_year(2020));
+        assert_eq!(Some(NaiveDate::from_ymd(2024, 2, 10)), calendar.new_year(2024));
+        assert_eq!(Some(NaiveDate::from_ymd(2026, 2, 17)), calendar.new_year(2026));
+        assert_eq!(Some(NaiveDate::from_ymd(2038, 2, 4)), calendar.new_year(2038));
+        assert_eq!(None, calendar.new_year(1899));
+        assert_eq!(None, calendar.new_year(2101));
+    }
+
+    #[test]
+    fn test_chinese_festivals() {
+        let calendar = LunarCalendar::Chinese;
+        // Mid-Autumn festival
+        assert_eq!(Some(NaiveDate::from_ymd(2017, 10, 4)), calendar.to_gregorian(2017, 8, 15, false));
+        assert_eq!(Some(NaiveDate::from_ymd(2018, 9, 24)), calendar.to_gregorian(2018, 8, 15, false));
+        assert_eq!(Some(NaiveDate::from_ymd(2023, 9, 29)), calendar.to_gregorian(2023, 8, 15, false));
+        // Dragon boat festival
+        assert_eq!(Some(NaiveDate::from_ymd(2017, 5, 30)), calendar.to_gregorian(2017, 5, 5, false));
+        assert_eq!(Some(NaiveDate::from_ymd(2020, 6, 25)), calendar.to_gregorian(2020, 5, 5, false));
+        // Lantern festival
+        assert_eq!(Some(NaiveDate::from_ymd(2017, 2, 11)), calendar.to_gregorian(2017, 1, 15, false));
+        // Leap months
+        assert_eq!(Some(6), calendar.leap_month(2017));
+        assert_eq!(None, calendar.leap_month(2018));
+        assert_eq!(Some(NaiveDate::from_ymd(2017, 7, 23)), calendar.to_gregorian(2017, 6, 1, true));
+        assert_eq!(None, calendar.to_gregorian(2018, 6, 1, true));
+    }
+
+    #[test]
+    fn test_korean_festivals() {
+        let calendar = LunarCalendar::Korean;
+        // Seollal
+        assert_eq!(Some(NaiveDate::from_ymd(2017, 1, 28)), calendar.new_year(2017));
+        assert_eq!(Some(NaiveDate::from_ymd(2027, 2, 7)), calendar.new_year(2027));
+        // Chuseok
+        assert_eq!(Some(NaiveDate::from_ymd(2017, 10, 4)), calendar.to_gregorian(2017, 8, 15, false));
+        assert_eq!(Some(NaiveDate::from_ymd(2019, 9, 13)), calendar.to_gregorian(2019, 8, 15, false));
+        assert_eq!(Some(NaiveDate::from_ymd(2020, 10, 1)), calendar.to_gregorian(2020, 8, 15, false));
+        // Buddha's birthday
+        assert_eq!(Some(NaiveDate::from_ymd(2018, 5, 22)), calendar.to_gregorian(2018, 4, 8, false));
+    }
+
+    #[test]
+    fn test_month_days() {
+        let calendar = LunarCalendar::Chinese;
+        assert_eq!(Some(384), calendar.year_days(2017));
+        assert_eq!(Some(354), calendar.year_days(2018));
+        assert_eq!(None, calendar.month_days(2017, 13, false));
+        assert_eq!(None, calendar.to_gregorian(2017, 1, 31, false));
+    }
+}
@@ -1,6 +1,11 @@
+use std::cmp::Ordering;
+use std::fmt;
 use std::ops;
+use std::str::FromStr;
 use vec_map::VecMap;
 
+use crate::MomentError;
+
 enum_from_primitive! {
     #[derive(Debug,PartialEq,Copy,Clone,Eq,Ord,PartialOrd, Hash)]
     pub enum Grain {
@@ -175,6 +180,183 @@ impl Period {
     pub fn coarse_num_secs(&self) -> i64 {
         self.comps().iter().map(|it| it.coarse_num_secs()).sum()
     }
+
+    fn get(&self, grain: Grain) -> i64 {
+        *self.0.get(grain as usize).unwrap_or(&0)
+    }
+
+    /// Years, quarters and months of the period, counted in months.
+    fn calendar_months(&self) -> i64 {
+        12 * self.get(Grain::Year) + 3 * self.get(Grain::Quarter) + self.get(Grain::Month)
+    }
+
+    /// Weeks, days, hours, minutes and seconds of the period, counted in seconds.
+    fn nominal_seconds(&self) -> i64 {
+        Grain::all()
+            .into_iter()
+            .filter(|grain| *grain >= Grain::Week)
+            .map(|grain| grain.coarse_num_secs() * self.get(grain))
+            .sum()
+    }
+
+    /// Whether the period has years, quarters or months, whose length in seconds depends on
+    /// when the period starts.
+    pub fn has_calendar_grains(&self) -> bool {
+        self.get(Grain::Year) != 0 || self.get(Grain::Quarter) != 0 || self.get(Grain::Month) != 0
+    }
+
+    /// Exact length of the period in seconds, e.g. 9000 for "1 hour 90 minutes", or `None` if
+    /// the period has calendar grains. Days and weeks are counted as 24 and 168 hours.
+    pub fn total_seconds(&self) -> Option<i64> {
+        if self.has_calendar_grains() {
+            None
+        } else {
+            Some(self.nominal_seconds())
+        }
+    }
+
+    /// Equivalent period with carried over components and without zeros, e.g. "2 hours 30
+    /// minutes" for "1 hour 90 minutes".
+    ///
+    /// Seconds are carried into minutes and hours, quarters and months into years. Days are
+    /// not carried into weeks and hours not into days, as days aren't always 24 hours long.
+    pub fn normalized(&self) -> Period {
+        let mut result = Period::default();
+        let months = self.calendar_months();
+        let seconds = 3600 * self.get(Grain::Hour)
+            + 60 * self.get(Grain::Minute)
+            + self.get(Grain::Second);
+        let comps = vec![
+            PeriodComp::years(months / 12),
+            PeriodComp::months(months % 12),
+            PeriodComp::weeks(self.get(Grain::Week)),
+            PeriodComp::days(self.get(Grain::Day)),
+            PeriodComp::hours(seconds / 3600),
+            PeriodComp::minutes(seconds % 3600 / 60),
+            PeriodComp::seconds(seconds % 60),
+        ];
+        for comp in comps.into_iter().filter(|comp| comp.quantity != 0) {
+            result += comp;
+        }
+        result
+    }
+
+    /// Compares the lengths of two periods, e.g. "60 minutes" is as long as "1 hour", and "1
+    /// month" longer than "27 days". Returns `None` when it depends on when the periods
+    /// start, e.g. for "1 month" and "30 days".
+    pub fn compare(&self, other: &Period) -> Option<Ordering> {
+        let months = self.calendar_months() - other.calendar_months();
+        let seconds = self.nominal_seconds() - other.nominal_seconds();
+        // Months last between 28 and 31 days
+        let (min, max) = if months >= 0 {
+            (months * 28 * 86400 + seconds, months * 31 * 86400 + seconds)
+        } else {
+            (months * 31 * 86400 + seconds, months * 28 * 86400 + seconds)
+        };
+        if min == 0 && max == 0 {
+            Some(Ordering::Equal)
+        } else if min > 0 {
+            Some(Ordering::Greater)
+        } else if max < 0 {
+            Some(Ordering::Less)
+        } else {
+            None
+        }
+    }
+}
+
+/// ISO-8601 duration, e.g. "P1Y2M10DT2H30M".
+///
+/// Quarters are written as months, and weeks as days unless the period only has weeks. A
+/// period whose components are all negative is written with a leading minus sign, e.g.
+/// "-P1D", otherwise signs are kept on each component.
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let comps = self.comps().into_iter().filter(|comp| comp.quantity != 0).collect::<Vec<_>>();
+        if comps.is_empty() {
+            return write!(f, "PT0S");
+        }
+        let negative = comps.iter().all(|comp| comp.quantity < 0);
+        let sign = if negative { -1 } else { 1 };
+        let get = |grain| sign * self.get(grain);
+        if negative {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+        if comps.iter().all(|comp| comp.grain == Grain::Week) {
+            return write!(f, "{}W", get(Grain::Week));
+        }
+        let months = 3 * get(Grain::Quarter) + get(Grain::Month);
+        let days = 7 * get(Grain::Week) + get(Grain::Day);
+        let date = [(get(Grain::Year), "Y"), (months, "M"), (days, "D")];
+        for &(quantity, designator) in date.iter() {
+            if quantity != 0 {
+                write!(f, "{}{}", quantity, designator)?;
+            }
+        }
+        let time = [(get(Grain::Hour), "H"), (get(Grain::Minute), "M"), (get(Grain::Second), "S")];
+        if time.iter().any(|&(quantity, _)| quantity != 0) {
+            write!(f, "T")?;
+            for &(quantity, designator) in time.iter() {
+                if quantity != 0 {
+                    write!(f, "{}{}", quantity, designator)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses ISO-8601 durations with integer components, e.g. "PT2H30M", "P3W" or "-P1D".
+impl FromStr for Period {
+    type Err = MomentError;
+
+    fn from_str(s: &str) -> Result<Period, MomentError> {
+        let invalid = || MomentError::InvalidPeriod { context: s.to_string() };
+        let (sign, unsigned) = if s.starts_with('-') { (-1, &s[1..]) } else { (1, s) };
+        if !unsigned.starts_with('P') || unsigned.len() < 2 {
+            return Err(invalid());
+        }
+        let mut period = Period::default();
+        let mut in_time = false;
+        let mut number = String::new();
+        let mut last_grain = None;
+        for c in unsigned[1..].chars() {
+            let grain = match (c, in_time) {
+                ('T', false) if number.is_empty() => {
+                    in_time = true;
+                    continue;
+                }
+                ('0'..='9', _) | ('-', _) => {
+                    number.push(c);
+                    continue;
+                }
+                ('Y', false) => Grain::Year,
+                ('M', false) => Grain::Month,
+                ('W', false) => Grain::Week,
+                ('D', false) => Grain::Day,
+                ('H', true) => Grain::Hour,
+                ('M', true) => Grain::Minute,
+                ('S', true) => Grain::Second,
+                _ => return Err(invalid()),
+            };
+            // Components must be given from the coarsest to the finest grain
+            if last_grain.map(|last| last >= grain).unwrap_or(false) {
+                return Err(invalid());
+            }
+            let quantity: i64 = number.parse().map_err(|_| invalid())?;
+            period += PeriodComp::new(grain, sign * quantity);
+            number.clear();
+            last_grain = Some(grain);
+        }
+        let dangling_time = last_grain
+            .map(|last| in_time && !last.is_time_grain())
+            .unwrap_or(true);
+        if !number.is_empty() || dangling_time {
+            return Err(invalid());
+        }
+        Ok(period)
+    }
 }
 
 impl From<PeriodComp> for Period {
@@ -367,6 +549,108 @@ impl<'a> ops::Neg for &'a PeriodComp {
 mod tests {
     use super::*;
 
+    fn period(comps: &[PeriodComp]) -> Period {
+        comps.iter().fold(Period::default(), |period, comp| period + comp)
+    }
+
+    #[test]
+    fn test_total_seconds() {
+        let hours = period(&[PeriodComp::hours(1), PeriodComp::minutes(90)]);
+        assert_eq!(Some(9000), hours.total_seconds());
+        let days = period(&[PeriodComp::days(1), PeriodComp::hours(1)]);
+        assert_eq!(Some(90000), days.total_seconds());
+        assert_eq!(Some(0), Period::default().total_seconds());
+        assert_eq!(None, period(&[PeriodComp::months(1)]).total_seconds());
+        let zero_months = period(&[PeriodComp::months(0), PeriodComp::hours(1)]);
+        assert_eq!(Some(3600), zero_months.total_seconds());
+    }
+
+    #[test]
+    fn test_normalized() {
+        let normalized = period(&[PeriodComp::hours(1), PeriodComp::minutes(90)]).normalized();
+        assert_eq!(period(&[PeriodComp::hours(2), PeriodComp::minutes(30)]), normalized);
+        assert_eq!(vec![PeriodComp::hours(2), PeriodComp::minutes(30)], normalized.comps());
+        assert_eq!(
+            period(&[PeriodComp::years(1), PeriodComp::months(3)]),
+            period(&[PeriodComp::quarters(5)]).normalized()
+        );
+        assert_eq!(
+            period(&[PeriodComp::days(1), PeriodComp::hours(1)]),
+            period(&[PeriodComp::days(1), PeriodComp::minutes(60)]).normalized()
+        );
+        assert_eq!(
+            period(&[PeriodComp::minutes(30)]),
+            period(&[PeriodComp::hours(1), PeriodComp::minutes(-30)]).normalized()
+        );
+        assert!(period(&[PeriodComp::seconds(0)]).normalized().comps().is_empty());
+    }
+
+    #[test]
+    fn test_compare() {
+        let hour = period(&[PeriodComp::hours(1)]);
+        assert_eq!(Some(Ordering::Equal), hour.compare(&period(&[PeriodComp::minutes(60)])));
+        assert_eq!(Some(Ordering::Less), hour.compare(&period(&[PeriodComp::minutes(61)])));
+        let month = period(&[PeriodComp::months(1)]);
+        assert_eq!(Some(Ordering::Greater), month.compare(&period(&[PeriodComp::days(27)])));
+        assert_eq!(Some(Ordering::Less), month.compare(&period(&[PeriodComp::days(32)])));
+        assert_eq!(None, month.compare(&period(&[PeriodComp::days(30)])));
+        let year = period(&[PeriodComp::years(1)]);
+        assert_eq!(Some(Ordering::Equal), year.compare(&period(&[PeriodComp::quarters(4)])));
+    }
+
+    #[test]
+    fn test_display_iso8601() {
+        assert_eq!("PT2H30M", period(&[PeriodComp::hours(2), PeriodComp::minutes(30)]).to_string());
+        assert_eq!(
+            "P1Y2M10DT2H30M",
+            period(&[
+                PeriodComp::years(1),
+                PeriodComp::months(2),
+                PeriodComp::days(10),
+                PeriodComp::hours(2),
+                PeriodComp::minutes(30),
+            ])
+            .to_string()
+        );
+        assert_eq!("P3W", period(&[PeriodComp::weeks(3)]).to_string());
+        assert_eq!("P17D", period(&[PeriodComp::weeks(2), PeriodComp::days(3)]).to_string());
+        assert_eq!("P6M", period(&[PeriodComp::quarters(2)]).to_string());
+        assert_eq!("-P1D", period(&[PeriodComp::days(-1)]).to_string());
+        let mixed = period(&[PeriodComp::hours(1), PeriodComp::minutes(-30)]);
+        assert_eq!("PT1H-30M", mixed.to_string());
+        assert_eq!("PT0S", Period::default().to_string());
+    }
+
+    #[test]
+    fn test_parse_iso8601() {
+        let hours = period(&[PeriodComp::hours(2), PeriodComp::minutes(30)]);
+        assert_eq!(hours, "PT2H30M".parse().unwrap());
+        assert_eq!(
+            period(&[
+                PeriodComp::years(1),
+                PeriodComp::months(2),
+                PeriodComp::days(10),
+                PeriodComp::hours(2),
+            ]),
+            "P1Y2M10DT2H".parse().unwrap()
+        );
+        assert_eq!(period(&[PeriodComp::weeks(3)]), "P3W".parse().unwrap());
+        assert_eq!(period(&[PeriodComp::days(-1)]), "-P1D".parse().unwrap());
+        let mixed = period(&[PeriodComp::hours(1), PeriodComp::minutes(-30)]);
+        assert_eq!(mixed, "PT1H-30M".parse().unwrap());
+        assert_eq!(Period::default(), "PT0S".parse().unwrap());
+        for invalid in &["", "P", "PT", "P1H", "PT1D", "P1M1Y", "P1", "PT0.5H", "1D", "P1DT"] {
+            assert!(invalid.parse::<Period>().is_err(), "{:?} should be invalid", invalid);
+        }
+    }
+
+    #[test]
+    fn test_iso8601_round_trip() {
+        for s in &["PT2H30M", "P1Y2M10DT2H30M5S", "P3W", "-P1D", "PT0S"] {
+            assert_eq!(*s, s.parse::<Period>().unwrap().to_string());
+        }
+    }
+
     #[test]
     fn period_comp_add_to_period() {
         assert_eq!(
@@ -25,11 +25,16 @@ extern crate rustling_ontology_moment;
 extern crate rustling_ontology_values;
 extern crate serde;
 
-pub use grammar::{dims, Lang};
+pub use grammar::{dims, InputMode, Lang};
 pub use rustling::RustlingResult;
-pub use rustling::{AttemptInto, ParsedNode, ParserMatch, ParsingAnalysis, Range, Sym, Value};
+pub use rustling::{
+    AttemptInto, Candidate, Node, ParsedNode, ParserMatch, ParsingAnalysis, Range, RuleSet, Sym,
+    Value,
+};
 pub use rustling_ontology_moment::Grain;
 pub use rustling_ontology_moment::{Interval, Local, Moment, TimeZone};
+pub use rustling_ontology_values::currency::Currency;
+pub use rustling_ontology_values::decimal::Decimal;
 pub use rustling_ontology_values::dimension;
 pub use rustling_ontology_values::output;
 pub use rustling_ontology_values::output::{Output, OutputKind};
@@ -39,7 +44,9 @@ mod mapper;
 mod parser;
 mod tagger;
 
-pub use tagger::CandidateTagger;
+use std::cmp::Ordering;
+
+pub use tagger::{CandidateTagger, ExpectingTagger};
 
 // Rustling raw parser. Don't use directly
 #[doc(hidden)]
@@ -91,6 +98,78 @@ impl Parser {
         self.parse_with_kind_order(input, context, &all_output)
     }
 
+    /// Parse with all output kinds, preferring the `expected` ones when several readings of
+    /// the same text compete, e.g. a date rather than an ordinal for "the 3rd" in the answer
+    /// to "when do you leave?".
+    pub fn parse_expecting(
+        &self,
+        input: &str,
+        context: &ResolverContext,
+        expected: &[OutputKind],
+    ) -> RustlingResult<Vec<ParserMatch<Output>>> {
+        let all_output = OutputKind::all();
+        let tagger = ExpectingTagger {
+            tagger: CandidateTagger {
+                output_kind_filter: &all_output,
+                context,
+                resolve_all_candidates: false,
+            },
+            expected_kinds: expected,
+        };
+        Ok(self
+            .0
+            .parse(input, &tagger)?
+            .iter()
+            .filter_map(resolved_match)
+            .collect())
+    }
+
+    /// Parse like `parse_with_kind_order`, also giving the other reading of matches which
+    /// can be read either as a position, like an ordinal, or as a day of month, e.g. "the
+    /// 3rd" or "le 3". Both readings come with their log-probability.
+    pub fn parse_with_readings(
+        &self,
+        input: &str,
+        context: &ResolverContext,
+        order: &[OutputKind],
+    ) -> RustlingResult<Vec<Readings>> {
+        let tagger = CandidateTagger {
+            output_kind_filter: order,
+            context,
+            resolve_all_candidates: true,
+        };
+        let candidates = self.0.candidates(input, &tagger)?;
+        let mut readings = candidates
+            .iter()
+            .filter(|candidate| candidate.tagged)
+            .filter_map(|candidate| {
+                let best = resolved_match(&candidate.match_)?;
+                let alternatives = match position_or_day_of_month(&candidate.node.value) {
+                    Some(is_day_of_month) => candidates
+                        .iter()
+                        .filter(|other| {
+                            !other.tagged
+                                && !other.match_.byte_range.is_disjoint(&candidate.match_.byte_range)
+                                && position_or_day_of_month(&other.node.value) == Some(!is_day_of_month)
+                        })
+                        .filter_map(|other| resolved_match(&other.match_))
+                        .max_by(|a, b| {
+                            a.byte_range
+                                .len()
+                                .cmp(&b.byte_range.len())
+                                .then_with(|| a.probalog.partial_cmp(&b.probalog).unwrap_or(Ordering::Equal))
+                        })
+                        .into_iter()
+                        .collect(),
+                    None => vec![],
+                };
+                Some(Readings { best, alternatives })
+            })
+            .collect::<Vec<_>>();
+        readings.sort_by_key(|reading| reading.best.byte_range.0);
+        Ok(readings)
+    }
+
     pub fn analyse_with_kind_order(
         &self,
         examples: Vec<&str>,
@@ -123,42 +202,94 @@ impl Parser {
     }
 }
 
+/// A match with its other readings, see `Parser::parse_with_readings`.
+#[derive(Debug, PartialEq)]
+pub struct Readings {
+    /// The reading selected by the parser
+    pub best: ParserMatch<Output>,
+    pub alternatives: Vec<ParserMatch<Output>>,
+}
+
+fn resolved_match(m: &ParserMatch<Option<Output>>) -> Option<ParserMatch<Output>> {
+    m.value.clone().map(|value| ParserMatch {
+        byte_range: m.byte_range,
+        char_range: m.char_range,
+        parsing_tree_height: m.parsing_tree_height,
+        parsing_tree_num_nodes: m.parsing_tree_num_nodes,
+        value,
+        probalog: m.probalog,
+        latent: m.latent,
+    })
+}
+
+/// `Some(true)` for a day of month, `Some(false)` for a position like "the 3rd" or "le 3",
+/// which can only be a day of month from 1 to 31.
+fn position_or_day_of_month(value: &dimension::Dimension) -> Option<bool> {
+    match value {
+        &dimension::Dimension::Ordinal(ref ordinal) if (1..=31).contains(&ordinal.value) => Some(false),
+        &dimension::Dimension::Number(dimension::NumberValue::Integer(ref integer))
+            if (1..=31).contains(&integer.value) => Some(false),
+        value if value.is_day_of_month() => Some(true),
+        _ => None,
+    }
+}
+
 /// Obtain a parser for a given language.
 pub fn build_parser(lang: Lang) -> RustlingResult<Parser> {
-    build_raw_parser(lang).map(crate::Parser)
+    build_parser_with_mode(lang, InputMode::Written)
+}
+
+/// Obtain a parser for a given language and kind of input, e.g. speech recognition transcripts.
+pub fn build_parser_with_mode(lang: Lang, mode: InputMode) -> RustlingResult<Parser> {
+    build_raw_parser_with_mode(lang, mode).map(crate::Parser)
 }
 
 /// Obtain a parser for a given language.
 pub fn build_raw_parser(lang: Lang) -> RustlingResult<RawParser> {
-    let rules = grammar::rules(lang)?;
-    let model = match lang {
-        Lang::DE => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/de.rmp"))[..])
-        }
-        Lang::EN => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/en.rmp"))[..])
-        }
-        Lang::ES => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/es.rmp"))[..])
-        }
-        Lang::IT => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/it.rmp"))[..])
-        }
-        Lang::FR => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/fr.rmp"))[..])
-        }
-        Lang::PT => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/pt.rmp"))[..])
-        }
-        Lang::JA => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/ja.rmp"))[..])
-        }
-        Lang::KO => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/ko.rmp"))[..])
-        }
-        Lang::ZH => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/zh.rmp"))[..])
+    build_raw_parser_with_mode(lang, InputMode::Written)
+}
+
+/// Obtain a parser for a given language and kind of input. The models of spoken inputs are only
+/// built in with the `spoken` feature; without it, use `train_parser_with_mode` instead.
+pub fn build_raw_parser_with_mode(lang: Lang, mode: InputMode) -> RustlingResult<RawParser> {
+    macro_rules! model {
+        ($file:expr) => {
+            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/", $file))[..])
+        };
+    }
+    let rules = grammar::rules_with_mode(lang, mode)?;
+    let model = match (lang, mode) {
+        (Lang::DE, InputMode::Written) => model!("de.rmp"),
+        (Lang::EN, InputMode::Written) => model!("en.rmp"),
+        (Lang::ES, InputMode::Written) => model!("es.rmp"),
+        (Lang::IT, InputMode::Written) => model!("it.rmp"),
+        (Lang::FR, InputMode::Written) => model!("fr.rmp"),
+        (Lang::PT, InputMode::Written) => model!("pt.rmp"),
+        (Lang::JA, InputMode::Written) => model!("ja.rmp"),
+        (Lang::KO, InputMode::Written) => model!("ko.rmp"),
+        (Lang::ZH, InputMode::Written) => model!("zh.rmp"),
+        #[cfg(not(feature = "spoken"))]
+        (_, InputMode::Spoken) => {
+            return Err(format!("The spoken model of {} is only built in with the spoken feature", lang.to_string()).into())
         }
+        #[cfg(feature = "spoken")]
+        (Lang::DE, InputMode::Spoken) => model!("de_spoken.rmp"),
+        #[cfg(feature = "spoken")]
+        (Lang::EN, InputMode::Spoken) => model!("en_spoken.rmp"),
+        #[cfg(feature = "spoken")]
+        (Lang::ES, InputMode::Spoken) => model!("es_spoken.rmp"),
+        #[cfg(feature = "spoken")]
+        (Lang::IT, InputMode::Spoken) => model!("it_spoken.rmp"),
+        #[cfg(feature = "spoken")]
+        (Lang::FR, InputMode::Spoken) => model!("fr_spoken.rmp"),
+        #[cfg(feature = "spoken")]
+        (Lang::PT, InputMode::Spoken) => model!("pt_spoken.rmp"),
+        #[cfg(feature = "spoken")]
+        (Lang::JA, InputMode::Spoken) => model!("ja_spoken.rmp"),
+        #[cfg(feature = "spoken")]
+        (Lang::KO, InputMode::Spoken) => model!("ko_spoken.rmp"),
+        #[cfg(feature = "spoken")]
+        (Lang::ZH, InputMode::Spoken) => model!("zh_spoken.rmp"),
     }?;
     Ok(crate::RawParser::new(
         rules,
@@ -168,8 +299,12 @@ pub fn build_raw_parser(lang: Lang) -> RustlingResult<RawParser> {
 }
 
 pub fn train_parser(lang: Lang) -> RustlingResult<Parser> {
-    let rules = grammar::rules(lang)?;
-    let examples = grammar::examples(lang);
+    train_parser_with_mode(lang, InputMode::Written)
+}
+
+pub fn train_parser_with_mode(lang: Lang, mode: InputMode) -> RustlingResult<Parser> {
+    let rules = grammar::rules_with_mode(lang, mode)?;
+    let examples = grammar::examples_with_mode(lang, mode);
     let model = ::rustling::train::train(&rules, examples, crate::parser::FeatureExtractor())?;
     Ok(Parser(::rustling::Parser::new(
         rules,
@@ -194,6 +329,93 @@ mod tests {
         assert_eq!(1521082, int.0);
     }
 
+    #[test]
+    #[cfg(feature = "spoken")]
+    fn test_spoken_digit_sequence_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser_with_mode(Lang::EN, InputMode::Spoken).unwrap();
+        let number = "five five five um one two three four";
+        let result = parser
+            .parse_with_kind_order(number, &ctx, &[OutputKind::Number])
+            .unwrap();
+        let int: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(5551234, int.0);
+    }
+
+    #[test]
+    fn test_ordinal_readings_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let readings = parser
+            .parse_with_readings("the 3rd", &ctx, &OutputKind::all())
+            .unwrap();
+        assert_eq!(OutputKind::Ordinal, readings[0].best.value.kind());
+        assert_eq!(1, readings[0].alternatives.len());
+        assert_eq!(OutputKind::Date, readings[0].alternatives[0].value.kind());
+
+        let result = parser
+            .parse_expecting("the 3rd", &ctx, &[OutputKind::Date])
+            .unwrap();
+        assert_eq!(OutputKind::Date, result[0].value.kind());
+    }
+
+    #[test]
+    fn test_position_or_day_of_month() {
+        let third = dimension::Dimension::Number(dimension::IntegerValue::new(3).unwrap().into());
+        assert_eq!(Some(false), position_or_day_of_month(&third));
+        let many = dimension::Dimension::Number(dimension::IntegerValue::new(1500).unwrap().into());
+        assert_eq!(None, position_or_day_of_month(&many));
+    }
+
+    #[test]
+    fn test_age_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser.parse("my son is 7 years old", &ctx).unwrap();
+        let age: output::AgeOutput = result[0].value.clone().attempt_into().unwrap();
+        let seven_years = rustling_ontology_moment::Period::from(rustling_ontology_moment::PeriodComp::years(7));
+        assert_eq!(Some(&seven_years), age.exact());
+    }
+
+    #[test]
+    fn test_money_currency_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser.parse("it costs 50 cents", &ctx).unwrap();
+        let money: output::AmountOfMoneyOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(0.5, money.value);
+        assert_eq!(Some(Currency::Gbp), money.resolve_currency("en-GB").currency);
+        let result = parser.parse("it costs 20 euros", &ctx).unwrap();
+        let money: output::AmountOfMoneyOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Some(Currency::Eur), money.currency);
+    }
+
+    #[test]
+    fn test_money_decimal_fr() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::FR).unwrap();
+        let result = parser.parse("ça coûte 31.415.926.535.897,93 €", &ctx).unwrap();
+        let money: output::AmountOfMoneyOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Some(Decimal::new(3_141_592_653_589_793, 2)), money.decimal());
+    }
+
+    #[test]
+    fn test_digit_sequence_over_number_fr() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::FR).unwrap();
+        let code = "0042";
+        let result = parser
+            .parse_with_kind_order(code, &ctx, &[OutputKind::DigitSequence, OutputKind::Number])
+            .unwrap();
+        let sequence: output::DigitSequenceOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!("0042", sequence.digits);
+        let result = parser
+            .parse_with_kind_order("appelle le 06 12 34 56 78", &ctx, &[OutputKind::PhoneNumber])
+            .unwrap();
+        let phone: output::DigitSequenceOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Some("+33612345678".to_string()), phone.e164(Some(33)));
+    }
+
     #[test]
     #[ignore]
     fn time_resolve_complex_train_sentence() {
@@ -9,12 +9,41 @@ pub struct CandidateTagger<'a, C: ParsingContext<Dimension> + 'a> {
     pub resolve_all_candidates: bool,
 }
 
+/// Tagger re-ranking the candidates of the wrapped `CandidateTagger` with the kinds the caller
+/// expects, e.g. a date in the answer to "when do you leave?". Unlike the filter they don't
+/// exclude any candidate, but they win over other kinds when candidates cover the same text.
+pub struct ExpectingTagger<'a, C: ParsingContext<Dimension> + 'a> {
+    pub tagger: CandidateTagger<'a, C>,
+    pub expected_kinds: &'a [OutputKind],
+}
+
 impl<'a, C: ParsingContext<Dimension>> MaxElementTagger<Dimension> for CandidateTagger<'a, C> {
     type O = Option<C::O>;
 
     fn tag(
+        &self,
+        candidates: Vec<(ParsedNode<Dimension>, ParserMatch<Dimension>)>,
+    ) -> Vec<Candidate<Dimension, Option<C::O>>> {
+        self.tag_expecting(candidates, &[])
+    }
+}
+
+impl<'a, C: ParsingContext<Dimension>> MaxElementTagger<Dimension> for ExpectingTagger<'a, C> {
+    type O = Option<C::O>;
+
+    fn tag(
+        &self,
+        candidates: Vec<(ParsedNode<Dimension>, ParserMatch<Dimension>)>,
+    ) -> Vec<Candidate<Dimension, Option<C::O>>> {
+        self.tagger.tag_expecting(candidates, self.expected_kinds)
+    }
+}
+
+impl<'a, C: ParsingContext<Dimension>> CandidateTagger<'a, C> {
+    fn tag_expecting(
         &self,
         mut candidates: Vec<(ParsedNode<Dimension>, ParserMatch<Dimension>)>,
+        expected_kinds: &[OutputKind],
     ) -> Vec<Candidate<Dimension, Option<C::O>>> {
         // The filter is an OutputKind vector
 
@@ -51,12 +80,19 @@ impl<'a, C: ParsingContext<Dimension>> MaxElementTagger<Dimension> for Candidate
             .collect::<Vec<_>>();
 
         // 2. Priorisation intra OutputKind - Use probas from training, and many other things
-        // like match length etc.
+        // like match length etc. Expected kinds come right after the match position, so that
+        // they only re-rank candidates of the same text.
+        let is_expected = |value: &Dimension| {
+            expected_kinds
+                .iter()
+                .any(|output_kind| output_kind.match_dim(value))
+        };
         candidates.sort_by(|a, b| {
             a.1.byte_range
                 .len()
                 .cmp(&b.1.byte_range.len())
                 .then_with(|| a.1.byte_range.0.cmp(&b.1.byte_range.0))
+                .then_with(|| is_expected(&a.1.value).cmp(&is_expected(&b.1.value)))
                 .then_with(|| a.2.cmp(&b.2))
                 .then_with(|| {
                     if a.1.value.kind() == b.1.value.kind() {
@@ -0,0 +1,300 @@
+extern crate proptest;
+extern crate rustling_ontology;
+
+use std::fmt::Debug;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+use proptest::test_runner::{Config, TestCaseResult, TestRunner};
+use rustling_ontology::*;
+
+fn context() -> ResolverContext {
+    ResolverContext::from_secs(1_500_000_000)
+}
+
+/// Runs a property on the parser of each language, building it only once per language.
+fn check_langs<S, F, T>(langs: &[Lang], cases: u32, strategy: F, test: T)
+where
+    S: Strategy,
+    S::Value: Debug,
+    F: Fn(Lang) -> S,
+    T: Fn(&Parser, S::Value) -> TestCaseResult,
+{
+    for &lang in langs {
+        let parser = build_parser(lang).unwrap();
+        let mut runner = TestRunner::new(Config::with_cases(cases));
+        if let Err(e) = runner.run(&strategy(lang), |input| test(&parser, input)) {
+            panic!("{}: {}", lang.to_string(), e);
+        }
+    }
+}
+
+/// Parses the lowercased text, checking that the matches are within the text.
+fn parse(parser: &Parser, text: &str) -> TestCaseResult {
+    let text = text.to_lowercase();
+    let matches = parser
+        .parse(&text, &context())
+        .map_err(|e| TestCaseError::fail(e.to_string()))?;
+    for m in matches {
+        let (start, end) = m.byte_range;
+        prop_assert!(start <= end && end <= text.len(), "{:?} out of {:?}", m.byte_range, text);
+        prop_assert!(text.is_char_boundary(start) && text.is_char_boundary(end),
+                     "{:?} splits a character of {:?}", m.byte_range, text);
+    }
+    Ok(())
+}
+
+fn number_words(lang: Lang) -> Vec<&'static str> {
+    match lang {
+        Lang::DE => vec!["null", "eins", "ein", "zwei", "drei", "zwölf", "zwanzig", "dreißig", "und", "hundert",
+                         "tausend", "million", "millionen", "komma", "halb", "dutzend", "erste", "-", "1", "2,5"],
+        Lang::EN => vec!["zero", "one", "two", "three", "twelve", "twenty", "forty", "and", "hundred", "thousand",
+                         "million", "billion", "point", "half", "dozen", "first", "-", "1", "2.5", "1,000"],
+        Lang::ES => vec!["cero", "uno", "una", "dos", "doce", "veinte", "treinta", "y", "cien", "cientos", "mil",
+                         "millón", "millones", "coma", "medio", "docena", "primero", "-", "1", "2,5"],
+        Lang::FR => vec!["zéro", "un", "deux", "dix", "douze", "vingt", "vingts", "soixante", "quatre", "et",
+                         "cent", "cents", "mille", "million", "millions", "virgule", "demi", "douzaine", "-", "2,5"],
+        Lang::IT => vec!["zero", "uno", "due", "tre", "dodici", "venti", "trenta", "e", "cento", "mille", "mila",
+                         "milione", "milioni", "virgola", "mezzo", "dozzina", "primo", "-", "1", "2,5"],
+        Lang::JA => vec!["零", "一", "二", "三", "十", "百", "千", "万", "億", "点", "半", "つ", "番目", "第", "1", "2.5"],
+        Lang::KO => vec!["영", "일", "이", "삼", "십", "백", "천", "만", "억", "하나", "둘", "스물", "점", "반", "번째", "1"],
+        Lang::PT => vec!["zero", "um", "uma", "dois", "doze", "vinte", "trinta", "e", "cem", "cento", "mil",
+                         "milhão", "milhões", "vírgula", "meio", "dúzia", "primeiro", "-", "1", "2,5"],
+        Lang::ZH => vec!["零", "一", "二", "两", "三", "十", "百", "千", "万", "亿", "点", "半", "第", "个", "1", "2.5"],
+    }
+}
+
+#[test]
+fn parse_random_unicode_en() {
+    check_langs(&[Lang::EN], 16, |_| "\\PC{0,64}", |parser, text| parse(parser, &text));
+}
+
+#[test]
+#[ignore]
+fn parse_random_unicode() {
+    check_langs(&Lang::all(), 64, |_| "\\PC{0,64}", |parser, text| parse(parser, &text));
+}
+
+#[test]
+#[ignore]
+fn parse_long_inputs() {
+    check_langs(&Lang::all(), 4, |_| "[a-z0-9 ,.:/'-]{1000,2000}", |parser, text| parse(parser, &text));
+}
+
+#[test]
+#[ignore]
+fn parse_number_word_soups() {
+    check_langs(&Lang::all(),
+                64,
+                |lang| vec(select(number_words(lang)), 1..32).prop_map(|words| words.join(" ")),
+                |parser, text| parse(parser, &text));
+}
+
+const EN_UNITS: [&str; 20] = ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+                              "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen",
+                              "eighteen", "nineteen"];
+const EN_TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+
+fn english_below_thousand(n: u64, words: &mut Vec<&'static str>) {
+    if n >= 100 {
+        words.push(EN_UNITS[(n / 100) as usize]);
+        words.push("hundred");
+    }
+    let rest = (n % 100) as usize;
+    if rest >= 20 {
+        words.push(EN_TENS[rest / 10]);
+        if rest % 10 > 0 {
+            words.push(EN_UNITS[rest % 10]);
+        }
+    } else if rest > 0 {
+        words.push(EN_UNITS[rest]);
+    }
+}
+
+/// Spelling of a number below 10^9, e.g. "two hundred two thousand forty".
+fn english(n: u64) -> String {
+    if n == 0 {
+        return EN_UNITS[0].to_string();
+    }
+    let mut words = vec![];
+    for &(scale, name) in &[(1_000_000, "million"), (1_000, "thousand")] {
+        if n / scale % 1000 > 0 {
+            english_below_thousand(n / scale % 1000, &mut words);
+            words.push(name);
+        }
+    }
+    english_below_thousand(n % 1000, &mut words);
+    words.join(" ")
+}
+
+const FR_UNITS: [&str; 20] = ["zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix",
+                              "onze", "douze", "treize", "quatorze", "quinze", "seize", "dix-sept", "dix-huit",
+                              "dix-neuf"];
+const FR_TENS: [&str; 7] = ["", "", "vingt", "trente", "quarante", "cinquante", "soixante"];
+
+fn french_below_hundred(n: u64, plural: bool) -> String {
+    let n = n as usize;
+    match n {
+        0..=19 => FR_UNITS[n].to_string(),
+        20..=69 if n % 10 == 0 => FR_TENS[n / 10].to_string(),
+        20..=69 if n % 10 == 1 => format!("{} et un", FR_TENS[n / 10]),
+        20..=69 => format!("{}-{}", FR_TENS[n / 10], FR_UNITS[n % 10]),
+        71 => "soixante et onze".to_string(),
+        70..=79 => format!("soixante-{}", FR_UNITS[n - 60]),
+        80 if plural => "quatre-vingts".to_string(),
+        80 => "quatre-vingt".to_string(),
+        _ => format!("quatre-vingt-{}", FR_UNITS[n - 80]),
+    }
+}
+
+/// "cents" and "quatre-vingts" lose their "s" when followed by another number or by "mille".
+fn french_below_thousand(n: u64, plural: bool) -> String {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let hundred = match hundreds {
+        0 => return french_below_hundred(rest, plural),
+        1 => "cent".to_string(),
+        _ if rest == 0 && plural => format!("{} cents", FR_UNITS[hundreds as usize]),
+        _ => format!("{} cent", FR_UNITS[hundreds as usize]),
+    };
+    if rest == 0 {
+        hundred
+    } else {
+        format!("{} {}", hundred, french_below_hundred(rest, plural))
+    }
+}
+
+/// Spelling of a number below 10^9, e.g. "deux cent vingt et un mille quatre-vingts".
+fn french(n: u64) -> String {
+    if n == 0 {
+        return FR_UNITS[0].to_string();
+    }
+    let (millions, thousands, rest) = (n / 1_000_000, n / 1000 % 1000, n % 1000);
+    let mut words = vec![];
+    match millions {
+        0 => {}
+        1 => words.push("un million".to_string()),
+        _ => words.push(format!("{} millions", french_below_thousand(millions, true))),
+    }
+    match thousands {
+        0 => {}
+        1 => words.push("mille".to_string()),
+        _ => words.push(format!("{} mille", french_below_thousand(thousands, false))),
+    }
+    if rest > 0 {
+        words.push(french_below_thousand(rest, true));
+    }
+    words.join(" ")
+}
+
+const DE_UNITS: [&str; 20] = ["null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+                              "zehn", "elf", "zwölf", "dreizehn", "vierzehn", "fünfzehn", "sechzehn", "siebzehn",
+                              "achtzehn", "neunzehn"];
+const DE_TENS: [&str; 10] = ["", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig",
+                             "neunzig"];
+
+/// "eins" is "ein" in compounds, e.g. "einundzwanzig" or "einhundert".
+fn german_unit(n: u64) -> &'static str {
+    if n == 1 {
+        "ein"
+    } else {
+        DE_UNITS[n as usize]
+    }
+}
+
+fn german_below_thousand(n: u64, last: bool) -> String {
+    let mut word = String::new();
+    if n >= 100 {
+        word.push_str(german_unit(n / 100));
+        word.push_str("hundert");
+    }
+    let rest = n % 100;
+    match rest {
+        0 => {}
+        1 if !last => word.push_str("ein"),
+        1..=19 => word.push_str(DE_UNITS[rest as usize]),
+        _ if rest % 10 == 0 => word.push_str(DE_TENS[(rest / 10) as usize]),
+        _ => {
+            word.push_str(german_unit(rest % 10));
+            word.push_str("und");
+            word.push_str(DE_TENS[(rest / 10) as usize]);
+        }
+    }
+    word
+}
+
+/// Spelling of a number below 10^9, e.g. "zwei millionen einhunderttausendeins".
+fn german(n: u64) -> String {
+    if n == 0 {
+        return DE_UNITS[0].to_string();
+    }
+    let (millions, thousands, rest) = (n / 1_000_000, n / 1000 % 1000, n % 1000);
+    let mut words = vec![];
+    match millions {
+        0 => {}
+        1 => words.push("eine million".to_string()),
+        _ => words.push(format!("{} millionen", german_below_thousand(millions, false))),
+    }
+    let mut word = String::new();
+    if thousands > 0 {
+        word.push_str(&german_below_thousand(thousands, false));
+        word.push_str("tausend");
+    }
+    word.push_str(&german_below_thousand(rest, true));
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words.join(" ")
+}
+
+#[test]
+fn spell_numbers() {
+    assert_eq!(english(202_040), "two hundred two thousand forty");
+    assert_eq!(french(221_080), "deux cent vingt et un mille quatre-vingts");
+    assert_eq!(french(200_000_071), "deux cents millions soixante et onze");
+    assert_eq!(german(2_100_001), "zwei millionen einhunderttausendeins");
+    assert_eq!(german(500_736), "fünfhunderttausendsiebenhundertsechsunddreißig");
+}
+
+fn spell(lang: Lang, n: u64) -> String {
+    match lang {
+        Lang::DE => german(n),
+        Lang::EN => english(n),
+        Lang::FR => french(n),
+        _ => unreachable!(),
+    }
+}
+
+/// Checks that spelled out numbers below 10^9 parse back to their value.
+fn check_round_trip(langs: &[Lang], cases: u32) {
+    check_langs(langs,
+                cases,
+                |lang| (0..1_000_000_000u64).prop_map(move |n| (n, spell(lang, n))),
+                |parser, (n, text)| {
+                    let matches = parser
+                        .parse(&text, &context())
+                        .map_err(|e| TestCaseError::fail(e.to_string()))?;
+                    let whole = matches
+                        .iter()
+                        .find(|m| m.byte_range == (0, text.len()))
+                        .map(|m| m.value.clone());
+                    let value = match whole {
+                        Some(Output::Integer(ref integer)) => Some(integer.0 as f64),
+                        Some(Output::Float(ref float)) => Some(float.0),
+                        _ => None,
+                    };
+                    prop_assert_eq!(value, Some(n as f64), "{:?} parsed as {:?}", text, matches);
+                    Ok(())
+                });
+}
+
+#[test]
+fn spelled_numbers_round_trip_en() {
+    check_round_trip(&[Lang::EN], 16);
+}
+
+#[test]
+#[ignore]
+fn spelled_numbers_round_trip() {
+    check_round_trip(&[Lang::DE, Lang::EN, Lang::FR], 256);
+}
@@ -6,30 +6,73 @@ use serde_json;
 use std::env;
 use std::path;
 
+/// Runs the utterances of a file, and fails with all the failures which aren't listed in the
+/// `expected_failures.txt` file of the same folder, if any. Utterances expecting a list of
+/// entities fail unless all the entities found are the expected ones, and the precision and
+/// recall over all of them are printed.
 pub fn run_json_test<P: AsRef<path::Path>>(lang: Lang, path: P) {
-    let file = ::std::fs::File::open(path).unwrap();
+    let file = ::std::fs::File::open(path.as_ref()).unwrap();
     let utterances: Vec<Utterance> = serde_json::from_reader(&file).unwrap();
     let utterances: Vec<Utterance> = utterances.into_iter().filter(|it| it.keep()).collect();
+    let expected_failures = path.as_ref()
+        .parent()
+        .map(|it| it.join("expected_failures.txt"))
+        .filter(|it| it.exists())
+        .map(|it| read_expected_failures(it).unwrap())
+        .unwrap_or_default();
     let parser = build_parser(lang).unwrap();
+    let mut failures = vec![];
+    let mut scores = EntityScores::default();
     for utterance in utterances {
-        let context = ResolverContext::new(Interval::starting_at(utterance.context, moment::Grain::Second));
+        let context = ResolverContext::new(Interval::starting_at(utterance_reference(utterance.context, default_reference()), moment::Grain::Second));
         let entities = parser.parse(utterance.phrase.to_lowercase().as_str(), &context).unwrap();
-        assert_eq!(entities.len(), 1, "Only one match was exepcted for this sentence: {:?}", utterance.phrase.as_str());
-
-        let entity = entities.first();
-        match (entity, utterance.value) {
-            (Some(entity), Some(expected_value)) => {
-                assert_eq!(entity.byte_range.len(), utterance.phrase.len(), "Expected full match for this sentence: {:?} found: {:?}", utterance.phrase.as_str(), entity);
-                let value: SlotValue = entity.value.clone().into();
-                assert_eq!(value, expected_value, "Sentence: {:?}, Found: {:?} expected: {:?}", utterance.phrase.as_str(), entities, expected_value);
+        let failure = if let Some(ref expected) = utterance.entities {
+            let found = entities
+                .iter()
+                .map(|it| Entity {
+                    start: it.char_range.0,
+                    end: it.char_range.1,
+                    value: it.value.clone().into(),
+                })
+                .collect::<Vec<_>>();
+            let utterance_scores = EntityScores::new(expected, &found);
+            scores.add(&utterance_scores);
+            if utterance_scores.is_perfect() {
+                None
+            } else {
+                Some(format!("Sentence: {:?}, Found entities: {:?} expected: {:?}", utterance.phrase.as_str(), found, expected))
+            }
+        } else if entities.len() != 1 {
+            Some(format!("Only one match was exepcted for this sentence: {:?} found: {:?}", utterance.phrase.as_str(), entities))
+        } else {
+            let entity = entities.first();
+            match (entity, utterance.value) {
+                (Some(entity), Some(expected_value)) => {
+                    let value: SlotValue = entity.value.clone().into();
+                    if entity.byte_range.len() != utterance.phrase.len() {
+                        Some(format!("Expected full match for this sentence: {:?} found: {:?}", utterance.phrase.as_str(), entity))
+                    } else if !value.matches_expected(&expected_value) {
+                        Some(format!("Sentence: {:?}, Found: {:?} expected: {:?}", utterance.phrase.as_str(), entities, expected_value))
+                    } else {
+                        None
+                    }
+                }
+                (None, None) => None,
+                (entity, utterance_value) => {
+                    Some(format!("Sentence: {:?}, Found: {:?} expected: {:?}", utterance.phrase.as_str(), entity, utterance_value))
+                }
             }
-            (None, None) => {},
-            (entity, utterance_value) => {
-                assert!(false, "Found: {:?} expected: {:?}", entity, utterance_value);
+        };
+        if let Some(failure) = failure {
+            if !expected_failures.contains(&utterance.phrase) {
+                failures.push(failure);
             }
         }
-
     }
+    if scores.expected > 0 || scores.found > 0 {
+        println!("Entities: precision {:.3} | recall {:.3}", scores.precision(), scores.recall());
+    }
+    assert!(failures.is_empty(), "{} failures:\n{}", failures.len(), failures.join("\n"));
 }
 
 pub fn build_resources_path(lang: &str, file_name: &str) -> path::PathBuf {
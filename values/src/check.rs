@@ -229,10 +229,53 @@ pub fn check_percentage(value: f64) -> CheckPercentage {
     CheckPercentage { value }
 }
 
+#[derive(Debug)]
+pub struct CheckDigitSequence {
+    pub digits: &'static str,
+    pub kind: Option<DigitSequenceKind>,
+}
+
+impl Check<Dimension> for CheckDigitSequence {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        DigitSequenceValue::attempt_from(pn.value.clone())
+            .map(|v| v.digits == self.digits && self.kind.map(|kind| kind == v.kind).unwrap_or(true))
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_digit_sequence(digits: &'static str) -> CheckDigitSequence {
+    CheckDigitSequence { digits, kind: None }
+}
+
+pub fn check_phone_number(digits: &'static str) -> CheckDigitSequence {
+    CheckDigitSequence {
+        digits,
+        kind: Some(DigitSequenceKind::PhoneNumber),
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckAge {
+    pub min: Option<Period>,
+    pub max: Option<Period>,
+}
+
+impl Check<Dimension> for CheckAge {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        AgeValue::attempt_from(pn.value.clone())
+            .map(|v| v.min == self.min && v.max == self.max)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_age(min: Option<Period>, max: Option<Period>) -> CheckAge {
+    CheckAge { min, max }
+}
+
 #[derive(Debug)]
 pub struct CheckTemperature {
     pub value: f64,
-    pub unit: Option<&'static str>,
+    pub unit: Option<TemperatureUnit>,
 }
 
 impl Check<Dimension> for CheckTemperature {
@@ -243,6 +286,6 @@ impl Check<Dimension> for CheckTemperature {
     }
 }
 
-pub fn check_temperature(value: f64, unit: Option<&'static str>) -> CheckTemperature {
+pub fn check_temperature(value: f64, unit: Option<TemperatureUnit>) -> CheckTemperature {
     CheckTemperature { value, unit }
 }
@@ -117,6 +117,7 @@ impl ParsingContext<Dimension> for ResolverContext {
                                 interval_kind: DatetimeIntervalKind::Between {
                                     start: interval.start,
                                     end: end,
+                                    grain: interval.grain,
                                     precision: datetime_value.precision,
                                     latent: datetime_value.latent,
                                 },
@@ -137,15 +138,16 @@ impl ParsingContext<Dimension> for ResolverContext {
             }
             &Dimension::Number(ref number) => match number {
                 &NumberValue::Integer(ref v) => Some(Output::Integer(IntegerOutput(v.value))),
-                &NumberValue::Float(ref v) => Some(Output::Float(FloatOutput(v.value))),
+                &NumberValue::Float(ref v) => Some(Output::Float(FloatOutput(v.value, v.decimal))),
             },
             &Dimension::Ordinal(ref ordinal) => Some(Output::Ordinal(OrdinalOutput(ordinal.value))),
             &Dimension::AmountOfMoney(ref aom) => {
-                Some(Output::AmountOfMoney(AmountOfMoneyOutput {
-                    value: aom.value,
-                    precision: aom.precision,
-                    unit: aom.unit,
-                }))
+                Some(Output::AmountOfMoney(AmountOfMoneyOutput::new(
+                    aom.value,
+                    aom.decimal,
+                    aom.precision,
+                    aom.unit,
+                )))
             }
             &Dimension::Temperature(ref temp) => Some(Output::Temperature(TemperatureOutput {
                 value: temp.value,
@@ -157,8 +159,22 @@ impl ParsingContext<Dimension> for ResolverContext {
                 precision: duration.precision,
             })),
             &Dimension::Percentage(ref percentage) => {
-                Some(Output::Percentage(PercentageOutput(percentage.0)))
+                Some(Output::Percentage(PercentageOutput(percentage.0, percentage.1)))
+            }
+            &Dimension::DigitSequence(ref sequence) => {
+                Some(Output::DigitSequence(DigitSequenceOutput {
+                    digits: sequence.digits.clone(),
+                    groups: sequence.groups.clone(),
+                    prefix: sequence.prefix.clone(),
+                    kind: sequence.kind,
+                    country_code: sequence.country_code,
+                    international: sequence.international,
+                }))
             }
+            &Dimension::Age(ref age) => Some(Output::Age(AgeOutput {
+                min: age.min.clone(),
+                max: age.max.clone(),
+            })),
             _ => None,
         }
     }
@@ -0,0 +1,278 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::dimension::locale_region;
+
+/// Currency of an amount of money, identified by its ISO-4217 code.
+///
+/// Cryptocurrencies have no ISO-4217 codes and use the common "XBT" for bitcoin and "ETH"
+/// for ether.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Currency {
+    Aed,
+    Aud,
+    Cad,
+    Chf,
+    Cny,
+    Dkk,
+    Egp,
+    Esp,
+    Eth,
+    Eur,
+    Gbp,
+    Hkd,
+    Inr,
+    Isk,
+    Jpy,
+    Krw,
+    Mxn,
+    Nok,
+    Nzd,
+    Rub,
+    Sek,
+    Sgd,
+    Usd,
+    Xbt,
+}
+
+const CURRENCIES: &[Currency] = &[
+    Currency::Aed,
+    Currency::Aud,
+    Currency::Cad,
+    Currency::Chf,
+    Currency::Cny,
+    Currency::Dkk,
+    Currency::Egp,
+    Currency::Esp,
+    Currency::Eth,
+    Currency::Eur,
+    Currency::Gbp,
+    Currency::Hkd,
+    Currency::Inr,
+    Currency::Isk,
+    Currency::Jpy,
+    Currency::Krw,
+    Currency::Mxn,
+    Currency::Nok,
+    Currency::Nzd,
+    Currency::Rub,
+    Currency::Sek,
+    Currency::Sgd,
+    Currency::Usd,
+    Currency::Xbt,
+];
+
+/// Currency in use in each region, for the regions whose locales this crate supports.
+const REGION_CURRENCIES: &[(&str, Currency)] = &[
+    ("AE", Currency::Aed),
+    ("AT", Currency::Eur),
+    ("AU", Currency::Aud),
+    ("BE", Currency::Eur),
+    ("CA", Currency::Cad),
+    ("CH", Currency::Chf),
+    ("CN", Currency::Cny),
+    ("DE", Currency::Eur),
+    ("DK", Currency::Dkk),
+    ("EG", Currency::Egp),
+    ("ES", Currency::Eur),
+    ("FI", Currency::Eur),
+    ("FR", Currency::Eur),
+    ("GB", Currency::Gbp),
+    ("GR", Currency::Eur),
+    ("HK", Currency::Hkd),
+    ("IE", Currency::Eur),
+    ("IN", Currency::Inr),
+    ("IS", Currency::Isk),
+    ("IT", Currency::Eur),
+    ("JP", Currency::Jpy),
+    ("KR", Currency::Krw),
+    ("LU", Currency::Eur),
+    ("MX", Currency::Mxn),
+    ("NL", Currency::Eur),
+    ("NO", Currency::Nok),
+    ("NZ", Currency::Nzd),
+    ("PT", Currency::Eur),
+    ("RU", Currency::Rub),
+    ("SE", Currency::Sek),
+    ("SG", Currency::Sgd),
+    ("US", Currency::Usd),
+];
+
+/// Units of money meaning a fraction of a major unit, with the number of minor units in
+/// the major one: "cent" for "cents", "pence" or "centimes", and "sat" for satoshis.
+const MINOR_UNITS: &[(&str, f64)] = &[("cent", 100.0), ("sat", 100_000_000.0)];
+
+/// Number of minor units in the major one if the unit is a minor unit, e.g. 100 for "cent".
+pub fn minor_unit_ratio(unit: &str) -> Option<f64> {
+    MINOR_UNITS
+        .iter()
+        .find(|&&(it, _)| it == unit)
+        .map(|&(_, ratio)| ratio)
+}
+
+impl Currency {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::Aed => "AED",
+            Currency::Aud => "AUD",
+            Currency::Cad => "CAD",
+            Currency::Chf => "CHF",
+            Currency::Cny => "CNY",
+            Currency::Dkk => "DKK",
+            Currency::Egp => "EGP",
+            Currency::Esp => "ESP",
+            Currency::Eth => "ETH",
+            Currency::Eur => "EUR",
+            Currency::Gbp => "GBP",
+            Currency::Hkd => "HKD",
+            Currency::Inr => "INR",
+            Currency::Isk => "ISK",
+            Currency::Jpy => "JPY",
+            Currency::Krw => "KRW",
+            Currency::Mxn => "MXN",
+            Currency::Nok => "NOK",
+            Currency::Nzd => "NZD",
+            Currency::Rub => "RUB",
+            Currency::Sek => "SEK",
+            Currency::Sgd => "SGD",
+            Currency::Usd => "USD",
+            Currency::Xbt => "XBT",
+        }
+    }
+
+    /// Currency in use in the region of a locale such as "en-US" or "fr_CH".
+    pub fn for_locale(locale: &str) -> Option<Currency> {
+        let region = locale_region(locale)?;
+        REGION_CURRENCIES
+            .iter()
+            .find(|&&(it, _)| it.eq_ignore_ascii_case(region))
+            .map(|&(_, currency)| currency)
+    }
+
+    /// Currency of a unit of money as produced by the grammars, e.g. `Currency::Eur` for
+    /// "EUR" or `Currency::Rub` for "₽". Returns `None` for ambiguous symbols like "$" and
+    /// for cents, see `Currency::resolve`.
+    pub fn from_unit(unit: &str) -> Option<Currency> {
+        match unit {
+            "₽" => Some(Currency::Rub),
+            "€" => Some(Currency::Eur),
+            "₩" => Some(Currency::Krw),
+            "₹" => Some(Currency::Inr),
+            "฿" | "BTC" | "sat" => Some(Currency::Xbt),
+            "PTS" => Some(Currency::Esp),
+            _ => unit.parse().ok(),
+        }
+    }
+
+    /// Currency of a unit of money in a locale, resolving ambiguous symbols with the currency
+    /// of the locale's region: "$" is Canadian dollars in "en-CA" and US dollars when the
+    /// region doesn't use dollars, "¥" yuan in "zh-CN" and yen elsewhere, "£" Egyptian pounds
+    /// in "ar-EG" and pounds sterling elsewhere. Crowns ("KR") and cents only resolve in
+    /// regions using crowns, or any currency with cents respectively. Amounts without unit,
+    /// like "2 grand" or "50 balles", are in the currency of the region.
+    pub fn resolve(unit: Option<&str>, locale: Option<&str>) -> Option<Currency> {
+        let local = locale.and_then(Currency::for_locale);
+        let unit = match unit {
+            Some(unit) => unit,
+            None => return local,
+        };
+        if let Some(currency) = Currency::from_unit(unit) {
+            return Some(currency);
+        }
+        let local_among = |candidates: &[Currency]| local.filter(|it| candidates.contains(it));
+        match unit {
+            "$" => local_among(&[
+                Currency::Aud,
+                Currency::Cad,
+                Currency::Hkd,
+                Currency::Mxn,
+                Currency::Nzd,
+                Currency::Sgd,
+            ])
+            .or(Some(Currency::Usd)),
+            "£" => local_among(&[Currency::Egp]).or(Some(Currency::Gbp)),
+            "¥" => local_among(&[Currency::Cny]).or(Some(Currency::Jpy)),
+            "KR" => local_among(&[Currency::Dkk, Currency::Isk, Currency::Nok, Currency::Sek]),
+            "cent" => local.filter(|it| it.minor_unit_digits() > 0),
+            _ => None,
+        }
+    }
+
+    /// Number of decimal digits of the minor unit, e.g. 2 for the cents of euros, 0 for yens.
+    pub fn minor_unit_digits(&self) -> u32 {
+        match self {
+            Currency::Isk | Currency::Jpy | Currency::Krw => 0,
+            Currency::Xbt => 8,
+            Currency::Eth => 18,
+            _ => 2,
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Currency, String> {
+        CURRENCIES
+            .iter()
+            .find(|currency| currency.code() == s)
+            .cloned()
+            .ok_or_else(|| format!("Unknown currency code: {}", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_unit() {
+        assert_eq!(Some(Currency::Eur), Currency::from_unit("EUR"));
+        assert_eq!(Some(Currency::Rub), Currency::from_unit("₽"));
+        assert_eq!(Some(Currency::Esp), Currency::from_unit("PTS"));
+        assert_eq!(None, Currency::from_unit("$"));
+        assert_eq!(None, Currency::from_unit("cent"));
+    }
+
+    #[test]
+    fn test_resolve_ambiguous_symbols() {
+        assert_eq!(Some(Currency::Usd), Currency::resolve(Some("$"), None));
+        assert_eq!(Some(Currency::Usd), Currency::resolve(Some("$"), Some("fr-FR")));
+        assert_eq!(Some(Currency::Cad), Currency::resolve(Some("$"), Some("fr-CA")));
+        assert_eq!(Some(Currency::Cny), Currency::resolve(Some("¥"), Some("zh_CN")));
+        assert_eq!(Some(Currency::Jpy), Currency::resolve(Some("¥"), Some("ja-JP")));
+        assert_eq!(Some(Currency::Sek), Currency::resolve(Some("KR"), Some("sv-SE")));
+        assert_eq!(None, Currency::resolve(Some("KR"), Some("en-US")));
+        assert_eq!(Some(Currency::Gbp), Currency::resolve(Some("£"), Some("en-GB")));
+    }
+
+    #[test]
+    fn test_resolve_minor_units() {
+        assert_eq!(Some(Currency::Gbp), Currency::resolve(Some("cent"), Some("en-GB")));
+        assert_eq!(Some(Currency::Chf), Currency::resolve(Some("cent"), Some("fr-CH")));
+        assert_eq!(None, Currency::resolve(Some("cent"), Some("ja-JP")));
+        assert_eq!(None, Currency::resolve(Some("cent"), None));
+        assert_eq!(Some(Currency::Xbt), Currency::resolve(Some("sat"), None));
+        assert_eq!(Some(100_000_000.0), minor_unit_ratio("sat"));
+    }
+
+    #[test]
+    fn test_resolve_without_unit() {
+        assert_eq!(Some(Currency::Eur), Currency::resolve(None, Some("fr-FR")));
+        assert_eq!(Some(Currency::Chf), Currency::resolve(None, Some("fr-CH")));
+        assert_eq!(None, Currency::resolve(None, None));
+    }
+
+    #[test]
+    fn test_codes_round_trip() {
+        for currency in CURRENCIES {
+            assert_eq!(Ok(*currency), currency.code().parse());
+        }
+    }
+}
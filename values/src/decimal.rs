@@ -0,0 +1,211 @@
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+
+/// Exact decimal number, equal to `mantissa * 10^-scale`.
+///
+/// Decimals are always normalized, i.e. without trailing zeros in the fractional part, so
+/// that equal numbers have equal representations: `19.90` and `19.9` are the same decimal.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    pub fn new(mut mantissa: i128, mut scale: u32) -> Decimal {
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        if mantissa == 0 {
+            scale = 0;
+        }
+        Decimal { mantissa, scale }
+    }
+
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Exact quotient of two integers, `None` when its decimal expansion doesn't terminate,
+    /// e.g. `3 / 4` but not `2 / 3`.
+    pub fn ratio(numerator: i64, denominator: i64) -> Option<Decimal> {
+        if denominator == 0 {
+            return None;
+        }
+        let (numerator, denominator) = (numerator as i128, denominator as i128);
+        // Stops when the scaled numerator overflows, which covers the non terminating quotients
+        for scale in 0.. {
+            let scaled = numerator.checked_mul(10i128.checked_pow(scale)?)?;
+            if scaled % denominator == 0 {
+                return Some(Decimal::new(scaled / denominator, scale));
+            }
+        }
+        None
+    }
+
+    /// Closest `f64` to the decimal.
+    pub fn to_f64(&self) -> f64 {
+        // Parsing is correctly rounded, which dividing by a power of ten is not
+        self.to_string().parse().unwrap_or(::std::f64::NAN)
+    }
+
+    /// Value of the decimal if it is an integer fitting in an `i64`.
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.scale == 0
+            && self.mantissa >= i64::min_value() as i128
+            && self.mantissa <= i64::max_value() as i128
+        {
+            Some(self.mantissa as i64)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.scale == 0
+    }
+
+    pub fn checked_add(self, other: Decimal) -> Option<Decimal> {
+        let scale = self.scale.max(other.scale);
+        let lhs = self.mantissa.checked_mul(10i128.checked_pow(scale - self.scale)?)?;
+        let rhs = other.mantissa.checked_mul(10i128.checked_pow(scale - other.scale)?)?;
+        Some(Decimal::new(lhs.checked_add(rhs)?, scale))
+    }
+
+    pub fn checked_mul(self, other: Decimal) -> Option<Decimal> {
+        Some(Decimal::new(
+            self.mantissa.checked_mul(other.mantissa)?,
+            self.scale.checked_add(other.scale)?,
+        ))
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(value: i64) -> Decimal {
+        Decimal::new(value as i128, 0)
+    }
+}
+
+impl ops::Neg for Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        Decimal::new(-self.mantissa, self.scale)
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        if scale == 0 {
+            write!(f, "{}{}", sign, digits)
+        } else if digits.len() > scale {
+            let (integer, fractional) = digits.split_at(digits.len() - scale);
+            write!(f, "{}{}.{}", sign, integer, fractional)
+        } else {
+            write!(f, "{}0.{}{}", sign, "0".repeat(scale - digits.len()), digits)
+        }
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ::failure::Error;
+
+    fn from_str(s: &str) -> Result<Decimal, Self::Err> {
+        let (negative, unsigned) = if s.starts_with('-') {
+            (true, &s[1..])
+        } else if s.starts_with('+') {
+            (false, &s[1..])
+        } else {
+            (false, s)
+        };
+        let mut parts = unsigned.splitn(2, '.');
+        let integer = parts.next().unwrap_or("");
+        let fractional = parts.next().unwrap_or("");
+        let all_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+        if integer.len() + fractional.len() == 0 || !all_digits(integer) || !all_digits(fractional) {
+            return Err(format_err!("Invalid decimal {:?}", s));
+        }
+        let mantissa: i128 = format!("{}{}", integer, fractional)
+            .parse()
+            .map_err(|_| format_err!("Decimal {:?} is out of range", s))?;
+        Ok(Decimal::new(if negative { -mantissa } else { mantissa }, fractional.len() as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalization() {
+        assert_eq!(Decimal::new(1990, 2), Decimal::new(199, 1));
+        assert_eq!(Decimal::new(0, 5), Decimal::new(0, 0));
+        assert_eq!(Decimal::new(1990, 2).scale(), 1);
+    }
+
+    #[test]
+    fn test_ratio() {
+        assert_eq!(Decimal::ratio(3, 4), Some(Decimal::new(75, 2)));
+        assert_eq!(Decimal::ratio(-1, 8), Some(Decimal::new(-125, 3)));
+        assert_eq!(Decimal::ratio(10, 5), Some(Decimal::from(2)));
+        assert_eq!(Decimal::ratio(2, 3), None);
+        assert_eq!(Decimal::ratio(1, 0), None);
+    }
+
+    #[test]
+    fn test_to_f64() {
+        assert_eq!(Decimal::new(1999, 2).to_f64(), 19.99);
+        assert_eq!(Decimal::new(-5, 2).to_f64(), -0.05);
+    }
+
+    #[test]
+    fn test_to_i64() {
+        assert_eq!(Decimal::from(i64::max_value()).to_i64(), Some(i64::max_value()));
+        assert_eq!(Decimal::new(12_000_000_000_000_000_000, 0).to_i64(), None);
+        assert_eq!(Decimal::new(15, 1).to_i64(), None);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Decimal::new(1999, 2);
+        let b = Decimal::new(1, 1);
+        assert_eq!(a.checked_add(b), Some(Decimal::new(2009, 2)));
+        assert_eq!(a.checked_mul(Decimal::from(3)), Some(Decimal::new(5997, 2)));
+        assert_eq!(-a, Decimal::new(-1999, 2));
+        assert_eq!(Decimal::new(i128::max_value(), 0).checked_add(Decimal::from(1)), None);
+        assert_eq!(Decimal::new(i128::max_value(), 0).checked_mul(Decimal::from(2)), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Decimal::new(1999, 2).to_string(), "19.99");
+        assert_eq!(Decimal::new(-5, 2).to_string(), "-0.05");
+        assert_eq!(Decimal::new(12, 0).to_string(), "12");
+        assert_eq!(Decimal::new(-123, 3).to_string(), "-0.123");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("19.99".parse::<Decimal>().unwrap(), Decimal::new(1999, 2));
+        assert_eq!("-0.050".parse::<Decimal>().unwrap(), Decimal::new(-5, 2));
+        assert_eq!("+12".parse::<Decimal>().unwrap(), Decimal::from(12));
+        assert_eq!(".5".parse::<Decimal>().unwrap(), Decimal::new(5, 1));
+        assert_eq!(
+            "12000000000000000000".parse::<Decimal>().unwrap(),
+            Decimal::new(12_000_000_000_000_000_000, 0)
+        );
+        assert!("".parse::<Decimal>().is_err());
+        assert!(".".parse::<Decimal>().is_err());
+        assert!("1.2.3".parse::<Decimal>().is_err());
+        assert!("1e3".parse::<Decimal>().is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::decimal::Decimal;
 use moment::{Grain, Local, Period, RcConstraint};
 use rustling::*;
 use std::{fmt, result};
@@ -16,6 +17,8 @@ rustling_value! {
         Datetime(DatetimeValue),
         Duration(DurationValue),
         Percentage(PercentageValue),
+        DigitSequence(DigitSequenceValue),
+        Age(AgeValue),
         Cycle(CycleValue),
         UnitOfDuration(UnitOfDurationValue),
         RelativeMinute(RelativeMinuteValue),
@@ -25,6 +28,8 @@ rustling_value! {
         match v {
             &Dimension::Number(_) => false,
             &Dimension::Percentage(_) => false,
+            &Dimension::DigitSequence(_) => false,
+            &Dimension::Age(_) => false,
             &Dimension::AmountOfMoney(_) => false,
             &Dimension::Ordinal(_) => false,
             &Dimension::Temperature(ref temp) => temp.latent,
@@ -41,6 +46,8 @@ rustling_value! {
         match v {
             &Dimension::Number(_) => None,
             &Dimension::Percentage(_) => None,
+            &Dimension::DigitSequence(_) => None,
+            &Dimension::Age(_) => None,
             &Dimension::AmountOfMoney(_) => None,
             &Dimension::Ordinal(_) => None,
             &Dimension::Temperature(_) => None,
@@ -55,10 +62,21 @@ rustling_value! {
 }
 
 impl Dimension {
+    /// Whether the value is a bare day of month like "the 3rd", which could also be read as
+    /// a position in a list.
+    pub fn is_day_of_month(&self) -> bool {
+        match self {
+            &Dimension::Datetime(ref dtv) => dtv.form == Form::DayOfMonth,
+            _ => false,
+        }
+    }
+
     pub fn is_too_ambiguous(&self) -> bool {
         match self {
             &Dimension::Number(_) => false,
             &Dimension::Percentage(_) => false,
+            &Dimension::DigitSequence(_) => false,
+            &Dimension::Age(_) => false,
             &Dimension::AmountOfMoney(_) => false,
             &Dimension::Ordinal(_) => false,
             &Dimension::Temperature(_) => false,
@@ -83,6 +101,8 @@ impl fmt::Display for Dimension {
                 &NumberValue::Float(ref v) => write!(fmt, "Number: {}", v.value),
             },
             &Dimension::Percentage(ref v) => write!(fmt, "Percentage: {}", v.0),
+            &Dimension::DigitSequence(ref v) => write!(fmt, "DigitSequence: {}", v.digits),
+            &Dimension::Age(_) => write!(fmt, "Age"),
             &Dimension::Ordinal(_) => write!(fmt, "Ordinal"),
             &Dimension::Temperature(_) => write!(fmt, "Temperature"),
             &Dimension::AmountOfMoney(_) => write!(fmt, "AmountOfMoney"),
@@ -148,6 +168,8 @@ pub struct AmountOfMoneyValue {
     pub value: f64,
     pub precision: Precision,
     pub unit: Option<&'static str>,
+    /// Exact value, when the amount was read from digits or computed exactly from them
+    pub decimal: Option<Decimal>,
 }
 
 /// Payload for the unit of money value of Dimension
@@ -228,6 +250,17 @@ impl IntegerValue {
     pub fn combined_from_right(&self) -> bool {
         return self.combine_from == Some(CombinationDirection::Right);
     }
+
+    /// Whether the integer is a multiplier word, i.e. a group like "dozen" or a power of ten
+    /// like "hundred" or "million", which can multiply a number written before it.
+    #[doc(hidden)]
+    pub fn is_multiplier(&self) -> bool {
+        self.group
+            || self
+                .grain
+                .map(|grain| grain >= 2 && 10i64.checked_pow(grain as u32) == Some(self.value))
+                .unwrap_or(false)
+    }
 }
 
 impl From<IntegerValue> for Dimension {
@@ -314,6 +347,8 @@ impl AttemptFrom<Dimension> for FloatValue {
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct FloatValue {
     pub value: f64,
+    /// Exact value, when the float was read from digits or computed exactly from them
+    pub decimal: Option<Decimal>,
     #[doc(hidden)]
     pub prefixed: bool,
     #[doc(hidden)]
@@ -322,6 +357,9 @@ pub struct FloatValue {
     pub combine_from: Option<CombinationDirection>,
     #[doc(hidden)]
     pub precision: Precision,
+    /// Whether the float is a fraction spelled out in words, e.g. "a half"
+    #[doc(hidden)]
+    pub spelled_fraction: bool,
 }
 
 impl FloatValue {
@@ -332,6 +370,15 @@ impl FloatValue {
         })
     }
 
+    /// Float read from digits, e.g. "19.99", keeping their exact value.
+    pub fn from_decimal(decimal: Decimal) -> RuleResult<FloatValue> {
+        Ok(FloatValue {
+            value: decimal.to_f64(),
+            decimal: Some(decimal),
+            ..FloatValue::default()
+        })
+    }
+
     #[doc(hidden)]
     pub fn combine_from(self, direction: CombinationDirection) -> RuleResult<FloatValue> {
         Ok(FloatValue {
@@ -435,6 +482,14 @@ impl NumberValue {
         }
     }
 
+    /// Exact value of the number, always known for integers.
+    pub fn decimal(&self) -> Option<Decimal> {
+        match self {
+            &NumberValue::Float(ref v) => v.decimal,
+            &NumberValue::Integer(ref v) => Some(v.value.into()),
+        }
+    }
+
     #[doc(hidden)]
     pub fn grain(&self) -> Option<u8> {
         match self {
@@ -442,14 +497,105 @@ impl NumberValue {
             &NumberValue::Integer(ref v) => v.grain,
         }
     }
+
+    #[doc(hidden)]
+    pub fn is_proper_fraction(&self) -> bool {
+        match self {
+            &NumberValue::Float(ref v) => !v.prefixed && 0.0 < v.value && v.value < 1.0,
+            &NumberValue::Integer(_) => false,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn is_spelled_fraction(&self) -> bool {
+        match self {
+            &NumberValue::Float(ref v) => v.spelled_fraction && self.is_proper_fraction(),
+            &NumberValue::Integer(_) => false,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum TemperatureUnit {
+    /// Degrees without a scale, e.g. "25°", see `TemperatureUnit::default_for_locale`
+    Degree,
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+/// Region subtag of a locale, e.g. "US" for "en-US" or "en_US", "TW" for "zh-Hant-TW".
+pub fn locale_region(locale: &str) -> Option<&str> {
+    locale
+        .split(|c| c == '-' || c == '_')
+        .skip(1)
+        .find(|subtag| subtag.len() == 2)
+}
+
+/// Regions where temperatures are given in degrees Fahrenheit.
+const FAHRENHEIT_REGIONS: &[&str] = &[
+    "US", "AS", "BS", "BZ", "FM", "GU", "KY", "LR", "MH", "MP", "PR", "PW", "VI",
+];
+
+impl TemperatureUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Degree => "degree",
+            TemperatureUnit::Celsius => "celsius",
+            TemperatureUnit::Fahrenheit => "fahrenheit",
+            TemperatureUnit::Kelvin => "kelvin",
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Degree => "°",
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+            TemperatureUnit::Kelvin => "K",
+        }
+    }
+
+    /// Scale meant by plain degrees in a locale such as "en-US" or "fr_FR": Fahrenheit in the
+    /// United States and the few other regions using it, Celsius elsewhere.
+    pub fn default_for_locale(locale: &str) -> TemperatureUnit {
+        match locale_region(locale) {
+            Some(region) if FAHRENHEIT_REGIONS.iter().any(|it| it.eq_ignore_ascii_case(region)) => {
+                TemperatureUnit::Fahrenheit
+            }
+            _ => TemperatureUnit::Celsius,
+        }
+    }
+
+    /// Converts a value from this unit to another one, `None` if either of them is
+    /// `TemperatureUnit::Degree`.
+    pub fn convert(&self, value: f64, target: TemperatureUnit) -> Option<f64> {
+        let celsius = match self {
+            TemperatureUnit::Degree => return None,
+            TemperatureUnit::Celsius => value,
+            TemperatureUnit::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+            TemperatureUnit::Kelvin => value - 273.15,
+        };
+        match target {
+            TemperatureUnit::Degree => None,
+            TemperatureUnit::Celsius => Some(celsius),
+            TemperatureUnit::Fahrenheit => Some(celsius * 9.0 / 5.0 + 32.0),
+            TemperatureUnit::Kelvin => Some(celsius + 273.15),
+        }
+    }
+}
+
+impl fmt::Display for TemperatureUnit {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}", self.as_str())
+    }
 }
 
 /// Payload for the temperatures value of Dimension
 #[derive(Debug, PartialEq, Clone)]
 pub struct TemperatureValue {
     pub value: f64,
-    /// Celsius, Fahrenheit, ...
-    pub unit: Option<&'static str>,
+    pub unit: Option<TemperatureUnit>,
     /// true if it can not be confirmed that the value is actually a temperature
     pub latent: bool,
 }
@@ -520,10 +666,19 @@ pub struct DatetimeValue {
 }
 
 // We need partial eq to make Dimension partial eq happy, but this is only
-// useful for testing.
+// useful for testing. Constraints can't be compared, so values are only equal
+// when they share the same constraint.
 impl PartialEq for DatetimeValue {
-    fn eq(&self, _other: &DatetimeValue) -> bool {
-        unimplemented!()
+    fn eq(&self, other: &DatetimeValue) -> bool {
+        ::std::ptr::eq(
+            ::std::rc::Rc::as_ptr(&self.constraint.0) as *const (),
+            ::std::rc::Rc::as_ptr(&other.constraint.0) as *const (),
+        ) && self.form == other.form
+            && self.direction == other.direction
+            && self.precision == other.precision
+            && self.latent == other.latent
+            && self.ambiguity == other.ambiguity
+            && self.datetime_kind == other.datetime_kind
     }
 }
 
@@ -860,8 +1015,99 @@ pub struct YearMonthDayForm {
     pub day_of_month: u32,
 }
 
+/// Payload for the percentage value of Dimension, with its exact value when known
+#[derive(Debug, PartialEq, Clone)]
+pub struct PercentageValue(pub f64, pub Option<Decimal>);
+
+impl<'a> From<&'a NumberValue> for PercentageValue {
+    fn from(number: &'a NumberValue) -> PercentageValue {
+        PercentageValue(number.value(), number.decimal())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum DigitSequenceKind {
+    /// Codes, PINs, flight numbers...
+    Generic,
+    PhoneNumber,
+}
+
+/// Payload for the digit sequences of Dimension, i.e. codes read digit by digit rather than
+/// quantities, like "4 8 1 5" or "06 12 34 56 78".
+#[derive(Debug, PartialEq, Clone)]
+pub struct DigitSequenceValue {
+    /// Digits as written, leading zeros included
+    pub digits: String,
+    /// Number of digits of each group, e.g. [2, 2, 2, 2, 2] for "06 12 34 56 78"
+    pub groups: Vec<usize>,
+    /// Letters before the digits, e.g. "AF" in "flight AF 1234"
+    pub prefix: Option<String>,
+    pub kind: DigitSequenceKind,
+    /// Country calling code of international phone numbers, when it is written apart from
+    /// the rest of the number, e.g. 33 in "+33 6 12 34 56 78"
+    pub country_code: Option<u16>,
+    /// true if the digits start with a country calling code
+    pub international: bool,
+}
+
+impl DigitSequenceValue {
+    pub fn new(groups: &[&str]) -> DigitSequenceValue {
+        DigitSequenceValue {
+            digits: groups.concat(),
+            groups: groups.iter().map(|group| group.len()).collect(),
+            prefix: None,
+            kind: DigitSequenceKind::Generic,
+            country_code: None,
+            international: false,
+        }
+    }
+
+    pub fn prefix(self, prefix: &str) -> DigitSequenceValue {
+        DigitSequenceValue { prefix: Some(prefix.to_uppercase()), ..self }
+    }
+
+    pub fn kind(self, kind: DigitSequenceKind) -> DigitSequenceValue {
+        DigitSequenceValue { kind, ..self }
+    }
+}
+
+/// Payload for the age value of Dimension, e.g. "34 years old" or "between 18 and 25 years
+/// old". A single age has equal bounds.
 #[derive(Debug, PartialEq, Clone)]
-pub struct PercentageValue(pub f64);
+pub struct AgeValue {
+    pub min: Option<Period>,
+    pub max: Option<Period>,
+}
+
+impl AgeValue {
+    pub fn new(period: Period) -> AgeValue {
+        AgeValue {
+            min: Some(period.clone()),
+            max: Some(period),
+        }
+    }
+
+    pub fn range(min: Period, max: Period) -> AgeValue {
+        AgeValue {
+            min: Some(min),
+            max: Some(max),
+        }
+    }
+
+    /// Age with no upper bound, e.g. "over 18 years old"
+    pub fn at_least(self) -> AgeValue {
+        AgeValue { max: None, ..self }
+    }
+
+    /// Age with no lower bound, e.g. "under 18 years old"
+    pub fn at_most(self) -> AgeValue {
+        AgeValue { min: None, ..self }
+    }
+
+    pub fn is_single(&self) -> bool {
+        self.min.is_some() && self.min == self.max
+    }
+}
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum FromAddition {
@@ -1,25 +1,362 @@
+use crate::decimal::Decimal;
 use crate::dimension::*;
 use moment::*;
 use regex::Regex;
 use rustling::{RuleError, RuleResult};
+use std::f64;
 use std::ops;
 
+/// 2^53, the first integer past which floats cannot represent every integer.
+const MAX_EXACT_F64: f64 = 9_007_199_254_740_992.0;
+
 pub fn compose_numbers(a: &NumberValue, b: &NumberValue) -> RuleResult<NumberValue> {
     let grain = a.grain().unwrap_or(0) as u32;
-    if 10u64.pow(grain) as f64 > b.value() && a.value() >= 0.0 && b.value() >= 0.0 {
+    let limit = 10u64.checked_pow(grain).map(|it| it as f64).unwrap_or(f64::INFINITY);
+    if limit > b.value() && a.value() >= 0.0 && b.value() >= 0.0 {
         match (a, b) {
             (&NumberValue::Integer(ref lhs), &NumberValue::Integer(ref rhs)) => {
+                let value = lhs
+                    .value
+                    .checked_add(rhs.value)
+                    .ok_or_else(|| -> RuleError { RuleError::Invalid.into() })?;
                 Ok(NumberValue::Integer(
-                    IntegerValue::new(lhs.value + rhs.value)?.with_grain(rhs.grain)?,
+                    IntegerValue::new(value)?.with_grain(rhs.grain)?,
                 ))
             }
-            _ => Ok(NumberValue::Float(FloatValue::new(a.value() + b.value())?)),
+            _ => {
+                let sum = a.value() + b.value();
+                // Past 2^53 floats cannot hold every integer, the sum would silently drop digits
+                if sum >= MAX_EXACT_F64 && sum - a.value() != b.value() {
+                    return Err(RuleError::Invalid.into());
+                }
+                let decimal = match (a.decimal(), b.decimal()) {
+                    (Some(lhs), Some(rhs)) => lhs.checked_add(rhs),
+                    _ => None,
+                };
+                Ok(NumberValue::Float(FloatValue {
+                    decimal,
+                    ..FloatValue::new(sum)?
+                }))
+            }
         }
     } else {
         Err(RuleError::Invalid.into())
     }
 }
 
+pub fn multiply_number(number: &NumberValue, multiplier: &IntegerValue) -> RuleResult<NumberValue> {
+    match number {
+        &NumberValue::Integer(ref integer) => {
+            if let Some(value) = integer.value.checked_mul(multiplier.value) {
+                return Ok(NumberValue::Integer(IntegerValue {
+                    value,
+                    grain: multiplier.grain,
+                    ..IntegerValue::default()
+                }));
+            }
+            // Numbers past i64, e.g. "twelve quintillion", are kept as floats when exact
+            let product = integer.value as i128 * multiplier.value as i128;
+            let float = product as f64;
+            if float as i128 != product {
+                return Err(RuleError::Invalid.into());
+            }
+            Ok(NumberValue::Float(FloatValue {
+                decimal: Some(Decimal::new(product, 0)),
+                ..FloatValue::new(float)?
+            }))
+        }
+        &NumberValue::Float(FloatValue { decimal: Some(decimal), .. }) => {
+            let product = decimal
+                .checked_mul(multiplier.value.into())
+                .ok_or_else(|| -> RuleError { RuleError::Invalid.into() })?;
+            match product.to_i64() {
+                Some(value) => Ok(NumberValue::Integer(IntegerValue {
+                    value,
+                    grain: multiplier.grain,
+                    ..IntegerValue::default()
+                })),
+                None => Ok(NumberValue::Float(FloatValue::from_decimal(product)?)),
+            }
+        }
+        &NumberValue::Float(ref float) => {
+            let product = float.value * multiplier.value as f64;
+            // Absorbs the representation error of decimals, e.g. 4.35 * 100 = 434.99999999999994
+            let rounded = product.round();
+            if (product - rounded).abs() <= 1e-9 * rounded.abs()
+                && rounded.abs() < i64::max_value() as f64
+            {
+                Ok(NumberValue::Integer(IntegerValue {
+                    value: rounded as i64,
+                    grain: multiplier.grain,
+                    ..IntegerValue::default()
+                }))
+            } else {
+                Ok(NumberValue::Float(FloatValue::new(product)?))
+            }
+        }
+    }
+}
+
+/// Value of a roman numeral, unless it's one of the `stop_words` of the language: words and
+/// abbreviations which happen to be valid numerals, like "mix" or "cm".
+pub fn roman_numeral(numeral: &str, stop_words: &[&str]) -> RuleResult<i64> {
+    if stop_words.contains(&numeral) {
+        return Err(RuleError::Invalid.into());
+    }
+    let digit_value = |c: char| match c {
+        'i' => Some(1),
+        'v' => Some(5),
+        'x' => Some(10),
+        'l' => Some(50),
+        'c' => Some(100),
+        'd' => Some(500),
+        'm' => Some(1000),
+        _ => None,
+    };
+    let digits = numeral
+        .chars()
+        .map(|c| digit_value(c.to_ascii_lowercase()))
+        .collect::<Option<Vec<i64>>>()
+        .ok_or_else(|| -> RuleError { RuleError::Invalid.into() })?;
+    let value = digits.iter().enumerate().fold(0, |acc, (i, &digit)| {
+        match digits.get(i + 1) {
+            Some(&next) if next > digit => acc - digit,
+            _ => acc + digit,
+        }
+    });
+    // Only the canonical writing of a number is accepted, which rules out "iiii", "vx" or "ic"
+    if value <= 0 || value >= 4000 || to_roman_numeral(value) != numeral.to_ascii_lowercase() {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(value)
+}
+
+fn to_roman_numeral(mut value: i64) -> String {
+    let symbols = [
+        (1000, "m"), (900, "cm"), (500, "d"), (400, "cd"),
+        (100, "c"), (90, "xc"), (50, "l"), (40, "xl"),
+        (10, "x"), (9, "ix"), (5, "v"), (4, "iv"), (1, "i"),
+    ];
+    let mut numeral = String::new();
+    for &(symbol_value, symbol) in symbols.iter() {
+        while value >= symbol_value {
+            numeral.push_str(symbol);
+            value -= symbol_value;
+        }
+    }
+    numeral
+}
+
+pub fn fraction(numerator: i64, denominator: i64) -> RuleResult<FloatValue> {
+    if numerator < 0 || denominator < 2 {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(FloatValue {
+        decimal: Decimal::ratio(numerator, denominator),
+        ..FloatValue::new(numerator as f64 / denominator as f64)?
+    })
+}
+
+/// Fraction spelled out in words, e.g. "three quarters", which unlike the typed ones can be
+/// a fraction of the unit after an amount of money.
+pub fn spelled_fraction(numerator: i64, denominator: i64) -> RuleResult<FloatValue> {
+    Ok(FloatValue {
+        spelled_fraction: true,
+        ..fraction(numerator, denominator)?
+    })
+}
+
+pub fn vulgar_fraction(character: &str) -> RuleResult<FloatValue> {
+    let (numerator, denominator) = match character {
+        "½" => (1, 2),
+        "⅓" => (1, 3),
+        "⅔" => (2, 3),
+        "¼" => (1, 4),
+        "¾" => (3, 4),
+        "⅕" => (1, 5),
+        "⅖" => (2, 5),
+        "⅗" => (3, 5),
+        "⅘" => (4, 5),
+        "⅙" => (1, 6),
+        "⅚" => (5, 6),
+        "⅐" => (1, 7),
+        "⅛" => (1, 8),
+        "⅜" => (3, 8),
+        "⅝" => (5, 8),
+        "⅞" => (7, 8),
+        "⅑" => (1, 9),
+        "⅒" => (1, 10),
+        _ => return Err(RuleError::Invalid.into()),
+    };
+    fraction(numerator, denominator)
+}
+
+pub fn mixed_number(integer: &IntegerValue, fraction: &NumberValue) -> RuleResult<FloatValue> {
+    if integer.value < 0 || !fraction.is_proper_fraction() {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(FloatValue {
+        decimal: fraction.decimal().and_then(|it| it.checked_add(integer.value.into())),
+        ..FloatValue::new(integer.value as f64 + fraction.value())?
+    })
+}
+
+/// Integer read digit by digit, e.g. "five five five one two three four" in a speech
+/// transcript. Leading zeros are not kept.
+pub fn digit_sequence(digits: &[i64]) -> RuleResult<IntegerValue> {
+    if digits.is_empty() || digits.iter().any(|digit| *digit < 0 || *digit > 9) {
+        return Err(RuleError::Invalid.into());
+    }
+    let value = digits
+        .iter()
+        .try_fold(0i64, |acc, digit| acc.checked_mul(10)?.checked_add(*digit))
+        .ok_or_else(|| -> RuleError { RuleError::Invalid.into() })?;
+    IntegerValue::new(value)
+}
+
+/// Digit sequence written in `text`, with groups of digits separated by any other character,
+/// e.g. "06 12 34 56 78" or "4-8-1-5".
+///
+/// A single group is only accepted with a leading zero like "0042" or with the length of a phone
+/// number, and two groups from six digits or with a leading zero, so that plain numbers like "42",
+/// numbers merely written next to each other like "2 3" or ranges like "10-12" are kept apart.
+/// Dates like "2013-02-12" and numbers with thousands separators like "1.500.000" are rejected.
+pub fn digit_sequence_value(text: &str) -> RuleResult<DigitSequenceValue> {
+    let groups = digit_groups(text);
+    if groups.is_empty() || is_date_shaped(&groups) || is_thousands_shaped(text, &groups) {
+        return Err(RuleError::Invalid.into());
+    }
+    let value = DigitSequenceValue::new(&groups);
+    let too_short = match groups.len() {
+        1 => !value.digits.starts_with('0') && value.digits.len() < 7,
+        2 => value.digits.len() < 6 && !(value.digits.starts_with('0') && value.digits.len() >= 3),
+        _ => value.digits.len() < 3,
+    };
+    if too_short {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(national_phone_number_kind(value))
+}
+
+/// Code made of digits, e.g. the number of a flight, which is taken as is.
+pub fn digit_code(text: &str) -> RuleResult<DigitSequenceValue> {
+    let groups = digit_groups(text);
+    if groups.is_empty() {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(DigitSequenceValue::new(&groups))
+}
+
+/// Digit sequence read digit by digit, e.g. "oh six one two" in a speech transcript. Unlike
+/// `digit_sequence`, leading zeros are kept.
+pub fn spoken_digit_sequence_value(digits: &[i64]) -> RuleResult<DigitSequenceValue> {
+    if digits.is_empty() || digits.iter().any(|digit| *digit < 0 || *digit > 9) {
+        return Err(RuleError::Invalid.into());
+    }
+    let digits = digits.iter().map(|digit| digit.to_string()).collect::<String>();
+    Ok(national_phone_number_kind(DigitSequenceValue::new(&[&digits])))
+}
+
+/// International phone number, e.g. "+33 6 12 34 56 78", given its country calling code when
+/// it is written apart from the rest of the number.
+pub fn international_phone_number(country_code: Option<&str>, number: &str) -> RuleResult<DigitSequenceValue> {
+    let mut groups = digit_groups(number);
+    if let Some(country_code) = country_code {
+        groups.insert(0, country_code);
+    }
+    let mut value = DigitSequenceValue::new(&groups).kind(DigitSequenceKind::PhoneNumber);
+    // E.164 numbers have at most 15 digits, and country calling codes don't start with 0
+    if value.digits.len() < 7 || value.digits.len() > 15 || value.digits.starts_with('0') {
+        return Err(RuleError::Invalid.into());
+    }
+    value.country_code = match country_code {
+        Some(country_code) => Some(country_code.parse().map_err(|_| -> RuleError { RuleError::Invalid.into() })?),
+        None => None,
+    };
+    value.international = true;
+    Ok(value)
+}
+
+fn digit_groups(text: &str) -> Vec<&str> {
+    text.split(|c: char| !c.is_ascii_digit())
+        .filter(|group| !group.is_empty())
+        .collect()
+}
+
+/// A year of four digits with a month and a day, e.g. "2013-02-12" or "12.02.2013".
+fn is_date_shaped(groups: &[&str]) -> bool {
+    let short = |group: &&str| group.len() <= 2;
+    match groups {
+        [year, month, day] if year.len() == 4 => short(month) && short(day),
+        [day, month, year] if year.len() == 4 => short(day) && short(month),
+        _ => false,
+    }
+}
+
+/// A number with dots or spaces as thousands separators, e.g. "1.500.000" or "1 500 000".
+fn is_thousands_shaped(text: &str, groups: &[&str]) -> bool {
+    groups.len() >= 2
+        && !groups[0].starts_with('0')
+        && groups[0].len() <= 3
+        && groups[1..].iter().all(|group| group.len() == 3)
+        && text.chars().all(|c| c.is_ascii_digit() || c == '.' || c == ' ')
+}
+
+/// Marks national phone numbers, recognized from their trunk prefix like "06 12 34 56 78" or
+/// "020 7946 0958", or from the North American grouping like "555-123-4567".
+fn national_phone_number_kind(value: DigitSequenceValue) -> DigitSequenceValue {
+    let trunk_prefixed = value.digits.starts_with('0')
+        && !value.digits.starts_with("00")
+        && value.digits.len() >= 9
+        && value.digits.len() <= 11;
+    if trunk_prefixed || value.groups == [3, 3, 4] {
+        value.kind(DigitSequenceKind::PhoneNumber)
+    } else {
+        value
+    }
+}
+
+/// Age of the given duration, which must be counted in days or coarser units.
+pub fn age(duration: &DurationValue) -> RuleResult<AgeValue> {
+    match duration.period.finer_grain() {
+        Some(grain) if grain.is_date_grain() => Ok(AgeValue::new(duration.period.clone())),
+        _ => Err(RuleError::Invalid.into()),
+    }
+}
+
+/// Age of the given duration, which must be counted in years, after words which don't imply
+/// an age by themselves, e.g. the verb of "j'ai 34 ans" or the preposition of "ab 18 Jahren".
+pub fn age_in_years_duration(duration: &DurationValue) -> RuleResult<AgeValue> {
+    match duration.period.finer_grain() {
+        Some(Grain::Year) => age(duration),
+        _ => Err(RuleError::Invalid.into()),
+    }
+}
+
+/// Age given as a bare number of years, e.g. "I'm 34".
+pub fn age_in_years(years: i64) -> RuleResult<AgeValue> {
+    if years < 0 || years > 150 {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(AgeValue::new(PeriodComp::years(years).into()))
+}
+
+/// Age range from a bare number to an age, counted in the unit of the latter, e.g.
+/// "between 18 and 25 years old".
+pub fn age_range(min: i64, max: &AgeValue) -> RuleResult<AgeValue> {
+    let comps = match (max.is_single(), max.max.as_ref()) {
+        (true, Some(max)) => max.comps(),
+        _ => return Err(RuleError::Invalid.into()),
+    };
+    match comps.as_slice() {
+        &[comp] if min >= 0 && min < comp.quantity => Ok(AgeValue::range(
+            PeriodComp::new(comp.grain, min).into(),
+            comp.into(),
+        )),
+        _ => Err(RuleError::Invalid.into()),
+    }
+}
+
 pub fn compose_numbers_from_left(a: &NumberValue, b: &NumberValue) -> RuleResult<NumberValue> {
     if b.combined_from_left() {
         Err(RuleError::Invalid.into())
@@ -49,6 +386,48 @@ pub fn compose_duration_with_integer(
     Ok(duration + DurationValue::new(period))
 }
 
+/// Splits a fractional quantity of the given grain into whole quantities of finer grains, e.g.
+/// 2.75 hours into 2 hours and 45 minutes. Remainders below a second are dropped.
+pub fn fractional_period(quantity: f64, grain: Grain) -> RuleResult<Period> {
+    if !quantity.is_finite() || quantity < 0.0 || quantity >= i64::max_value() as f64 {
+        return Err(RuleError::Invalid.into());
+    }
+    let mut period = Period::default();
+    let mut current_grain = grain;
+    let mut quantity = quantity;
+    loop {
+        // Absorbs the representation error of fractions, e.g. 1/3 * 60 = 19.999999999999996
+        let whole = if (quantity - quantity.round()).abs() < 1e-6 {
+            quantity.round()
+        } else {
+            quantity.floor()
+        };
+        if whole > 0.0 {
+            period += PeriodComp::new(current_grain, whole as i64);
+        }
+        let remainder = quantity - whole;
+        if remainder < 1e-6 {
+            break;
+        }
+        let (finer_grain, ratio) = match current_grain {
+            Grain::Year => (Grain::Month, 12.0),
+            Grain::Quarter => (Grain::Month, 3.0),
+            Grain::Month => (Grain::Day, 30.0),
+            Grain::Week => (Grain::Day, 7.0),
+            Grain::Day => (Grain::Hour, 24.0),
+            Grain::Hour => (Grain::Minute, 60.0),
+            Grain::Minute => (Grain::Second, 60.0),
+            Grain::Second => break,
+        };
+        current_grain = finer_grain;
+        quantity = remainder * ratio;
+    }
+    if period.0.is_empty() {
+        period += PeriodComp::new(grain, 0);
+    }
+    Ok(period)
+}
+
 #[derive(Debug, Clone)]
 pub struct RegexMatch<'a> {
     pub groups: Vec<Option<&'a str>>,
@@ -80,9 +459,16 @@ pub fn compose_money(
     b: &AmountOfMoneyValue,
 ) -> RuleResult<AmountOfMoneyValue> {
     let amount = a.value + b.value / 100.0;
+    let decimal = match (a.decimal, b.decimal) {
+        (Some(major), Some(minor)) => minor
+            .checked_mul(Decimal::new(1, 2))
+            .and_then(|minor| major.checked_add(minor)),
+        _ => None,
+    };
     Ok(AmountOfMoneyValue {
         value: amount,
         unit: a.unit,
+        decimal,
         ..AmountOfMoneyValue::default()
     })
 }
@@ -91,14 +477,41 @@ pub fn compose_money_number(
     a: &AmountOfMoneyValue,
     b: &NumberValue,
 ) -> RuleResult<AmountOfMoneyValue> {
-    let amount = a.value + b.value() / 100.0;
+    // "two dollars and a half" is a fraction of the unit, "two dollars fifty" a number of cents
+    let (amount, scale) = if b.is_spelled_fraction() {
+        (a.value + b.value(), Decimal::new(1, 0))
+    } else {
+        (a.value + b.value() / 100.0, Decimal::new(1, 2))
+    };
+    let decimal = match (a.decimal, b.decimal()) {
+        (Some(major), Some(minor)) => minor
+            .checked_mul(scale)
+            .and_then(|minor| major.checked_add(minor)),
+        _ => None,
+    };
     Ok(AmountOfMoneyValue {
         value: amount,
         unit: a.unit,
+        decimal,
         ..AmountOfMoneyValue::default()
     })
 }
 
+/// Amount of money scaled by a magnitude suffix, e.g. "k" in "5k€" or "bn" in "$2bn".
+pub fn money_magnitude(a: &AmountOfMoneyValue, suffix: &str) -> RuleResult<AmountOfMoneyValue> {
+    let multiplier: i64 = match suffix {
+        "k" | "tsd" => 1_000,
+        "m" | "mm" | "mn" | "mio" => 1_000_000,
+        "b" | "bn" | "md" | "mds" | "mrd" | "mrds" => 1_000_000_000,
+        _ => return Err(RuleError::Invalid.into()),
+    };
+    Ok(AmountOfMoneyValue {
+        value: a.value * multiplier as f64,
+        decimal: a.decimal.and_then(|it| it.checked_mul(multiplier.into())),
+        ..*a
+    })
+}
+
 impl Form {
     fn time_of_day_hour(full_hour: u32, is_12_clock: bool) -> Form {
         Form::TimeOfDay(TimeOfDayForm::hour(full_hour, is_12_clock))
@@ -503,6 +916,27 @@ pub fn month_day(m: u32, d: u32) -> RuleResult<DatetimeValue> {
     )
 }
 
+pub fn lunar_month_day(calendar: LunarCalendar, m: u32, d: u32) -> RuleResult<DatetimeValue> {
+    Ok(
+        DatetimeValue::constraint(LunarMonthDay::new(calendar, m, d).invalid_if_err()?)
+            .datetime_kind(DatetimeKind::Date),
+    )
+}
+
+pub fn hijri_month_day(m: u32, d: u32) -> RuleResult<DatetimeValue> {
+    Ok(
+        DatetimeValue::constraint(HijriMonthDay::new(m, d).invalid_if_err()?)
+            .datetime_kind(DatetimeKind::Date),
+    )
+}
+
+pub fn hebrew_month_day(m: u32, d: u32) -> RuleResult<DatetimeValue> {
+    Ok(
+        DatetimeValue::constraint(HebrewMonthDay::new(m, d).invalid_if_err()?)
+            .datetime_kind(DatetimeKind::Date),
+    )
+}
+
 pub fn year_month_day(y: i32, m: u32, d: u32) -> RuleResult<DatetimeValue> {
     let y = normalize_year(y)?;
     Ok(
@@ -816,16 +1250,279 @@ impl<'a> ops::Add<DurationValue> for &'a DurationValue {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_money_magnitude() {
+        let amount = AmountOfMoneyValue {
+            value: 1.2,
+            unit: Some("EUR"),
+            ..AmountOfMoneyValue::default()
+        };
+        assert_eq!(1_200_000.0, money_magnitude(&amount, "m").unwrap().value);
+        let exact = AmountOfMoneyValue {
+            decimal: Some(Decimal::new(12, 1)),
+            ..amount
+        };
+        assert_eq!(Some(Decimal::new(1_200, 0)), money_magnitude(&exact, "k").unwrap().decimal);
+        assert_eq!(Some("EUR"), money_magnitude(&amount, "k").unwrap().unit);
+        assert!(money_magnitude(&amount, "x").is_err());
+    }
+
+    #[test]
+    fn test_compose_money_decimal() {
+        let dollars = AmountOfMoneyValue {
+            value: 31_415_926_535_897.0,
+            unit: Some("$"),
+            decimal: Some(Decimal::new(31_415_926_535_897, 0)),
+            ..AmountOfMoneyValue::default()
+        };
+        let cents = AmountOfMoneyValue {
+            value: 93.0,
+            unit: Some("cent"),
+            decimal: Some(Decimal::new(93, 0)),
+            ..AmountOfMoneyValue::default()
+        };
+        assert_eq!(
+            Some(Decimal::new(3_141_592_653_589_793, 2)),
+            compose_money(&dollars, &cents).unwrap().decimal
+        );
+        let fifty: NumberValue = IntegerValue::new(50).unwrap().into();
+        assert_eq!(
+            Some(Decimal::new(314_159_265_358_975, 1)),
+            compose_money_number(&dollars, &fifty).unwrap().decimal
+        );
+    }
+
+    #[test]
+    fn test_compose_money_fraction() {
+        let dollars = AmountOfMoneyValue {
+            value: 5.0,
+            unit: Some("$"),
+            decimal: Some(Decimal::new(5, 0)),
+            ..AmountOfMoneyValue::default()
+        };
+        let half: NumberValue = spelled_fraction(1, 2).unwrap().into();
+        assert_eq!(Some(Decimal::new(55, 1)), compose_money_number(&dollars, &half).unwrap().decimal);
+        let typed: NumberValue = FloatValue::from_decimal(Decimal::new(5, 1)).unwrap().into();
+        assert_eq!(Some(Decimal::new(5005, 3)), compose_money_number(&dollars, &typed).unwrap().decimal);
+        let numeric: NumberValue = fraction(1, 2).unwrap().into();
+        assert_eq!(Some(Decimal::new(5005, 3)), compose_money_number(&dollars, &numeric).unwrap().decimal);
+    }
+
     #[test]
     fn test_decimal_hour() {
         assert_eq!(90, decimal_hour_in_minute("1", "5").unwrap());
         assert_eq!(93, decimal_hour_in_minute("1", "55").unwrap());
     }
 
+    #[test]
+    fn test_roman_numeral() {
+        assert_eq!(4, roman_numeral("iv", &[]).unwrap());
+        assert_eq!(14, roman_numeral("xiv", &[]).unwrap());
+        assert_eq!(58, roman_numeral("lviii", &[]).unwrap());
+        assert_eq!(1994, roman_numeral("MCMXCIV", &[]).unwrap());
+        assert_eq!(3999, roman_numeral("mmmcmxcix", &[]).unwrap());
+        assert!(roman_numeral("iiii", &[]).is_err());
+        assert!(roman_numeral("vx", &[]).is_err());
+        assert!(roman_numeral("ic", &[]).is_err());
+        assert!(roman_numeral("mid", &[]).is_err());
+        assert!(roman_numeral("", &[]).is_err());
+        assert!(roman_numeral("mix", &["mix", "cm"]).is_err());
+        assert_eq!(1010, roman_numeral("mx", &["mix", "cm"]).unwrap());
+    }
+
+    #[test]
+    fn test_multiply_number() {
+        let million = IntegerValue::new_with_grain(1_000_000, 6).unwrap();
+        let dozen = IntegerValue::new(12).unwrap();
+        assert_eq!(
+            NumberValue::Integer(IntegerValue::new_with_grain(2_500_000, 6).unwrap()),
+            multiply_number(&FloatValue::new(2.5).unwrap().into(), &million).unwrap()
+        );
+        assert_eq!(
+            NumberValue::Integer(IntegerValue::new(36).unwrap()),
+            multiply_number(&IntegerValue::new(3).unwrap().into(), &dozen).unwrap()
+        );
+        assert_eq!(
+            NumberValue::Float(FloatValue::new(1.5).unwrap()),
+            multiply_number(&FloatValue::new(0.125).unwrap().into(), &dozen).unwrap()
+        );
+        assert_eq!(
+            NumberValue::Integer(IntegerValue::new_with_grain(435, 2).unwrap()),
+            multiply_number(
+                &FloatValue::new(4.35).unwrap().into(),
+                &IntegerValue::new_with_grain(100, 2).unwrap()
+            )
+            .unwrap()
+        );
+        assert!(multiply_number(&IntegerValue::new(i64::max_value()).unwrap().into(), &dozen).is_err());
+        let quintillion = IntegerValue::new_with_grain(1_000_000_000_000_000_000, 18).unwrap();
+        assert_eq!(
+            NumberValue::Float(FloatValue::from_decimal(Decimal::new(12_000_000_000_000_000_000, 0)).unwrap()),
+            multiply_number(&IntegerValue::new(12).unwrap().into(), &quintillion).unwrap()
+        );
+        let thousand = IntegerValue::new_with_grain(1_000, 3).unwrap();
+        let pi = FloatValue::from_decimal("31415926535.89793".parse().unwrap()).unwrap();
+        assert_eq!(
+            Some(Decimal::new(3_141_592_653_589_793, 2)),
+            multiply_number(&pi.into(), &thousand).unwrap().decimal()
+        );
+    }
+
+    #[test]
+    fn test_digit_sequence() {
+        assert_eq!(5551234, digit_sequence(&[5, 5, 5, 1, 2, 3, 4]).unwrap().value);
+        assert_eq!(7, digit_sequence(&[0, 0, 7]).unwrap().value);
+        assert!(digit_sequence(&[]).is_err());
+        assert!(digit_sequence(&[1, 10]).is_err());
+        assert!(digit_sequence(&[9; 19]).is_err());
+    }
+
+    #[test]
+    fn test_digit_sequence_value() {
+        let code = digit_sequence_value("4 8 1 5").unwrap();
+        assert_eq!("4815", code.digits);
+        assert_eq!(vec![1, 1, 1, 1], code.groups);
+        assert_eq!(DigitSequenceKind::Generic, code.kind);
+        let phone = digit_sequence_value("06 12 34 56 78").unwrap();
+        assert_eq!("0612345678", phone.digits);
+        assert_eq!(vec![2, 2, 2, 2, 2], phone.groups);
+        assert_eq!(DigitSequenceKind::PhoneNumber, phone.kind);
+        assert_eq!(DigitSequenceKind::PhoneNumber, digit_sequence_value("555-123-4567").unwrap().kind);
+        assert_eq!("007", digit_sequence_value("007").unwrap().digits);
+        assert!(digit_sequence_value("2 3").is_err());
+        assert!(digit_sequence_value("12 3").is_err());
+        assert!(digit_sequence_value("10-12").is_err());
+        assert_eq!("1234", digit_sequence_value("1 2 3 4").unwrap().digits);
+        assert_eq!("12345678", digit_sequence_value("1234 5678").unwrap().digits);
+        assert!(digit_sequence_value("").is_err());
+        assert!(digit_sequence_value("42").is_err());
+        assert_eq!("0042", digit_sequence_value("0042").unwrap().digits);
+        assert_eq!(DigitSequenceKind::PhoneNumber, digit_sequence_value("0612345678").unwrap().kind);
+        assert!(digit_sequence_value("2013-02-12").is_err());
+        assert!(digit_sequence_value("12.02.2013").is_err());
+        assert!(digit_sequence_value("1.500.000").is_err());
+        assert!(digit_sequence_value("1 500 000").is_err());
+    }
+
+    #[test]
+    fn test_digit_code() {
+        assert_eq!("1234", digit_code("1234").unwrap().digits);
+        assert_eq!("42", digit_code("42").unwrap().digits);
+        assert!(digit_code("").is_err());
+    }
+
+    #[test]
+    fn test_spoken_digit_sequence_value() {
+        let phone = spoken_digit_sequence_value(&[0, 6, 1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        assert_eq!("0612345678", phone.digits);
+        assert_eq!(DigitSequenceKind::PhoneNumber, phone.kind);
+        assert!(spoken_digit_sequence_value(&[1, 10]).is_err());
+    }
+
+    #[test]
+    fn test_international_phone_number() {
+        let phone = international_phone_number(Some("33"), "6 12 34 56 78").unwrap();
+        assert_eq!("33612345678", phone.digits);
+        assert_eq!(Some(33), phone.country_code);
+        assert!(phone.international);
+        let phone = international_phone_number(None, "33612345678").unwrap();
+        assert_eq!(None, phone.country_code);
+        assert!(international_phone_number(None, "1234").is_err());
+        assert!(international_phone_number(None, "1234567890123456").is_err());
+    }
+
+    #[test]
+    fn test_age() {
+        let years = DurationValue::new(PeriodComp::years(34).into());
+        assert_eq!(AgeValue::new(PeriodComp::years(34).into()), age(&years).unwrap());
+        let hours = DurationValue::new(PeriodComp::hours(3).into());
+        assert!(age(&hours).is_err());
+        assert!(age_in_years_duration(&years).is_ok());
+        let weeks = DurationValue::new(PeriodComp::weeks(2).into());
+        assert!(age_in_years_duration(&weeks).is_err());
+        assert_eq!(AgeValue::new(PeriodComp::years(34).into()), age_in_years(34).unwrap());
+        assert!(age_in_years(200).is_err());
+    }
+
+    #[test]
+    fn test_age_range() {
+        let max = AgeValue::new(PeriodComp::years(25).into());
+        assert_eq!(
+            AgeValue::range(PeriodComp::years(18).into(), PeriodComp::years(25).into()),
+            age_range(18, &max).unwrap()
+        );
+        assert!(age_range(30, &max).is_err());
+        assert!(age_range(18, &max.clone().at_least()).is_err());
+        let mixed = AgeValue::new(Period::from(PeriodComp::years(2)) + PeriodComp::months(6));
+        assert!(age_range(1, &mixed).is_err());
+    }
+
+    #[test]
+    fn test_compose_numbers_overflow() {
+        let quintillion = IntegerValue::new_with_grain(1_000_000_000_000_000_000, 18).unwrap();
+        let eight_quintillion = multiply_number(&IntegerValue::new(8).unwrap().into(), &quintillion).unwrap();
+        let big = IntegerValue::new(999_999_999_999_999_999).unwrap();
+        assert_eq!(
+            NumberValue::Integer(IntegerValue::new(8_999_999_999_999_999_999).unwrap()),
+            compose_numbers(&eight_quintillion, &big.clone().into()).unwrap()
+        );
+        let nine_quintillion = multiply_number(&IntegerValue::new(9).unwrap().into(), &quintillion).unwrap();
+        assert!(compose_numbers(&nine_quintillion, &big.into()).is_err());
+        let twelve_quintillion = multiply_number(&IntegerValue::new(12).unwrap().into(), &quintillion).unwrap();
+        assert!(compose_numbers(&twelve_quintillion, &FloatValue::new(0.5).unwrap().into()).is_err());
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(0.75, vulgar_fraction("¾").unwrap().value);
+        assert_eq!(0.125, vulgar_fraction("⅛").unwrap().value);
+        assert!(vulgar_fraction("3").is_err());
+        assert_eq!(2.5, fraction(5, 2).unwrap().value);
+        assert_eq!(Some(Decimal::new(25, 1)), fraction(5, 2).unwrap().decimal);
+        assert_eq!(None, fraction(2, 3).unwrap().decimal);
+        assert!(fraction(1, 0).is_err());
+        assert!(fraction(1, 1).is_err());
+        let two = IntegerValue::new(2).unwrap();
+        assert_eq!(2.75, mixed_number(&two, &fraction(3, 4).unwrap().into()).unwrap().value);
+        assert!(mixed_number(&two, &fraction(5, 4).unwrap().into()).is_err());
+        assert!(mixed_number(&two, &IntegerValue::new(1).unwrap().into()).is_err());
+    }
+
+    #[test]
+    fn test_fractional_period() {
+        assert_eq!(
+            Period::from(PeriodComp::hours(2)) + PeriodComp::minutes(45),
+            fractional_period(2.75, Grain::Hour).unwrap()
+        );
+        assert_eq!(
+            Period::from(PeriodComp::minutes(20)),
+            fractional_period(1.0 / 3.0, Grain::Hour).unwrap()
+        );
+        assert_eq!(
+            Period::from(PeriodComp::days(3)) + PeriodComp::hours(12),
+            fractional_period(0.5, Grain::Week).unwrap()
+        );
+        assert_eq!(
+            Period::from(PeriodComp::years(1)) + PeriodComp::months(6),
+            fractional_period(1.5, Grain::Year).unwrap()
+        );
+        assert_eq!(Period::from(PeriodComp::seconds(0)), fractional_period(0.0, Grain::Second).unwrap());
+        assert!(fractional_period(-1.5, Grain::Hour).is_err());
+    }
+
     #[test]
     fn test_computer_easter() {
         assert_eq!((2017, 4, 16), computer_easter(2017));
         assert_eq!((2018, 4, 1), computer_easter(2018));
         assert_eq!((2019, 4, 21), computer_easter(2019));
     }
+
+    #[test]
+    fn test_datetime_value_eq() {
+        let march = month(3).unwrap();
+        assert_eq!(march, march.clone());
+        assert_ne!(march, march.clone().latent());
+        assert_ne!(march, month(3).unwrap());
+        assert_ne!(Dimension::Datetime(march), Dimension::Datetime(month(4).unwrap()));
+    }
 }
@@ -8,6 +8,8 @@ extern crate rustling_ontology_moment as moment;
 extern crate regex;
 
 pub mod check;
+pub mod currency;
+pub mod decimal;
 pub mod dimension;
 pub mod helpers;
 #[macro_use]
@@ -17,6 +19,8 @@ pub mod macros_rules;
 pub mod output;
 pub mod context;
 
+pub use currency::Currency;
+pub use decimal::Decimal;
 pub use dimension::Dimension;
 pub use dimension::DimensionKind;
 pub use output::Output;
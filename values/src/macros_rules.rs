@@ -128,6 +128,13 @@ macro_rules! duration_check {
 }
 
 
+#[macro_export]
+macro_rules! age_check {
+    () => ( ::rustling::core::AnyNodePattern::<AgeValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<AgeValue>::filter(vec![b!($predicate)]) );
+}
+
+
 #[macro_export]
 macro_rules! relative_minute_check {
     () => ( ::rustling::core::AnyNodePattern::<RelativeMinuteValue>::new() );
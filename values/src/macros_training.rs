@@ -18,6 +18,12 @@ macro_rules! check_duration {
     ([$($item:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_duration(period!($( $item ),*), $precision) );
 }
 
+#[macro_export]
+macro_rules! check_age {
+    ([$($item:expr),*]) => ( ::rustling_ontology_values::check::check_age(Some(period!($( $item ),*)), Some(period!($( $item ),*))) );
+    ([$($item1:expr),*], [$($item2:expr),*]) => ( ::rustling_ontology_values::check::check_age(Some(period!($( $item1 ),*)), Some(period!($( $item2 ),*))) );
+}
+
 #[macro_export]
 macro_rules! check_moment {
     ($context:expr, [$($item:expr),*]) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, None) );
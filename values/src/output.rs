@@ -1,3 +1,5 @@
+use crate::currency::{minor_unit_ratio, Currency};
+use crate::decimal::Decimal;
 use crate::dimension::*;
 use moment::*;
 use rustling::Value;
@@ -13,6 +15,8 @@ pub enum Output {
     AmountOfMoney(AmountOfMoneyOutput),
     Temperature(TemperatureOutput),
     Duration(DurationOutput),
+    DigitSequence(DigitSequenceOutput),
+    Age(AgeOutput),
 }
 
 impl Output {
@@ -45,6 +49,22 @@ impl Output {
             &Output::Temperature(_) => OutputKind::Temperature,
             &Output::Duration(_) => OutputKind::Duration,
             &Output::Percentage(_) => OutputKind::Percentage,
+            &Output::DigitSequence(ref sequence) => match sequence.kind {
+                DigitSequenceKind::PhoneNumber => OutputKind::PhoneNumber,
+                DigitSequenceKind::Generic => OutputKind::DigitSequence,
+            },
+            &Output::Age(_) => OutputKind::Age,
+        }
+    }
+
+    /// Exact decimal value of numbers, percentages and amounts of money.
+    pub fn decimal(&self) -> Option<Decimal> {
+        match self {
+            &Output::Integer(ref integer) => Some(integer.decimal()),
+            &Output::Float(ref float) => float.decimal(),
+            &Output::Percentage(ref percentage) => percentage.decimal(),
+            &Output::AmountOfMoney(ref amount) => amount.decimal(),
+            _ => None,
         }
     }
 }
@@ -62,7 +82,10 @@ enum_kind!(
         Duration,
         AmountOfMoney,
         Temperature,
-        Percentage
+        Percentage,
+        DigitSequence,
+        PhoneNumber,
+        Age
     ]
 );
 
@@ -80,6 +103,9 @@ impl OutputKind {
             &OutputKind::Temperature => DimensionKind::Temperature,
             &OutputKind::Duration => DimensionKind::Duration,
             &OutputKind::Percentage => DimensionKind::Percentage,
+            &OutputKind::DigitSequence => DimensionKind::DigitSequence,
+            &OutputKind::PhoneNumber => DimensionKind::DigitSequence,
+            &OutputKind::Age => DimensionKind::Age,
         }
     }
 
@@ -102,6 +128,12 @@ impl OutputKind {
                     _ => false,
                 }
             }
+            Dimension::DigitSequence(sequence_value) => match self {
+                OutputKind::PhoneNumber => DigitSequenceKind::PhoneNumber == sequence_value.kind,
+                // Phone numbers are digit sequences as well
+                OutputKind::DigitSequence => true,
+                _ => false,
+            },
             _ => self.to_dim() == dimension_value.kind(),
         }
     }
@@ -110,11 +142,31 @@ impl OutputKind {
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct IntegerOutput(pub i64);
 
+impl IntegerOutput {
+    pub fn decimal(&self) -> Decimal {
+        self.0.into()
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
-pub struct FloatOutput(pub f64);
+pub struct FloatOutput(pub f64, pub Option<Decimal>);
+
+impl FloatOutput {
+    /// Exact value of the float, when it was read from digits or computed exactly from them.
+    pub fn decimal(&self) -> Option<Decimal> {
+        self.1
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Debug)]
-pub struct PercentageOutput(pub f64);
+pub struct PercentageOutput(pub f64, pub Option<Decimal>);
+
+impl PercentageOutput {
+    /// Exact value of the percentage, when it was read from digits or computed exactly from them.
+    pub fn decimal(&self) -> Option<Decimal> {
+        self.1
+    }
+}
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct OrdinalOutput(pub i64);
@@ -135,6 +187,31 @@ impl DatetimeOutput {
             ..self
         }
     }
+
+    /// RFC-3339 representation of the moment, e.g. "2026-10-19T00:00:00+02:00".
+    pub fn to_rfc3339(&self) -> String {
+        self.moment.to_rfc3339()
+    }
+
+    /// ISO-8601 representation truncated to the grain, e.g. "2026-10" or "2026-W43".
+    pub fn to_iso8601(&self) -> String {
+        self.moment.to_iso8601(self.grain)
+    }
+}
+
+impl DatetimeIntervalOutput {
+    /// ISO-8601 interval with RFC-3339 bounds, e.g.
+    /// "2026-10-19T14:00:00+02:00/2026-10-19T16:00:00+02:00". Open ends are written "..",
+    /// e.g. "2026-10-19T14:00:00+02:00/.." after a moment.
+    pub fn to_iso8601(&self) -> String {
+        match self.interval_kind {
+            DatetimeIntervalKind::After(start) => format!("{}/..", start.to_rfc3339()),
+            DatetimeIntervalKind::Before(end) => format!("../{}", end.to_rfc3339()),
+            DatetimeIntervalKind::Between { start, end, .. } => {
+                format!("{}/{}", start.to_rfc3339(), end.to_rfc3339())
+            }
+        }
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -150,6 +227,7 @@ pub enum DatetimeIntervalKind {
     Between {
         start: Moment<Local>,
         end: Moment<Local>,
+        grain: Grain,
         precision: Precision,
         latent: bool,
     },
@@ -157,24 +235,201 @@ pub enum DatetimeIntervalKind {
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct AmountOfMoneyOutput {
+    /// Value in the major unit, e.g. 0.5 for "50 cents"
     pub value: f64,
+    /// Exact value in the major unit, when the amount was read from digits or computed exactly
+    /// from them
+    pub decimal: Option<Decimal>,
     pub precision: Precision,
+    /// Unit as found in the input, e.g. "$" or "cent", `None` for amounts in the local
+    /// currency like "2 grand". It only tells how the amount was written, not the unit of
+    /// `value`: "50 cents" gives 0.5 with the unit "cent"
     pub unit: Option<&'static str>,
+    /// Currency of the amount, the unit of `value`, `None` while the unit is ambiguous, see
+    /// `AmountOfMoneyOutput::resolve_currency`
+    pub currency: Option<Currency>,
+}
+
+impl AmountOfMoneyOutput {
+    pub fn new(
+        value: f64,
+        decimal: Option<Decimal>,
+        precision: Precision,
+        unit: Option<&'static str>,
+    ) -> AmountOfMoneyOutput {
+        let ratio = unit.and_then(minor_unit_ratio).unwrap_or(1.0);
+        AmountOfMoneyOutput {
+            value: value / ratio,
+            decimal: decimal.and_then(|it| it.checked_mul(Decimal::ratio(1, ratio as i64)?)),
+            precision,
+            unit,
+            currency: unit.and_then(Currency::from_unit),
+        }
+    }
+
+    /// Same amount with its currency resolved in a locale such as "en-US", see
+    /// `Currency::resolve`.
+    pub fn resolve_currency(&self, locale: &str) -> AmountOfMoneyOutput {
+        AmountOfMoneyOutput {
+            currency: self
+                .currency
+                .or_else(|| Currency::resolve(self.unit, Some(locale))),
+            ..*self
+        }
+    }
+
+    /// Exact value of the amount, e.g. `19.99` for "nineteen dollars ninety nine", unless it
+    /// was computed from an inexact number.
+    pub fn decimal(&self) -> Option<Decimal> {
+        self.decimal
+    }
+
+    /// Value in the minor unit found in the input, e.g. 50 for "50 cents", `None` when the
+    /// unit isn't a minor one.
+    pub fn minor_unit_value(&self) -> Option<f64> {
+        let ratio = self.unit.and_then(minor_unit_ratio)?;
+        Some(self
+            .decimal
+            .and_then(|it| it.checked_mul(Decimal::from(ratio as i64)))
+            .map(|it| it.to_f64())
+            .unwrap_or(self.value * ratio))
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct TemperatureOutput {
     pub value: f64,
-    pub unit: Option<&'static str>,
+    pub unit: Option<TemperatureUnit>,
     pub latent: bool,
 }
 
+impl TemperatureOutput {
+    /// Same temperature with plain degrees replaced by the scale used in the locale, e.g.
+    /// Fahrenheit for "25 degrees" in "en-US".
+    pub fn resolve_degree(&self, locale: &str) -> TemperatureOutput {
+        match self.unit {
+            Some(TemperatureUnit::Degree) => TemperatureOutput {
+                unit: Some(TemperatureUnit::default_for_locale(locale)),
+                ..*self
+            },
+            _ => *self,
+        }
+    }
+
+    /// Same temperature in another unit, `None` if the scale is unknown, see
+    /// `TemperatureOutput::resolve_degree`.
+    pub fn convert_to(&self, unit: TemperatureUnit) -> Option<TemperatureOutput> {
+        let value = self.unit?.convert(self.value, unit)?;
+        Some(TemperatureOutput {
+            value,
+            unit: Some(unit),
+            ..*self
+        })
+    }
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct DurationOutput {
     pub period: Period,
     pub precision: Precision,
 }
 
+impl DurationOutput {
+    /// Exact length in seconds, or `None` if the duration has years, quarters or months.
+    pub fn total_seconds(&self) -> Option<i64> {
+        self.period.total_seconds()
+    }
+
+    /// Same duration with carried over components, e.g. "2 hours 30 minutes" for "1 hour 90
+    /// minutes".
+    pub fn normalized(&self) -> DurationOutput {
+        DurationOutput {
+            period: self.period.normalized(),
+            precision: self.precision,
+        }
+    }
+
+    /// ISO-8601 representation, e.g. "PT2H30M".
+    pub fn to_iso8601(&self) -> String {
+        self.period.to_string()
+    }
+
+    /// Compares the lengths of two durations, `None` if it depends on when they start.
+    pub fn compare(&self, other: &DurationOutput) -> Option<::std::cmp::Ordering> {
+        self.period.compare(&other.period)
+    }
+
+    /// Moment at the end of the duration starting at `start`, keeping the wall clock time
+    /// when adding days across daylight saving time changes.
+    pub fn after(&self, start: Moment<Local>) -> Moment<Local> {
+        start + &self.period
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct DigitSequenceOutput {
+    pub digits: String,
+    pub groups: Vec<usize>,
+    pub prefix: Option<String>,
+    pub kind: DigitSequenceKind,
+    pub country_code: Option<u16>,
+    pub international: bool,
+}
+
+impl DigitSequenceOutput {
+    /// Digits with their original grouping, e.g. "AF 1234" or "06 12 34 56 78".
+    pub fn grouped(&self) -> String {
+        let mut groups = vec![];
+        if let Some(ref prefix) = self.prefix {
+            groups.push(prefix.as_str());
+        }
+        let mut start = 0;
+        for len in self.groups.iter() {
+            groups.push(&self.digits[start..start + len]);
+            start += len;
+        }
+        groups.join(" ")
+    }
+
+    /// E.164 form of a phone number, e.g. "+33612345678".
+    ///
+    /// National numbers need the `default_country_code` to be normalized, their trunk prefix
+    /// being dropped: "06 12 34 56 78" with 33 gives "+33612345678". Returns `None` for other
+    /// digit sequences and numbers which would have more than 15 digits.
+    pub fn e164(&self, default_country_code: Option<u16>) -> Option<String> {
+        if self.kind != DigitSequenceKind::PhoneNumber {
+            return None;
+        }
+        let digits = if self.international {
+            self.digits.clone()
+        } else {
+            let national = self.digits.trim_start_matches('0');
+            format!("{}{}", default_country_code?, national)
+        };
+        if digits.len() > 15 {
+            None
+        } else {
+            Some(format!("+{}", digits))
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct AgeOutput {
+    pub min: Option<Period>,
+    pub max: Option<Period>,
+}
+
+impl AgeOutput {
+    /// The age, unless it is a range.
+    pub fn exact(&self) -> Option<&Period> {
+        match (&self.min, &self.max) {
+            (&Some(ref min), &Some(ref max)) if min == max => Some(min),
+            _ => None,
+        }
+    }
+}
+
 variant_converters!(Output, Integer, IntegerOutput);
 variant_converters!(Output, Float, FloatOutput);
 variant_converters!(Output, Percentage, PercentageOutput);
@@ -184,3 +439,145 @@ variant_converters!(Output, DatetimeInterval, DatetimeIntervalOutput);
 variant_converters!(Output, AmountOfMoney, AmountOfMoneyOutput);
 variant_converters!(Output, Temperature, TemperatureOutput);
 variant_converters!(Output, Duration, DurationOutput);
+variant_converters!(Output, DigitSequence, DigitSequenceOutput);
+variant_converters!(Output, Age, AgeOutput);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phone_number(digits: &str, groups: Vec<usize>, international: bool) -> DigitSequenceOutput {
+        DigitSequenceOutput {
+            digits: digits.to_string(),
+            groups,
+            prefix: None,
+            kind: DigitSequenceKind::PhoneNumber,
+            country_code: None,
+            international,
+        }
+    }
+
+    fn datetime(moment: Moment<Local>, grain: Grain) -> DatetimeOutput {
+        DatetimeOutput {
+            moment,
+            grain,
+            precision: Precision::Exact,
+            latent: false,
+            datetime_kind: DatetimeKind::Datetime,
+        }
+    }
+
+    #[test]
+    fn test_datetime_iso8601() {
+        let moment = Moment(Local.ymd(2026, 10, 19).and_hms(14, 0, 0));
+        let offset = moment.format("%:z").to_string();
+        assert_eq!("2026-10", datetime(moment, Grain::Month).to_iso8601());
+        assert_eq!("2026-W43", datetime(moment, Grain::Week).to_iso8601());
+        assert_eq!(format!("2026-10-19T14{}", offset), datetime(moment, Grain::Hour).to_iso8601());
+        assert_eq!(
+            format!("2026-10-19T14:00:00{}", offset),
+            datetime(moment, Grain::Day).to_rfc3339()
+        );
+    }
+
+    #[test]
+    fn test_datetime_interval_iso8601() {
+        let start = Moment(Local.ymd(2026, 10, 19).and_hms(14, 0, 0));
+        let end = Moment(Local.ymd(2026, 10, 19).and_hms(16, 0, 0));
+        let offset = start.format("%:z").to_string();
+        let interval = |interval_kind| DatetimeIntervalOutput {
+            interval_kind,
+            datetime_kind: DatetimeKind::TimePeriod,
+        };
+        let between = interval(DatetimeIntervalKind::Between {
+            start,
+            end,
+            grain: Grain::Hour,
+            precision: Precision::Exact,
+            latent: false,
+        });
+        assert_eq!(
+            format!("2026-10-19T14:00:00{0}/2026-10-19T16:00:00{0}", offset),
+            between.to_iso8601()
+        );
+        let after = interval(DatetimeIntervalKind::After(datetime(start, Grain::Hour)));
+        assert_eq!(format!("2026-10-19T14:00:00{}/..", offset), after.to_iso8601());
+        let before = interval(DatetimeIntervalKind::Before(datetime(end, Grain::Hour)));
+        assert_eq!(format!("../2026-10-19T16:00:00{}", offset), before.to_iso8601());
+    }
+
+    #[test]
+    fn test_amount_of_money_currency() {
+        let cents = AmountOfMoneyOutput::new(50.0, Some(Decimal::new(50, 0)), Precision::Exact, Some("cent"));
+        assert_eq!(0.5, cents.value);
+        assert_eq!(Some(Decimal::new(5, 1)), cents.decimal());
+        assert_eq!(None, cents.currency);
+        assert_eq!(Some(Currency::Gbp), cents.resolve_currency("en-GB").currency);
+        let dollars = AmountOfMoneyOutput::new(20.0, None, Precision::Exact, Some("$"));
+        assert_eq!(20.0, dollars.value);
+        assert_eq!(Some(Currency::Cad), dollars.resolve_currency("en-CA").currency);
+        assert_eq!(Some(Currency::Usd), dollars.resolve_currency("en-GB").currency);
+        let euros = AmountOfMoneyOutput::new(20.0, None, Precision::Exact, Some("EUR"));
+        assert_eq!(Some(Currency::Eur), euros.currency);
+        assert_eq!(Some(Currency::Eur), euros.resolve_currency("en-US").currency);
+        let sats = AmountOfMoneyOutput::new(300.0, Some(Decimal::new(300, 0)), Precision::Exact, Some("sat"));
+        assert_eq!(0.000003, sats.value);
+        assert_eq!(Some(Decimal::new(3, 6)), sats.decimal());
+        assert_eq!(Some(Currency::Xbt), sats.currency);
+        let slang = AmountOfMoneyOutput::new(50.0, None, Precision::Exact, None);
+        assert_eq!(Some(Currency::Eur), slang.resolve_currency("fr-FR").currency);
+    }
+
+    #[test]
+    fn test_temperature_conversion() {
+        let temperature = TemperatureOutput {
+            value: 77.0,
+            unit: Some(TemperatureUnit::Degree),
+            latent: false,
+        };
+        assert_eq!(None, temperature.convert_to(TemperatureUnit::Celsius));
+        let fahrenheit = temperature.resolve_degree("en-US");
+        assert_eq!(Some(TemperatureUnit::Fahrenheit), fahrenheit.unit);
+        let celsius = fahrenheit.convert_to(TemperatureUnit::Celsius).unwrap();
+        assert_eq!(Some(TemperatureUnit::Celsius), celsius.unit);
+        assert!((celsius.value - 25.0).abs() < 1e-9);
+        let kelvin = celsius.convert_to(TemperatureUnit::Kelvin).unwrap();
+        assert!((kelvin.value - 298.15).abs() < 1e-9);
+        assert_eq!(Some(TemperatureUnit::Celsius), temperature.resolve_degree("fr_FR").unit);
+        assert_eq!(Some(TemperatureUnit::Celsius), temperature.resolve_degree("en").unit);
+    }
+
+    #[test]
+    fn test_duration_iso8601() {
+        let duration = DurationOutput {
+            period: Period::default() + PeriodComp::hours(1) + PeriodComp::minutes(90),
+            precision: Precision::Exact,
+        };
+        assert_eq!(Some(9000), duration.total_seconds());
+        assert_eq!("PT2H30M", duration.normalized().to_iso8601());
+        assert_eq!(Some(::std::cmp::Ordering::Equal), duration.compare(&duration.normalized()));
+    }
+
+    #[test]
+    fn test_grouped() {
+        let phone = phone_number("0612345678", vec![2, 2, 2, 2, 2], false);
+        assert_eq!("06 12 34 56 78", phone.grouped());
+        let flight = DigitSequenceOutput {
+            prefix: Some("AF".to_string()),
+            kind: DigitSequenceKind::Generic,
+            ..phone_number("1234", vec![4], false)
+        };
+        assert_eq!("AF 1234", flight.grouped());
+    }
+
+    #[test]
+    fn test_e164() {
+        let national = phone_number("0612345678", vec![2, 2, 2, 2, 2], false);
+        assert_eq!(Some("+33612345678".to_string()), national.e164(Some(33)));
+        assert_eq!(None, national.e164(None));
+        let international = phone_number("33612345678", vec![2, 1, 2, 2, 2, 2], true);
+        assert_eq!(Some("+33612345678".to_string()), international.e164(Some(1)));
+        let code = DigitSequenceOutput { kind: DigitSequenceKind::Generic, ..national };
+        assert_eq!(None, code.e164(Some(33)));
+    }
+}